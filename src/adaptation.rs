@@ -0,0 +1,235 @@
+use super::XYZ;
+
+/// The chromatic adaptation transforms available to [`XYZ::adapt`], which
+/// predict how a color seen under one white point looks under another, the
+/// way the eye adjusts to the light it is in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Adaptation {
+    /// The Bradford transform, used by ICC profiles and the CSS Color spec to
+    /// convert between D50 and D65.
+    Bradford,
+
+    /// The CAT16 transform of the CAM16 color appearance model, which behaves
+    /// better than Bradford for highly saturated colors.
+    Cat16,
+}
+
+/// Reference white points, the color that looks white under an illuminant,
+/// defined by their CIE xy chromaticity.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum WhitePoint {
+    /// Noon daylight, the white point of sRGB and Display P3, and the
+    /// default.
+    #[default]
+    D65,
+
+    /// Horizon light, the white point of ICC profiles, ProPhoto RGB and the
+    /// CSS `lab()` function.
+    D50,
+
+    /// Incandescent light, as from a tungsten light bulb.
+    A,
+
+    /// Cool white fluorescent light.
+    F2,
+
+    /// Any other white point, from its CIE xy chromaticity.
+    Custom(f32, f32),
+}
+
+impl WhitePoint {
+    /// Returns the CIE xy chromaticity of `self`, for the CIE 1931 2°
+    /// standard observer.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::WhitePoint;
+    ///
+    /// assert_eq!(WhitePoint::D65.chromaticity(), (0.3127, 0.3290));
+    /// ```
+    pub fn chromaticity(self) -> (f32, f32) {
+        match self {
+            WhitePoint::D65 => (0.3127, 0.3290),
+            WhitePoint::D50 => (0.3457, 0.3585),
+            WhitePoint::A => (0.44757, 0.40745),
+            WhitePoint::F2 => (0.37208, 0.37529),
+            WhitePoint::Custom(x, y) => (x, y),
+        }
+    }
+
+    /// Converts `self` into CIE XYZ, scaled to a luminance `y` of `1.0`.
+    /// Panics if a custom chromaticity is not finite, or does not lie within
+    /// the positive `x + y <= 1.0` triangle.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::WhitePoint;
+    ///
+    /// assert_eq!(
+    ///     WhitePoint::D50.to_xyz().to_string(),
+    ///     "color(xyz-d65 0.9643 1.0000 0.8251)"
+    /// );
+    /// ```
+    pub fn to_xyz(self) -> XYZ {
+        let (x, y) = self.chromaticity();
+        assert!(
+            x.is_finite() && y.is_finite() && x >= 0.0 && y > 0.0 && x + y <= 1.0,
+            "Invalid white point"
+        );
+
+        XYZ {
+            x: x / y,
+            y: 1.0,
+            z: (1.0 - x - y) / y,
+        }
+    }
+}
+
+// The cone response matrices of each transform, from XYZ.
+const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const CAT16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+// Adapts a color from one white point to another with the von Kries method,
+// scaling the cone responses by the ratio of the responses to each white.
+pub(crate) fn adapt(color: XYZ, from: WhitePoint, to: WhitePoint, method: Adaptation) -> XYZ {
+    if from == to {
+        return color;
+    }
+
+    let cone = match method {
+        Adaptation::Bradford => BRADFORD,
+        Adaptation::Cat16 => CAT16,
+    };
+    let response = |xyz: XYZ| multiply(&cone, [xyz.x as f64, xyz.y as f64, xyz.z as f64]);
+
+    let source = response(from.to_xyz());
+    let target = response(to.to_xyz());
+    let mut cones = response(color);
+    for (i, cone) in cones.iter_mut().enumerate() {
+        *cone *= target[i] / source[i];
+    }
+
+    let [x, y, z] = multiply(&invert(&cone), cones);
+    XYZ {
+        x: x as f32,
+        y: y as f32,
+        z: z as f32,
+    }
+}
+
+fn multiply(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+// Inverts a 3x3 matrix through its adjugate.
+fn invert(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let cofactor = |row: usize, column: usize| {
+        let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+        let (c0, c1) = ((column + 1) % 3, (column + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let determinant = (0..3).map(|i| m[0][i] * cofactor(0, i)).sum::<f64>();
+
+    let mut inverse = [[0.0; 3]; 3];
+    for (row, values) in inverse.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = cofactor(column, row) / determinant;
+        }
+    }
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{invert, multiply, BRADFORD};
+    use crate::{rgb, xyz, Adaptation, Color, WhitePoint};
+
+    #[test]
+    fn can_invert_matrices() {
+        let inverse = invert(&BRADFORD);
+
+        for i in 0..3 {
+            let mut unit = [0.0; 3];
+            unit[i] = 1.0;
+
+            let column = multiply(&BRADFORD, multiply(&inverse, unit));
+            for (value, expected) in column.iter().zip(unit) {
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn adapts_white_points() {
+        for method in [Adaptation::Bradford, Adaptation::Cat16] {
+            let white = WhitePoint::D65
+                .to_xyz()
+                .adapt(WhitePoint::D65, WhitePoint::D50, method);
+
+            assert_eq!(white.to_string(), WhitePoint::D50.to_xyz().to_string());
+        }
+    }
+
+    #[test]
+    fn can_convert_white_points() {
+        assert_eq!(
+            WhitePoint::A.to_xyz().to_string(),
+            "color(xyz-d65 1.0985 1.0000 0.3558)"
+        );
+        assert_eq!(
+            WhitePoint::Custom(0.3127, 0.3290).to_xyz(),
+            WhitePoint::D65.to_xyz()
+        );
+        assert_eq!(WhitePoint::default(), WhitePoint::D65);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_white_points() {
+        WhitePoint::Custom(0.5, 0.0).to_xyz();
+    }
+
+    #[test]
+    fn matches_published_bradford_matrix() {
+        // The sRGB red primary in D50, as published by Bruce Lindbloom.
+        let red =
+            rgb(255, 0, 0)
+                .to_xyz()
+                .adapt(WhitePoint::D65, WhitePoint::D50, Adaptation::Bradford);
+
+        assert!((red.x - 0.4360747).abs() < 1e-3, "{}", red);
+        assert!((red.y - 0.2225045).abs() < 1e-3, "{}", red);
+        assert!((red.z - 0.0139322).abs() < 1e-3, "{}", red);
+    }
+
+    #[test]
+    fn can_round_trip() {
+        let color = xyz(0.3, 0.4, 0.5);
+
+        for method in [Adaptation::Bradford, Adaptation::Cat16] {
+            let adapted = color.adapt(WhitePoint::D50, WhitePoint::D65, method).adapt(
+                WhitePoint::D65,
+                WhitePoint::D50,
+                method,
+            );
+
+            assert!((adapted.x - color.x).abs() < 1e-5);
+            assert!((adapted.y - color.y).abs() < 1e-5);
+            assert!((adapted.z - color.z).abs() < 1e-5);
+        }
+
+        assert_eq!(
+            color.adapt(WhitePoint::D50, WhitePoint::D50, Adaptation::Cat16),
+            color
+        );
+    }
+}
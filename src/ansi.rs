@@ -0,0 +1,541 @@
+use super::{difference, rgb, Color, RGB};
+use std::io;
+
+/// The escape sequence resetting the style of terminal output, including the
+/// colors set by `to_ansi_fg()` and `to_ansi_bg()`.
+///
+/// # Example
+/// ```
+/// use farver::{Color, rgb, ANSI_RESET};
+///
+/// let tomato = rgb(255, 99, 71);
+/// let output = format!("{}tomato{}", tomato.to_ansi_fg(), ANSI_RESET);
+///
+/// assert_eq!(output, "\x1b[38;2;255;99;71mtomato\x1b[0m");
+/// ```
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// The 16 basic ANSI colors as defined by xterm, in the order of their SGR
+/// codes: black, red, green, yellow, blue, magenta, cyan, white, then their
+/// bright variants.
+pub const ANSI16_XTERM: [RGB; 16] = [
+    rgb(0x00, 0x00, 0x00),
+    rgb(0xCD, 0x00, 0x00),
+    rgb(0x00, 0xCD, 0x00),
+    rgb(0xCD, 0xCD, 0x00),
+    rgb(0x00, 0x00, 0xEE),
+    rgb(0xCD, 0x00, 0xCD),
+    rgb(0x00, 0xCD, 0xCD),
+    rgb(0xE5, 0xE5, 0xE5),
+    rgb(0x7F, 0x7F, 0x7F),
+    rgb(0xFF, 0x00, 0x00),
+    rgb(0x00, 0xFF, 0x00),
+    rgb(0xFF, 0xFF, 0x00),
+    rgb(0x5C, 0x5C, 0xFF),
+    rgb(0xFF, 0x00, 0xFF),
+    rgb(0x00, 0xFF, 0xFF),
+    rgb(0xFF, 0xFF, 0xFF),
+];
+
+/// The 16 basic ANSI colors as defined by the VGA text mode, and used by the
+/// Linux console. They are in the same order as `ANSI16_XTERM`.
+pub const ANSI16_VGA: [RGB; 16] = [
+    rgb(0x00, 0x00, 0x00),
+    rgb(0xAA, 0x00, 0x00),
+    rgb(0x00, 0xAA, 0x00),
+    rgb(0xAA, 0x55, 0x00),
+    rgb(0x00, 0x00, 0xAA),
+    rgb(0xAA, 0x00, 0xAA),
+    rgb(0x00, 0xAA, 0xAA),
+    rgb(0xAA, 0xAA, 0xAA),
+    rgb(0x55, 0x55, 0x55),
+    rgb(0xFF, 0x55, 0x55),
+    rgb(0x55, 0xFF, 0x55),
+    rgb(0xFF, 0xFF, 0x55),
+    rgb(0x55, 0x55, 0xFF),
+    rgb(0xFF, 0x55, 0xFF),
+    rgb(0x55, 0xFF, 0xFF),
+    rgb(0xFF, 0xFF, 0xFF),
+];
+
+/// The xterm 256 colors palette, indexed like the `38;5;n` and `48;5;n` SGR
+/// codes: the 16 basic colors of `ANSI16_XTERM`, a 6x6x6 color cube, and a
+/// ramp of 24 greys.
+pub const ANSI256: [RGB; 256] = ansi256();
+
+const fn ansi256() -> [RGB; 256] {
+    let mut table = [rgb(0, 0, 0); 256];
+    let mut i = 0;
+
+    while i < 16 {
+        table[i] = ANSI16_XTERM[i];
+        i += 1;
+    }
+
+    while i < 232 {
+        let n = i - 16;
+        table[i] = rgb(
+            CUBE_LEVELS[n / 36],
+            CUBE_LEVELS[n / 6 % 6],
+            CUBE_LEVELS[n % 6],
+        );
+        i += 1;
+    }
+
+    while i < 256 {
+        let grey = (8 + (i - 232) * 10) as u8;
+        table[i] = rgb(grey, grey, grey);
+        i += 1;
+    }
+
+    table
+}
+
+/// Returns the color of the xterm 256 colors palette at `index`, the reverse
+/// of `to_ansi256()`.
+///
+/// # Example
+/// ```
+/// use farver::{from_ansi256, rgb, Color};
+///
+/// assert_eq!(from_ansi256(203), rgb(255, 95, 95));
+/// assert_eq!(from_ansi256(244), rgb(128, 128, 128));
+/// assert_eq!(from_ansi256(9), rgb(255, 0, 0));
+/// assert_eq!(from_ansi256(203).to_ansi256(), 203);
+/// ```
+pub fn from_ansi256(index: u8) -> RGB {
+    ANSI256[index as usize]
+}
+
+// Returns the index of the color of `palette` nearest to `color`. The first
+// one wins ties. Panics if the palette is empty, or holds more colors than
+// a `u8` can index.
+pub(crate) fn nearest<T: Color>(color: T, palette: &[RGB]) -> u8 {
+    assert!(!palette.is_empty(), "Empty palette");
+    assert!(palette.len() <= 256, "Invalid palette length");
+
+    let color = color.to_rgb();
+    let mut best = 0;
+
+    for (i, &candidate) in palette.iter().enumerate() {
+        if difference::redmean(color, candidate) < difference::redmean(color, palette[best]) {
+            best = i;
+        }
+    }
+
+    best as u8
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A color set by an SGR escape sequence, either for the foreground (the text)
+/// or the background of terminal output.
+pub enum AnsiColor {
+    Foreground(RGB),
+    Background(RGB),
+}
+
+/// Parses the colors set by the SGR escape sequences of terminal output, in
+/// the order they appear. The basic colors are read from `ANSI16_XTERM`, the
+/// indexed colors from `ANSI256`, and truecolors as is, whether their
+/// parameters are separated by semicolons or colons. Any other text and
+/// escape sequence is skipped.
+///
+/// # Example
+/// ```
+/// use farver::{parse_ansi, rgb, AnsiColor};
+///
+/// let output = "\x1b[1;38;2;255;99;71mtomato\x1b[0m on \x1b[48;5;24mblue\x1b[0m";
+///
+/// assert_eq!(
+///     parse_ansi(output),
+///     vec![
+///         AnsiColor::Foreground(rgb(255, 99, 71)),
+///         AnsiColor::Background(rgb(0, 95, 135)),
+///     ]
+/// );
+/// ```
+pub fn parse_ansi(input: &str) -> Vec<AnsiColor> {
+    let mut colors = Vec::new();
+    let mut rest = input;
+
+    while let Some(start) = rest.find("\x1b[") {
+        rest = &rest[start + 2..];
+
+        let end = match rest.find(|c: char| !(c.is_ascii_digit() || c == ';' || c == ':')) {
+            Some(end) => end,
+            None => break,
+        };
+
+        if rest[end..].starts_with('m') {
+            parse_sgr(&rest[..end], &mut colors);
+        }
+
+        rest = &rest[end..];
+    }
+
+    colors
+}
+
+// Parses the parameters of a single SGR escape sequence.
+fn parse_sgr(parameters: &str, colors: &mut Vec<AnsiColor>) {
+    let parameters: Vec<&str> = parameters.split(';').collect();
+    let mut i = 0;
+
+    while i < parameters.len() {
+        let mut arguments = parameters[i].split(':');
+        let code = arguments.next().and_then(|code| code.parse::<u8>().ok());
+        let arguments: Vec<&str> = arguments.collect();
+
+        let color = match code {
+            Some(code @ 30..=37) => Some(AnsiColor::Foreground(ANSI16_XTERM[(code - 30) as usize])),
+            Some(code @ 40..=47) => Some(AnsiColor::Background(ANSI16_XTERM[(code - 40) as usize])),
+            Some(code @ 90..=97) => Some(AnsiColor::Foreground(ANSI16_XTERM[(code - 82) as usize])),
+            Some(code @ 100..=107) => {
+                Some(AnsiColor::Background(ANSI16_XTERM[(code - 92) as usize]))
+            }
+            Some(code @ (38 | 48)) => {
+                // Colon separated arguments belong to the parameter itself,
+                // while semicolon separated ones follow it.
+                let color = if arguments.is_empty() {
+                    let (color, consumed) = parse_extended(&parameters[i + 1..], false);
+                    i += consumed;
+                    color
+                } else {
+                    parse_extended(&arguments, true).0
+                };
+
+                color.map(|color| {
+                    if code == 38 {
+                        AnsiColor::Foreground(color)
+                    } else {
+                        AnsiColor::Background(color)
+                    }
+                })
+            }
+            _ => None,
+        };
+
+        colors.extend(color);
+        i += 1;
+    }
+}
+
+// Parses the arguments of an extended color, either `5;n` or `2;r;g;b`, and
+// returns the color with the number of arguments used. The colon separated
+// form may also include a color space identifier, as in `2::r:g:b`.
+fn parse_extended(arguments: &[&str], colons: bool) -> (Option<RGB>, usize) {
+    let number = |i: usize| {
+        arguments
+            .get(i)
+            .and_then(|argument| argument.parse::<u8>().ok())
+    };
+
+    match arguments.first() {
+        Some(&"5") => (number(1).map(from_ansi256), 2),
+        Some(&"2") => {
+            let offset = if colons && arguments.len() > 4 { 2 } else { 1 };
+            let color = match (number(offset), number(offset + 1), number(offset + 2)) {
+                (Some(r), Some(g), Some(b)) => Some(rgb(r, g, b)),
+                _ => None,
+            };
+
+            (color, offset + 3)
+        }
+        _ => (None, 0),
+    }
+}
+
+// The SGR escape sequence setting a 24-bit color, either for the foreground
+// (38) or the background (48).
+pub(crate) fn truecolor<T: Color>(color: T, target: u8) -> String {
+    let RGB { r, g, b } = color.to_rgb();
+
+    format!(
+        "\x1b[{};2;{};{};{}m",
+        target,
+        r.as_u8(),
+        g.as_u8(),
+        b.as_u8()
+    )
+}
+
+/// Writes `label` on a block of `color` to a terminal, using truecolor escape
+/// sequences. The label is written in black or white, whichever is the most
+/// readable, and the style is reset afterwards. The alpha value, if any, is
+/// ignored.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, swatch};
+///
+/// let mut output = Vec::new();
+/// swatch(&mut output, rgb(255, 99, 71), "tomato").unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "\x1b[48;2;255;99;71m\x1b[38;2;0;0;0m tomato \x1b[0m"
+/// );
+/// ```
+pub fn swatch<W: io::Write, T: Color>(writer: &mut W, color: T, label: &str) -> io::Result<()> {
+    let color = color.to_rgba();
+
+    write!(
+        writer,
+        "{}{} {} {}",
+        color.to_ansi_bg(),
+        color.readable_on().to_ansi_fg(),
+        label,
+        ANSI_RESET
+    )
+}
+
+/// Writes a swatch for each of `colors` to a terminal, one per line, labelled
+/// with their hexadecimal notation. See `swatch()` for labels of your own.
+///
+/// # Example
+/// ```
+/// use farver::{print_palette, rgb};
+///
+/// let mut output = Vec::new();
+/// print_palette(&mut output, &[rgb(255, 255, 255), rgb(0, 0, 0)]).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "\x1b[48;2;255;255;255m\x1b[38;2;0;0;0m #ffffff \x1b[0m\n\
+///      \x1b[48;2;0;0;0m\x1b[38;2;255;255;255m #000000 \x1b[0m\n"
+/// );
+/// ```
+pub fn print_palette<W: io::Write, T: Color + Copy>(
+    writer: &mut W,
+    colors: &[T],
+) -> io::Result<()> {
+    for &color in colors {
+        swatch(writer, color, &color.to_hex())?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+// The channel levels of the 6x6x6 color cube of the xterm 256 colors palette,
+// found at indexes 16 to 231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// Returns the index of the cube level nearest to a channel value.
+fn cube_level(channel: u8) -> u8 {
+    match channel {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (channel - 35) / 40,
+    }
+}
+
+// Returns the xterm 256 colors index nearest to `color`, picking from either
+// the color cube or the grayscale ramp found at indexes 232 to 255. The first
+// 16 colors are left out, as they are usually redefined by terminal themes.
+pub(crate) fn to_ansi256<T: Color>(color: T) -> u8 {
+    let color = color.to_rgb();
+    let [r, g, b] = [color.r, color.g, color.b].map(|channel| cube_level(channel.as_u8()));
+    let cube = rgb(
+        CUBE_LEVELS[r as usize],
+        CUBE_LEVELS[g as usize],
+        CUBE_LEVELS[b as usize],
+    );
+
+    // The ramp goes from 8 to 238 in steps of 10.
+    let average = (color.r.as_u8() as u16 + color.g.as_u8() as u16 + color.b.as_u8() as u16) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = rgb(8 + step * 10, 8 + step * 10, 8 + step * 10);
+
+    if difference::redmean(color, grey) < difference::redmean(color, cube) {
+        232 + step
+    } else {
+        16 + 36 * r + 6 * g + b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        from_ansi256, hsl, parse_ansi, print_palette, rgb, rgba, swatch, AnsiColor, Color,
+        ANSI16_VGA, ANSI16_XTERM, ANSI256,
+    };
+
+    #[test]
+    fn can_output_truecolor_sequences() {
+        assert_eq!(rgb(255, 99, 71).to_ansi_fg(), "\x1b[38;2;255;99;71m");
+        assert_eq!(rgb(255, 99, 71).to_ansi_bg(), "\x1b[48;2;255;99;71m");
+        assert_eq!(hsl(0, 0, 0).to_ansi_fg(), "\x1b[38;2;0;0;0m");
+        assert_eq!(rgba(23, 98, 119, 0.5).to_ansi_bg(), "\x1b[48;2;23;98;119m");
+        assert_eq!(crate::ANSI_RESET, "\x1b[0m");
+    }
+
+    #[test]
+    fn can_parse_sequences() {
+        assert_eq!(
+            parse_ansi("\x1b[31mred\x1b[0m \x1b[1;92;104mgreen on blue\x1b[m"),
+            vec![
+                AnsiColor::Foreground(rgb(205, 0, 0)),
+                AnsiColor::Foreground(rgb(0, 255, 0)),
+                AnsiColor::Background(rgb(92, 92, 255)),
+            ]
+        );
+        assert_eq!(
+            parse_ansi("\x1b[38;5;203;48;2;23;98;119m"),
+            vec![
+                AnsiColor::Foreground(rgb(255, 95, 95)),
+                AnsiColor::Background(rgb(23, 98, 119)),
+            ]
+        );
+    }
+
+    #[test]
+    fn can_parse_colon_separated_sequences() {
+        assert_eq!(
+            parse_ansi("\x1b[38:2::255:99:71m\x1b[48:2:23:98:119m\x1b[38:5:9m"),
+            vec![
+                AnsiColor::Foreground(rgb(255, 99, 71)),
+                AnsiColor::Background(rgb(23, 98, 119)),
+                AnsiColor::Foreground(rgb(255, 0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trips_truecolor_sequences() {
+        let tomato = rgb(255, 99, 71);
+        let output = format!("{}{}text", tomato.to_ansi_fg(), tomato.to_ansi_bg());
+
+        assert_eq!(
+            parse_ansi(&output),
+            vec![AnsiColor::Foreground(tomato), AnsiColor::Background(tomato)]
+        );
+    }
+
+    #[test]
+    fn skips_invalid_sequences() {
+        assert_eq!(parse_ansi("plain text"), vec![]);
+        assert_eq!(parse_ansi("\x1b[2J\x1b[31"), vec![]);
+        assert_eq!(parse_ansi("\x1b[38;2;255;99m"), vec![]);
+        assert_eq!(
+            parse_ansi("\x1b[38;5;300;31m"),
+            vec![AnsiColor::Foreground(rgb(205, 0, 0))]
+        );
+    }
+
+    #[test]
+    fn can_write_swatches() {
+        let mut output = Vec::new();
+        swatch(&mut output, hsl(240, 100, 25), "navy").unwrap();
+        swatch(&mut output, rgba(255, 255, 0, 0.5), "").unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\x1b[48;2;0;0;128m\x1b[38;2;255;255;255m navy \x1b[0m\
+             \x1b[48;2;255;255;0m\x1b[38;2;0;0;0m  \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn can_print_palettes() {
+        let mut output = Vec::new();
+        print_palette(&mut output, &[rgba(255, 99, 71, 0.5)]).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.contains(" #ff634780 "));
+        assert_eq!(
+            parse_ansi(&output),
+            vec![
+                AnsiColor::Background(rgb(255, 99, 71)),
+                AnsiColor::Foreground(rgb(0, 0, 0)),
+            ]
+        );
+
+        let mut output = Vec::new();
+        print_palette::<_, crate::RGB>(&mut output, &[]).unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn can_approximate_basic_colors() {
+        assert_eq!(rgb(255, 99, 71).to_ansi16(), 9);
+        assert_eq!(rgb(139, 0, 0).to_ansi16(), 1);
+        assert_eq!(rgb(23, 98, 119).to_ansi16_with(&ANSI16_XTERM), 8);
+        assert_eq!(rgb(0, 0, 0).to_ansi16(), 0);
+        assert_eq!(hsl(0, 0, 100).to_ansi16(), 15);
+
+        for (i, &color) in ANSI16_XTERM.iter().enumerate() {
+            assert_eq!(color.to_ansi16(), i as u8);
+        }
+    }
+
+    #[test]
+    fn can_use_other_palettes() {
+        let orange = rgb(170, 85, 0);
+
+        assert_eq!(orange.to_ansi16_with(&ANSI16_VGA), 3);
+        assert_eq!(rgb(255, 99, 71).to_ansi16_with(&ANSI16_XTERM[..8]), 1);
+
+        for (i, &color) in ANSI16_VGA.iter().enumerate() {
+            assert_eq!(color.to_ansi16_with(&ANSI16_VGA), i as u8);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_empty_palettes() {
+        rgb(255, 99, 71).to_ansi16_with(&[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_oversized_palettes() {
+        rgb(255, 99, 71).to_ansi16_with(&[rgb(0, 0, 0); 257]);
+    }
+
+    #[test]
+    fn has_reference_table() {
+        assert_eq!(ANSI256[..16], ANSI16_XTERM);
+        assert_eq!(from_ansi256(16), rgb(0, 0, 0));
+        assert_eq!(from_ansi256(24), rgb(0, 95, 135));
+        assert_eq!(from_ansi256(231), rgb(255, 255, 255));
+        assert_eq!(from_ansi256(232), rgb(8, 8, 8));
+        assert_eq!(from_ansi256(255), rgb(238, 238, 238));
+    }
+
+    #[test]
+    fn can_round_trip() {
+        for index in 16..=255 {
+            assert_eq!(from_ansi256(index).to_ansi256(), index);
+        }
+    }
+
+    #[test]
+    fn can_find_cube_colors() {
+        assert_eq!(rgb(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(rgb(255, 255, 255).to_ansi256(), 231);
+        assert_eq!(rgb(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(rgb(0, 95, 135).to_ansi256(), 24);
+        assert_eq!(rgb(255, 99, 71).to_ansi256(), 203);
+        assert_eq!(hsl(120, 100, 25).to_ansi256(), 28);
+        assert_eq!(rgba(255, 0, 0, 0.5).to_ansi256(), 196);
+    }
+
+    #[test]
+    fn can_find_grayscale_colors() {
+        assert_eq!(rgb(8, 8, 8).to_ansi256(), 232);
+        assert_eq!(rgb(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(rgb(238, 238, 238).to_ansi256(), 255);
+        assert_eq!(rgb(135, 135, 135).to_ansi256(), 102);
+        assert_eq!(rgb(30, 31, 29).to_ansi256(), 234);
+    }
+
+    #[test]
+    fn rounds_to_nearest_level() {
+        assert_eq!(rgb(255, 255, 47).to_ansi256(), 226);
+        assert_eq!(rgb(255, 255, 48).to_ansi256(), 227);
+        assert_eq!(rgb(255, 255, 114).to_ansi256(), 227);
+        assert_eq!(rgb(255, 255, 115).to_ansi256(), 228);
+    }
+}
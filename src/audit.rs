@@ -0,0 +1,228 @@
+use super::{Color, ContrastRatio, Level, TextSize};
+use std::fmt;
+
+/// Computes the WCAG 2.x contrast ratio between every pair of `colors`, the
+/// basis of accessibility reports for design systems.
+///
+/// # Example
+/// ```
+/// use farver::{contrast_matrix, rgb, Level, TextSize};
+///
+/// let matrix = contrast_matrix(&[rgb(255, 255, 255), rgb(23, 98, 119), rgb(0, 0, 0)]);
+///
+/// assert_eq!(matrix.ratio(0, 2).to_string(), "21.00:1");
+/// assert!(matrix.meets(0, 1, Level::AA, TextSize::Normal));
+/// assert!(!matrix.meets(1, 2, Level::AA, TextSize::Normal));
+/// assert_eq!(matrix.pairs().count(), 3);
+/// ```
+pub fn contrast_matrix<T: Color + Copy>(colors: &[T]) -> ContrastMatrix {
+    let mut ratios = Vec::with_capacity(colors.len() * colors.len());
+
+    for &lhs in colors {
+        for &rhs in colors {
+            ratios.push(lhs.contrast_ratio(rhs));
+        }
+    }
+
+    ContrastMatrix {
+        len: colors.len(),
+        ratios,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct that holds the contrast ratio between every pair of colors of a
+/// palette, indexed by their position in the palette.
+pub struct ContrastMatrix {
+    len: usize,
+    ratios: Vec<ContrastRatio>,
+}
+
+impl ContrastMatrix {
+    /// Returns the number of colors the matrix was built from.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the contrast ratio between the colors at index `lhs` and `rhs`.
+    /// Panics if either index is out of bounds.
+    pub fn ratio(&self, lhs: usize, rhs: usize) -> ContrastRatio {
+        assert!(lhs < self.len && rhs < self.len, "Invalid color index");
+
+        self.ratios[lhs * self.len + rhs]
+    }
+
+    /// Returns whether the colors at index `lhs` and `rhs` conform to `level`
+    /// for text of the given size. Panics if either index is out of bounds.
+    pub fn meets(&self, lhs: usize, rhs: usize, level: Level, size: TextSize) -> bool {
+        self.ratio(lhs, rhs).meets(level, size)
+    }
+
+    /// Iterates over every unordered pair of distinct colors, once each.
+    pub fn pairs(&self) -> impl Iterator<Item = ContrastPair> + '_ {
+        (0..self.len).flat_map(move |lhs| {
+            (lhs + 1..self.len).map(move |rhs| ContrastPair {
+                lhs,
+                rhs,
+                ratio: self.ratio(lhs, rhs),
+            })
+        })
+    }
+
+    /// Iterates over the pairs of distinct colors conforming to `level` for
+    /// text of the given size.
+    pub fn passing(&self, level: Level, size: TextSize) -> impl Iterator<Item = ContrastPair> + '_ {
+        self.pairs()
+            .filter(move |pair| pair.ratio.meets(level, size))
+    }
+
+    /// Iterates over the pairs of distinct colors failing to conform to
+    /// `level` for text of the given size.
+    pub fn failing(&self, level: Level, size: TextSize) -> impl Iterator<Item = ContrastPair> + '_ {
+        self.pairs()
+            .filter(move |pair| !pair.ratio.meets(level, size))
+    }
+}
+
+// Prints the matrix as a table of ratios, one row per color.
+impl fmt::Display for ContrastMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for lhs in 0..self.len {
+            for rhs in 0..self.len {
+                if rhs > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "{:>5.02}", self.ratio(lhs, rhs).as_f32())?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that represents the contrast between two colors of a `ContrastMatrix`.
+pub struct ContrastPair {
+    // index of the first color
+    pub lhs: usize,
+
+    // index of the second color
+    pub rhs: usize,
+
+    pub ratio: ContrastRatio,
+}
+
+impl ContrastPair {
+    /// Returns the highest level the pair conforms to for text of the given
+    /// size, or `None` if it does not even conform to `Level::AA`.
+    pub fn level(self, size: TextSize) -> Option<Level> {
+        if self.ratio.meets(Level::AAA, size) {
+            Some(Level::AAA)
+        } else if self.ratio.meets(Level::AA, size) {
+            Some(Level::AA)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{contrast_matrix, hsl, rgb, Color, Level, TextSize, RGB};
+
+    fn palette() -> [RGB; 4] {
+        [
+            rgb(255, 255, 255),
+            rgb(23, 98, 119),
+            rgb(255, 99, 71),
+            rgb(0, 0, 0),
+        ]
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let matrix = contrast_matrix(&palette());
+
+        assert_eq!(matrix.len(), 4);
+
+        for lhs in 0..4 {
+            assert_eq!(matrix.ratio(lhs, lhs).as_f32(), 1.0);
+
+            for rhs in 0..4 {
+                assert_eq!(matrix.ratio(lhs, rhs), matrix.ratio(rhs, lhs));
+                assert_eq!(
+                    matrix.ratio(lhs, rhs),
+                    palette()[lhs].contrast_ratio(palette()[rhs])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_iterate_pairs() {
+        let matrix = contrast_matrix(&palette());
+        let pairs: Vec<(usize, usize)> = matrix.pairs().map(|pair| (pair.lhs, pair.rhs)).collect();
+
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn can_filter_pairs() {
+        let matrix = contrast_matrix(&palette());
+
+        let passing: Vec<(usize, usize)> = matrix
+            .passing(Level::AAA, TextSize::Normal)
+            .map(|pair| (pair.lhs, pair.rhs))
+            .collect();
+        assert_eq!(passing, vec![(0, 3), (2, 3)]);
+
+        let failing: Vec<(usize, usize)> = matrix
+            .failing(Level::AA, TextSize::Large)
+            .map(|pair| (pair.lhs, pair.rhs))
+            .collect();
+        assert_eq!(failing, vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn can_report_levels() {
+        let matrix = contrast_matrix(&palette());
+        let levels: Vec<Option<Level>> = matrix
+            .pairs()
+            .map(|pair| pair.level(TextSize::Normal))
+            .collect();
+
+        assert_eq!(
+            levels,
+            vec![
+                Some(Level::AA),
+                None,
+                Some(Level::AAA),
+                None,
+                None,
+                Some(Level::AAA)
+            ]
+        );
+    }
+
+    #[test]
+    fn can_display() {
+        let matrix = contrast_matrix(&[hsl(0, 0, 100), hsl(0, 0, 0)]);
+
+        assert_eq!(matrix.to_string(), " 1.00 21.00\n21.00  1.00\n");
+        assert_eq!(contrast_matrix::<RGB>(&[]).to_string(), "");
+        assert!(contrast_matrix::<RGB>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_index() {
+        contrast_matrix(&palette()).ratio(4, 0);
+    }
+}
@@ -0,0 +1,188 @@
+use super::{oklab::normalize_hue, Color, ColorSpace, Ratio, RGB, RGBA};
+
+/// Averages `colors` in linear light, the way light physically adds up.
+/// Returns `None` if there are no colors. See `average_in()` to average in
+/// another color space.
+///
+/// # Example
+/// ```
+/// use farver::{average, rgb, rgba};
+///
+/// let grey = average(&[rgb(0, 0, 0), rgb(255, 255, 255)]);
+///
+/// assert_eq!(grey, Some(rgba(188, 188, 188, 1.0)));
+/// ```
+pub fn average<T: Color + Copy>(colors: &[T]) -> Option<RGBA> {
+    average_in(colors, ColorSpace::LinearRGB)
+}
+
+/// Averages `colors` in the given color space, along with their alpha values.
+/// Returns `None` if there are no colors.
+///
+/// # Example
+/// ```
+/// use farver::{average_in, rgb, rgba, ColorSpace};
+///
+/// let grey = average_in(&[rgb(0, 0, 0), rgb(255, 255, 255)], ColorSpace::SRGB);
+///
+/// assert_eq!(grey, Some(rgba(128, 128, 128, 1.0)));
+/// ```
+pub fn average_in<T: Color + Copy>(colors: &[T], space: ColorSpace) -> Option<RGBA> {
+    blend(colors.iter().map(|&color| (color, 1.0)), space)
+}
+
+/// Blends `colors` in linear light, each in proportion to its weight. Weights
+/// need not add up to one, but cannot be negative. Returns `None` if there are
+/// no colors, or if the weights add up to zero.
+///
+/// # Example
+/// ```
+/// use farver::{blend_weighted, rgb, rgba};
+///
+/// let blend = blend_weighted(&[(rgb(255, 0, 0), 3.0), (rgb(0, 0, 255), 1.0)]);
+///
+/// assert_eq!(blend, Some(rgba(225, 0, 137, 1.0)));
+/// ```
+pub fn blend_weighted<T: Color + Copy>(colors: &[(T, f32)]) -> Option<RGBA> {
+    blend_weighted_in(colors, ColorSpace::LinearRGB)
+}
+
+/// Blends `colors` in the given color space, each in proportion to its weight,
+/// along with their alpha values. Weights need not add up to one, but cannot
+/// be negative. Returns `None` if there are no colors, or if the weights add
+/// up to zero.
+///
+/// # Example
+/// ```
+/// use farver::{blend_weighted_in, rgb, rgba, ColorSpace};
+///
+/// let colors = [(rgb(255, 0, 0), 3.0), (rgb(0, 0, 255), 1.0)];
+///
+/// assert_eq!(
+///     blend_weighted_in(&colors, ColorSpace::SRGB),
+///     Some(rgba(191, 0, 64, 1.0))
+/// );
+/// ```
+pub fn blend_weighted_in<T: Color + Copy>(colors: &[(T, f32)], space: ColorSpace) -> Option<RGBA> {
+    blend(colors.iter().copied(), space)
+}
+
+fn blend<T: Color + Copy>(
+    colors: impl Iterator<Item = (T, f32)>,
+    space: ColorSpace,
+) -> Option<RGBA> {
+    let mut channels = [0.0; 3];
+    let mut alpha = 0.0;
+    let mut total = 0.0;
+
+    // Hues are averaged around the wheel, leaving out powerless hues.
+    let hue = space.hue_channel();
+    let (mut cos, mut sin) = (0.0, 0.0);
+
+    for (color, weight) in colors {
+        assert!(weight >= 0.0, "Invalid weight");
+
+        let color = color.to_rgba();
+
+        for (i, channel) in space.decompose(color).into_iter().enumerate() {
+            if Some(i) != hue {
+                channels[i] += channel * weight;
+            } else if !channel.is_nan() {
+                cos += channel.to_radians().cos() * weight;
+                sin += channel.to_radians().sin() * weight;
+            }
+        }
+
+        alpha += color.a.as_f32() * weight;
+        total += weight;
+    }
+
+    if total <= 0.0 {
+        return None;
+    }
+
+    let mut channels = channels.map(|sum| sum / total);
+
+    if let Some(i) = hue {
+        channels[i] = if cos == 0.0 && sin == 0.0 {
+            f32::NAN
+        } else {
+            normalize_hue(f32::atan2(sin, cos).to_degrees())
+        };
+    }
+
+    let RGB { r, g, b } = space.compose(channels);
+
+    Some(RGBA {
+        r,
+        g,
+        b,
+        a: Ratio::from_f32((alpha / total).clamp(0.0, 1.0)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        average, average_in, blend_weighted, blend_weighted_in, hsl, rgb, rgba, ColorSpace, RGB,
+    };
+
+    #[test]
+    fn can_average() {
+        let colors = [rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255)];
+
+        assert_eq!(average(&colors), Some(rgba(156, 156, 156, 1.0)));
+        assert_eq!(
+            average_in(&colors, ColorSpace::SRGB),
+            Some(rgba(85, 85, 85, 1.0))
+        );
+        assert_eq!(average(&[hsl(9, 100, 64)]), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(average::<RGB>(&[]), None);
+    }
+
+    #[test]
+    fn depends_on_space() {
+        let colors = [rgb(0, 0, 0), rgb(255, 255, 255)];
+
+        let lab = average_in(&colors, ColorSpace::LAB).unwrap();
+        let oklab = average_in(&colors, ColorSpace::OKLAB).unwrap();
+
+        assert_eq!(lab.r, lab.g);
+        assert_ne!(lab, oklab);
+        assert!(lab.r.as_u8() < 188 && lab.r.as_u8() > 100);
+    }
+
+    #[test]
+    fn averages_alpha() {
+        assert_eq!(
+            average_in(
+                &[rgba(255, 0, 0, 1.0), rgba(255, 0, 0, 0.0)],
+                ColorSpace::SRGB
+            ),
+            Some(rgba(255, 0, 0, 0.5))
+        );
+    }
+
+    #[test]
+    fn can_blend_weighted() {
+        assert_eq!(
+            blend_weighted(&[(rgb(255, 0, 0), 1.0), (rgb(0, 0, 255), 0.0)]),
+            Some(rgba(255, 0, 0, 1.0))
+        );
+        assert_eq!(
+            blend_weighted_in(
+                &[(rgb(255, 0, 0), 2.0), (rgb(0, 0, 255), 2.0)],
+                ColorSpace::SRGB
+            ),
+            Some(rgba(128, 0, 128, 1.0))
+        );
+        assert_eq!(blend_weighted(&[(rgb(255, 0, 0), 0.0)]), None);
+        assert_eq!(blend_weighted::<RGB>(&[]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_negative_weights() {
+        blend_weighted(&[(rgb(255, 0, 0), -1.0)]);
+    }
+}
@@ -0,0 +1,909 @@
+use super::{rgb, Palette, RGB};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The kinds of ColorBrewer schemes.
+pub enum BrewerKind {
+    /// Schemes for ordered data, from light to dark. They come in 3 to 9
+    /// classes.
+    Sequential,
+
+    /// Schemes for data diverging from a critical midpoint, with a light
+    /// middle between two dark ends. They come in 3 to 11 classes.
+    Diverging,
+
+    /// Schemes for categories without any order. They come in 3 classes up
+    /// to the number of colors of the scheme.
+    Qualitative,
+}
+
+// The letters of the colors making up each class count of sequential and
+// diverging schemes, as ColorBrewer picks them so that every class count
+// spans the whole scheme.
+const SEQUENTIAL_CLASSES: [&str; 7] = [
+    "CFI",
+    "BEGJ",
+    "BEGIK",
+    "BDFGIK",
+    "BDFGHJL",
+    "ACDFGHJL",
+    "ACDFGHJKM",
+];
+const DIVERGING_CLASSES: [&str; 9] = [
+    "EHK",
+    "CFJM",
+    "CFHJM",
+    "BEGIKN",
+    "BEGHIKN",
+    "BDFGIJLN",
+    "BDFGHIJLN",
+    "ABDFGIJLNO",
+    "ABDFGHIJLNO",
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A struct to represent one of Cynthia Brewer's ColorBrewer schemes, as
+/// found in `BREWER_SCHEMES`.
+///
+/// For more, see [ColorBrewer](https://colorbrewer2.org).
+pub struct BrewerScheme {
+    name: &'static str,
+    kind: BrewerKind,
+
+    // every color the classes pick from, in order
+    colors: &'static [RGB],
+}
+
+impl BrewerScheme {
+    /// Returns the name of the scheme, as ColorBrewer calls it.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the kind of the scheme, which tells the data it suits.
+    pub fn kind(&self) -> BrewerKind {
+        self.kind
+    }
+
+    /// Returns the largest number of classes the scheme comes in. They all
+    /// come in at least 3 classes.
+    pub fn max_classes(&self) -> usize {
+        match self.kind {
+            BrewerKind::Sequential => SEQUENTIAL_CLASSES.len() + 2,
+            BrewerKind::Diverging => DIVERGING_CLASSES.len() + 2,
+            BrewerKind::Qualitative => self.colors.len(),
+        }
+    }
+
+    /// Returns the colors of the scheme for `classes` classes, or `None` if
+    /// it does not come in that many.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{brewer_scheme, rgb, Color};
+    ///
+    /// let blues = brewer_scheme("Blues").unwrap();
+    ///
+    /// assert_eq!(blues.classes(3).unwrap().get(0), Some(rgb(222, 235, 247).to_rgba()));
+    /// assert_eq!(blues.classes(10), None);
+    /// ```
+    pub fn classes(&self, classes: usize) -> Option<Palette> {
+        if classes < 3 || classes > self.max_classes() {
+            return None;
+        }
+
+        let letters = match self.kind {
+            BrewerKind::Sequential => SEQUENTIAL_CLASSES[classes - 3],
+            BrewerKind::Diverging => DIVERGING_CLASSES[classes - 3],
+            BrewerKind::Qualitative => {
+                return Some(self.colors[..classes].iter().copied().collect())
+            }
+        };
+
+        Some(
+            letters
+                .bytes()
+                .map(|letter| self.colors[(letter - b'A') as usize])
+                .collect(),
+        )
+    }
+}
+
+/// Finds a ColorBrewer scheme by its name, ignoring case.
+///
+/// # Example
+/// ```
+/// use farver::{brewer_scheme, BrewerKind};
+///
+/// assert_eq!(brewer_scheme("rdylbu").unwrap().name(), "RdYlBu");
+/// assert_eq!(brewer_scheme("Set2").unwrap().kind(), BrewerKind::Qualitative);
+/// assert_eq!(brewer_scheme("Rainbow"), None);
+/// ```
+pub fn brewer_scheme(name: &str) -> Option<BrewerScheme> {
+    BREWER_SCHEMES
+        .iter()
+        .copied()
+        .find(|scheme| scheme.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the colors of the named ColorBrewer scheme for `classes` classes,
+/// or `None` if there is no such scheme or if it does not come in that many.
+///
+/// # Example
+/// ```
+/// use farver::{brewer, rgb, Color};
+///
+/// let palette = brewer("RdBu", 5).unwrap();
+///
+/// assert_eq!(palette.len(), 5);
+/// assert_eq!(palette.get(2), Some(rgb(247, 247, 247).to_rgba()));
+/// assert_eq!(brewer("RdBu", 12), None);
+/// ```
+pub fn brewer(name: &str, classes: usize) -> Option<Palette> {
+    brewer_scheme(name)?.classes(classes)
+}
+
+/// Every ColorBrewer scheme: the sequential ones first, then the diverging
+/// and the qualitative ones, each in alphabetical order.
+pub const BREWER_SCHEMES: [BrewerScheme; 35] = [
+    BrewerScheme {
+        name: "Blues",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xFB, 0xFF),
+            rgb(0xEF, 0xF3, 0xFF),
+            rgb(0xDE, 0xEB, 0xF7),
+            rgb(0xC6, 0xDB, 0xEF),
+            rgb(0xBD, 0xD7, 0xE7),
+            rgb(0x9E, 0xCA, 0xE1),
+            rgb(0x6B, 0xAE, 0xD6),
+            rgb(0x42, 0x92, 0xC6),
+            rgb(0x31, 0x82, 0xBD),
+            rgb(0x21, 0x71, 0xB5),
+            rgb(0x08, 0x51, 0x9C),
+            rgb(0x08, 0x45, 0x94),
+            rgb(0x08, 0x30, 0x6B),
+        ],
+    },
+    BrewerScheme {
+        name: "BuGn",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xFC, 0xFD),
+            rgb(0xED, 0xF8, 0xFB),
+            rgb(0xE5, 0xF5, 0xF9),
+            rgb(0xCC, 0xEC, 0xE6),
+            rgb(0xB2, 0xE2, 0xE2),
+            rgb(0x99, 0xD8, 0xC9),
+            rgb(0x66, 0xC2, 0xA4),
+            rgb(0x41, 0xAE, 0x76),
+            rgb(0x2C, 0xA2, 0x5F),
+            rgb(0x23, 0x8B, 0x45),
+            rgb(0x00, 0x6D, 0x2C),
+            rgb(0x00, 0x58, 0x24),
+            rgb(0x00, 0x44, 0x1B),
+        ],
+    },
+    BrewerScheme {
+        name: "BuPu",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xFC, 0xFD),
+            rgb(0xED, 0xF8, 0xFB),
+            rgb(0xE0, 0xEC, 0xF4),
+            rgb(0xBF, 0xD3, 0xE6),
+            rgb(0xB3, 0xCD, 0xE3),
+            rgb(0x9E, 0xBC, 0xDA),
+            rgb(0x8C, 0x96, 0xC6),
+            rgb(0x8C, 0x6B, 0xB1),
+            rgb(0x88, 0x56, 0xA7),
+            rgb(0x88, 0x41, 0x9D),
+            rgb(0x81, 0x0F, 0x7C),
+            rgb(0x6E, 0x01, 0x6B),
+            rgb(0x4D, 0x00, 0x4B),
+        ],
+    },
+    BrewerScheme {
+        name: "GnBu",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xFC, 0xF0),
+            rgb(0xF0, 0xF9, 0xE8),
+            rgb(0xE0, 0xF3, 0xDB),
+            rgb(0xCC, 0xEB, 0xC5),
+            rgb(0xBA, 0xE4, 0xBC),
+            rgb(0xA8, 0xDD, 0xB5),
+            rgb(0x7B, 0xCC, 0xC4),
+            rgb(0x4E, 0xB3, 0xD3),
+            rgb(0x43, 0xA2, 0xCA),
+            rgb(0x2B, 0x8C, 0xBE),
+            rgb(0x08, 0x68, 0xAC),
+            rgb(0x08, 0x58, 0x9E),
+            rgb(0x08, 0x40, 0x81),
+        ],
+    },
+    BrewerScheme {
+        name: "Greens",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xFC, 0xF5),
+            rgb(0xED, 0xF8, 0xE9),
+            rgb(0xE5, 0xF5, 0xE0),
+            rgb(0xC7, 0xE9, 0xC0),
+            rgb(0xBA, 0xE4, 0xB3),
+            rgb(0xA1, 0xD9, 0x9B),
+            rgb(0x74, 0xC4, 0x76),
+            rgb(0x41, 0xAB, 0x5D),
+            rgb(0x31, 0xA3, 0x54),
+            rgb(0x23, 0x8B, 0x45),
+            rgb(0x00, 0x6D, 0x2C),
+            rgb(0x00, 0x5A, 0x32),
+            rgb(0x00, 0x44, 0x1B),
+        ],
+    },
+    BrewerScheme {
+        name: "Greys",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xFF, 0xFF),
+            rgb(0xF7, 0xF7, 0xF7),
+            rgb(0xF0, 0xF0, 0xF0),
+            rgb(0xD9, 0xD9, 0xD9),
+            rgb(0xCC, 0xCC, 0xCC),
+            rgb(0xBD, 0xBD, 0xBD),
+            rgb(0x96, 0x96, 0x96),
+            rgb(0x73, 0x73, 0x73),
+            rgb(0x63, 0x63, 0x63),
+            rgb(0x52, 0x52, 0x52),
+            rgb(0x25, 0x25, 0x25),
+            rgb(0x25, 0x25, 0x25),
+            rgb(0x00, 0x00, 0x00),
+        ],
+    },
+    BrewerScheme {
+        name: "Oranges",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF5, 0xEB),
+            rgb(0xFE, 0xED, 0xDE),
+            rgb(0xFE, 0xE6, 0xCE),
+            rgb(0xFD, 0xD0, 0xA2),
+            rgb(0xFD, 0xBE, 0x85),
+            rgb(0xFD, 0xAE, 0x6B),
+            rgb(0xFD, 0x8D, 0x3C),
+            rgb(0xF1, 0x69, 0x13),
+            rgb(0xE6, 0x55, 0x0D),
+            rgb(0xD9, 0x48, 0x01),
+            rgb(0xA6, 0x36, 0x03),
+            rgb(0x8C, 0x2D, 0x04),
+            rgb(0x7F, 0x27, 0x04),
+        ],
+    },
+    BrewerScheme {
+        name: "OrRd",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF7, 0xEC),
+            rgb(0xFE, 0xF0, 0xD9),
+            rgb(0xFE, 0xE8, 0xC8),
+            rgb(0xFD, 0xD4, 0x9E),
+            rgb(0xFD, 0xCC, 0x8A),
+            rgb(0xFD, 0xBB, 0x84),
+            rgb(0xFC, 0x8D, 0x59),
+            rgb(0xEF, 0x65, 0x48),
+            rgb(0xE3, 0x4A, 0x33),
+            rgb(0xD7, 0x30, 0x1F),
+            rgb(0xB3, 0x00, 0x00),
+            rgb(0x99, 0x00, 0x00),
+            rgb(0x7F, 0x00, 0x00),
+        ],
+    },
+    BrewerScheme {
+        name: "PuBu",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF7, 0xFB),
+            rgb(0xF1, 0xEE, 0xF6),
+            rgb(0xEC, 0xE7, 0xF2),
+            rgb(0xD0, 0xD1, 0xE6),
+            rgb(0xBD, 0xC9, 0xE1),
+            rgb(0xA6, 0xBD, 0xDB),
+            rgb(0x74, 0xA9, 0xCF),
+            rgb(0x36, 0x90, 0xC0),
+            rgb(0x2B, 0x8C, 0xBE),
+            rgb(0x05, 0x70, 0xB0),
+            rgb(0x04, 0x5A, 0x8D),
+            rgb(0x03, 0x4E, 0x7B),
+            rgb(0x02, 0x38, 0x58),
+        ],
+    },
+    BrewerScheme {
+        name: "PuBuGn",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF7, 0xFB),
+            rgb(0xF6, 0xEF, 0xF7),
+            rgb(0xEC, 0xE2, 0xF0),
+            rgb(0xD0, 0xD1, 0xE6),
+            rgb(0xBD, 0xC9, 0xE1),
+            rgb(0xA6, 0xBD, 0xDB),
+            rgb(0x67, 0xA9, 0xCF),
+            rgb(0x36, 0x90, 0xC0),
+            rgb(0x1C, 0x90, 0x99),
+            rgb(0x02, 0x81, 0x8A),
+            rgb(0x01, 0x6C, 0x59),
+            rgb(0x01, 0x64, 0x50),
+            rgb(0x01, 0x46, 0x36),
+        ],
+    },
+    BrewerScheme {
+        name: "PuRd",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xF7, 0xF4, 0xF9),
+            rgb(0xF1, 0xEE, 0xF6),
+            rgb(0xE7, 0xE1, 0xEF),
+            rgb(0xD4, 0xB9, 0xDA),
+            rgb(0xD7, 0xB5, 0xD8),
+            rgb(0xC9, 0x94, 0xC7),
+            rgb(0xDF, 0x65, 0xB0),
+            rgb(0xE7, 0x29, 0x8A),
+            rgb(0xDD, 0x1C, 0x77),
+            rgb(0xCE, 0x12, 0x56),
+            rgb(0x98, 0x00, 0x43),
+            rgb(0x91, 0x00, 0x3F),
+            rgb(0x67, 0x00, 0x1F),
+        ],
+    },
+    BrewerScheme {
+        name: "Purples",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFC, 0xFB, 0xFD),
+            rgb(0xF2, 0xF0, 0xF7),
+            rgb(0xEF, 0xED, 0xF5),
+            rgb(0xDA, 0xDA, 0xEB),
+            rgb(0xCB, 0xC9, 0xE2),
+            rgb(0xBC, 0xBD, 0xDC),
+            rgb(0x9E, 0x9A, 0xC8),
+            rgb(0x80, 0x7D, 0xBA),
+            rgb(0x75, 0x6B, 0xB1),
+            rgb(0x6A, 0x51, 0xA3),
+            rgb(0x54, 0x27, 0x8F),
+            rgb(0x4A, 0x14, 0x86),
+            rgb(0x3F, 0x00, 0x7D),
+        ],
+    },
+    BrewerScheme {
+        name: "RdPu",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF7, 0xF3),
+            rgb(0xFE, 0xEB, 0xE2),
+            rgb(0xFD, 0xE0, 0xDD),
+            rgb(0xFC, 0xC5, 0xC0),
+            rgb(0xFB, 0xB4, 0xB9),
+            rgb(0xFA, 0x9F, 0xB5),
+            rgb(0xF7, 0x68, 0xA1),
+            rgb(0xDD, 0x34, 0x97),
+            rgb(0xC5, 0x1B, 0x8A),
+            rgb(0xAE, 0x01, 0x7E),
+            rgb(0x7A, 0x01, 0x77),
+            rgb(0x7A, 0x01, 0x77),
+            rgb(0x49, 0x00, 0x6A),
+        ],
+    },
+    BrewerScheme {
+        name: "Reds",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xF5, 0xF0),
+            rgb(0xFE, 0xE5, 0xD9),
+            rgb(0xFE, 0xE0, 0xD2),
+            rgb(0xFC, 0xBB, 0xA1),
+            rgb(0xFC, 0xAE, 0x91),
+            rgb(0xFC, 0x92, 0x72),
+            rgb(0xFB, 0x6A, 0x4A),
+            rgb(0xEF, 0x3B, 0x2C),
+            rgb(0xDE, 0x2D, 0x26),
+            rgb(0xCB, 0x18, 0x1D),
+            rgb(0xA5, 0x0F, 0x15),
+            rgb(0x99, 0x00, 0x0D),
+            rgb(0x67, 0x00, 0x0D),
+        ],
+    },
+    BrewerScheme {
+        name: "YlGn",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xFF, 0xE5),
+            rgb(0xFF, 0xFF, 0xCC),
+            rgb(0xF7, 0xFC, 0xB9),
+            rgb(0xD9, 0xF0, 0xA3),
+            rgb(0xC2, 0xE6, 0x99),
+            rgb(0xAD, 0xDD, 0x8E),
+            rgb(0x78, 0xC6, 0x79),
+            rgb(0x41, 0xAB, 0x5D),
+            rgb(0x31, 0xA3, 0x54),
+            rgb(0x23, 0x84, 0x43),
+            rgb(0x00, 0x68, 0x37),
+            rgb(0x00, 0x5A, 0x32),
+            rgb(0x00, 0x45, 0x29),
+        ],
+    },
+    BrewerScheme {
+        name: "YlGnBu",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xFF, 0xD9),
+            rgb(0xFF, 0xFF, 0xCC),
+            rgb(0xED, 0xF8, 0xB1),
+            rgb(0xC7, 0xE9, 0xB4),
+            rgb(0xA1, 0xDA, 0xB4),
+            rgb(0x7F, 0xCD, 0xBB),
+            rgb(0x41, 0xB6, 0xC4),
+            rgb(0x1D, 0x91, 0xC0),
+            rgb(0x2C, 0x7F, 0xB8),
+            rgb(0x22, 0x5E, 0xA8),
+            rgb(0x25, 0x34, 0x94),
+            rgb(0x0C, 0x2C, 0x84),
+            rgb(0x08, 0x1D, 0x58),
+        ],
+    },
+    BrewerScheme {
+        name: "YlOrBr",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xFF, 0xE5),
+            rgb(0xFF, 0xFF, 0xD4),
+            rgb(0xFF, 0xF7, 0xBC),
+            rgb(0xFE, 0xE3, 0x91),
+            rgb(0xFE, 0xD9, 0x8E),
+            rgb(0xFE, 0xC4, 0x4F),
+            rgb(0xFE, 0x99, 0x29),
+            rgb(0xEC, 0x70, 0x14),
+            rgb(0xD9, 0x5F, 0x0E),
+            rgb(0xCC, 0x4C, 0x02),
+            rgb(0x99, 0x34, 0x04),
+            rgb(0x8C, 0x2D, 0x04),
+            rgb(0x66, 0x25, 0x06),
+        ],
+    },
+    BrewerScheme {
+        name: "YlOrRd",
+        kind: BrewerKind::Sequential,
+        colors: &[
+            rgb(0xFF, 0xFF, 0xCC),
+            rgb(0xFF, 0xFF, 0xB2),
+            rgb(0xFF, 0xED, 0xA0),
+            rgb(0xFE, 0xD9, 0x76),
+            rgb(0xFE, 0xCC, 0x5C),
+            rgb(0xFE, 0xB2, 0x4C),
+            rgb(0xFD, 0x8D, 0x3C),
+            rgb(0xFC, 0x4E, 0x2A),
+            rgb(0xF0, 0x3B, 0x20),
+            rgb(0xE3, 0x1A, 0x1C),
+            rgb(0xBD, 0x00, 0x26),
+            rgb(0xB1, 0x00, 0x26),
+            rgb(0x80, 0x00, 0x26),
+        ],
+    },
+    BrewerScheme {
+        name: "BrBG",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x54, 0x30, 0x05),
+            rgb(0x8C, 0x51, 0x0A),
+            rgb(0xA6, 0x61, 0x1A),
+            rgb(0xBF, 0x81, 0x2D),
+            rgb(0xD8, 0xB3, 0x65),
+            rgb(0xDF, 0xC2, 0x7D),
+            rgb(0xF6, 0xE8, 0xC3),
+            rgb(0xF5, 0xF5, 0xF5),
+            rgb(0xC7, 0xEA, 0xE5),
+            rgb(0x80, 0xCD, 0xC1),
+            rgb(0x5A, 0xB4, 0xAC),
+            rgb(0x35, 0x97, 0x8F),
+            rgb(0x01, 0x85, 0x71),
+            rgb(0x01, 0x66, 0x5E),
+            rgb(0x00, 0x3C, 0x30),
+        ],
+    },
+    BrewerScheme {
+        name: "PiYG",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x8E, 0x01, 0x52),
+            rgb(0xC5, 0x1B, 0x7D),
+            rgb(0xD0, 0x1C, 0x8B),
+            rgb(0xDE, 0x77, 0xAE),
+            rgb(0xE9, 0xA3, 0xC9),
+            rgb(0xF1, 0xB6, 0xDA),
+            rgb(0xFD, 0xE0, 0xEF),
+            rgb(0xF7, 0xF7, 0xF7),
+            rgb(0xE6, 0xF5, 0xD0),
+            rgb(0xB8, 0xE1, 0x86),
+            rgb(0xA1, 0xD7, 0x6A),
+            rgb(0x7F, 0xBC, 0x41),
+            rgb(0x4D, 0xAC, 0x26),
+            rgb(0x4D, 0x92, 0x21),
+            rgb(0x27, 0x64, 0x19),
+        ],
+    },
+    BrewerScheme {
+        name: "PRGn",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x40, 0x00, 0x4B),
+            rgb(0x76, 0x2A, 0x83),
+            rgb(0x7B, 0x32, 0x94),
+            rgb(0x99, 0x70, 0xAB),
+            rgb(0xAF, 0x8D, 0xC3),
+            rgb(0xC2, 0xA5, 0xCF),
+            rgb(0xE7, 0xD4, 0xE8),
+            rgb(0xF7, 0xF7, 0xF7),
+            rgb(0xD9, 0xF0, 0xD3),
+            rgb(0xA6, 0xDB, 0xA0),
+            rgb(0x7F, 0xBF, 0x7B),
+            rgb(0x5A, 0xAE, 0x61),
+            rgb(0x00, 0x88, 0x37),
+            rgb(0x1B, 0x78, 0x37),
+            rgb(0x00, 0x44, 0x1B),
+        ],
+    },
+    BrewerScheme {
+        name: "PuOr",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x7F, 0x3B, 0x08),
+            rgb(0xB3, 0x58, 0x06),
+            rgb(0xE6, 0x61, 0x01),
+            rgb(0xE0, 0x82, 0x14),
+            rgb(0xF1, 0xA3, 0x40),
+            rgb(0xFD, 0xB8, 0x63),
+            rgb(0xFE, 0xE0, 0xB6),
+            rgb(0xF7, 0xF7, 0xF7),
+            rgb(0xD8, 0xDA, 0xEB),
+            rgb(0xB2, 0xAB, 0xD2),
+            rgb(0x99, 0x8E, 0xC3),
+            rgb(0x80, 0x73, 0xAC),
+            rgb(0x5E, 0x3C, 0x99),
+            rgb(0x54, 0x27, 0x88),
+            rgb(0x2D, 0x00, 0x4B),
+        ],
+    },
+    BrewerScheme {
+        name: "RdBu",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x67, 0x00, 0x1F),
+            rgb(0xB2, 0x18, 0x2B),
+            rgb(0xCA, 0x00, 0x20),
+            rgb(0xD6, 0x60, 0x4D),
+            rgb(0xEF, 0x8A, 0x62),
+            rgb(0xF4, 0xA5, 0x82),
+            rgb(0xFD, 0xDB, 0xC7),
+            rgb(0xF7, 0xF7, 0xF7),
+            rgb(0xD1, 0xE5, 0xF0),
+            rgb(0x92, 0xC5, 0xDE),
+            rgb(0x67, 0xA9, 0xCF),
+            rgb(0x43, 0x93, 0xC3),
+            rgb(0x05, 0x71, 0xB0),
+            rgb(0x21, 0x66, 0xAC),
+            rgb(0x05, 0x30, 0x61),
+        ],
+    },
+    BrewerScheme {
+        name: "RdGy",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x67, 0x00, 0x1F),
+            rgb(0xB2, 0x18, 0x2B),
+            rgb(0xCA, 0x00, 0x20),
+            rgb(0xD6, 0x60, 0x4D),
+            rgb(0xEF, 0x8A, 0x62),
+            rgb(0xF4, 0xA5, 0x82),
+            rgb(0xFD, 0xDB, 0xC7),
+            rgb(0xFF, 0xFF, 0xFF),
+            rgb(0xE0, 0xE0, 0xE0),
+            rgb(0xBA, 0xBA, 0xBA),
+            rgb(0x99, 0x99, 0x99),
+            rgb(0x87, 0x87, 0x87),
+            rgb(0x40, 0x40, 0x40),
+            rgb(0x4D, 0x4D, 0x4D),
+            rgb(0x1A, 0x1A, 0x1A),
+        ],
+    },
+    BrewerScheme {
+        name: "RdYlBu",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0xA5, 0x00, 0x26),
+            rgb(0xD7, 0x30, 0x27),
+            rgb(0xD7, 0x19, 0x1C),
+            rgb(0xF4, 0x6D, 0x43),
+            rgb(0xFC, 0x8D, 0x59),
+            rgb(0xFD, 0xAE, 0x61),
+            rgb(0xFE, 0xE0, 0x90),
+            rgb(0xFF, 0xFF, 0xBF),
+            rgb(0xE0, 0xF3, 0xF8),
+            rgb(0xAB, 0xD9, 0xE9),
+            rgb(0x91, 0xBF, 0xDB),
+            rgb(0x74, 0xAD, 0xD1),
+            rgb(0x2C, 0x7B, 0xB6),
+            rgb(0x45, 0x75, 0xB4),
+            rgb(0x31, 0x36, 0x95),
+        ],
+    },
+    BrewerScheme {
+        name: "RdYlGn",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0xA5, 0x00, 0x26),
+            rgb(0xD7, 0x30, 0x27),
+            rgb(0xD7, 0x19, 0x1C),
+            rgb(0xF4, 0x6D, 0x43),
+            rgb(0xFC, 0x8D, 0x59),
+            rgb(0xFD, 0xAE, 0x61),
+            rgb(0xFE, 0xE0, 0x8B),
+            rgb(0xFF, 0xFF, 0xBF),
+            rgb(0xD9, 0xEF, 0x8B),
+            rgb(0xA6, 0xD9, 0x6A),
+            rgb(0x91, 0xCF, 0x60),
+            rgb(0x66, 0xBD, 0x63),
+            rgb(0x1A, 0x96, 0x41),
+            rgb(0x1A, 0x98, 0x50),
+            rgb(0x00, 0x68, 0x37),
+        ],
+    },
+    BrewerScheme {
+        name: "Spectral",
+        kind: BrewerKind::Diverging,
+        colors: &[
+            rgb(0x9E, 0x01, 0x42),
+            rgb(0xD5, 0x3E, 0x4F),
+            rgb(0xD7, 0x19, 0x1C),
+            rgb(0xF4, 0x6D, 0x43),
+            rgb(0xFC, 0x8D, 0x59),
+            rgb(0xFD, 0xAE, 0x61),
+            rgb(0xFE, 0xE0, 0x8B),
+            rgb(0xFF, 0xFF, 0xBF),
+            rgb(0xE6, 0xF5, 0x98),
+            rgb(0xAB, 0xDD, 0xA4),
+            rgb(0x99, 0xD5, 0x94),
+            rgb(0x66, 0xC2, 0xA5),
+            rgb(0x2B, 0x83, 0xBA),
+            rgb(0x32, 0x88, 0xBD),
+            rgb(0x5E, 0x4F, 0xA2),
+        ],
+    },
+    BrewerScheme {
+        name: "Accent",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0x7F, 0xC9, 0x7F),
+            rgb(0xBE, 0xAE, 0xD4),
+            rgb(0xFD, 0xC0, 0x86),
+            rgb(0xFF, 0xFF, 0x99),
+            rgb(0x38, 0x6C, 0xB0),
+            rgb(0xF0, 0x02, 0x7F),
+            rgb(0xBF, 0x5B, 0x17),
+            rgb(0x66, 0x66, 0x66),
+        ],
+    },
+    BrewerScheme {
+        name: "Dark2",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0x1B, 0x9E, 0x77),
+            rgb(0xD9, 0x5F, 0x02),
+            rgb(0x75, 0x70, 0xB3),
+            rgb(0xE7, 0x29, 0x8A),
+            rgb(0x66, 0xA6, 0x1E),
+            rgb(0xE6, 0xAB, 0x02),
+            rgb(0xA6, 0x76, 0x1D),
+            rgb(0x66, 0x66, 0x66),
+        ],
+    },
+    BrewerScheme {
+        name: "Paired",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0xA6, 0xCE, 0xE3),
+            rgb(0x1F, 0x78, 0xB4),
+            rgb(0xB2, 0xDF, 0x8A),
+            rgb(0x33, 0xA0, 0x2C),
+            rgb(0xFB, 0x9A, 0x99),
+            rgb(0xE3, 0x1A, 0x1C),
+            rgb(0xFD, 0xBF, 0x6F),
+            rgb(0xFF, 0x7F, 0x00),
+            rgb(0xCA, 0xB2, 0xD6),
+            rgb(0x6A, 0x3D, 0x9A),
+            rgb(0xFF, 0xFF, 0x99),
+            rgb(0xB1, 0x59, 0x28),
+        ],
+    },
+    BrewerScheme {
+        name: "Pastel1",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0xFB, 0xB4, 0xAE),
+            rgb(0xB3, 0xCD, 0xE3),
+            rgb(0xCC, 0xEB, 0xC5),
+            rgb(0xDE, 0xCB, 0xE4),
+            rgb(0xFE, 0xD9, 0xA6),
+            rgb(0xFF, 0xFF, 0xCC),
+            rgb(0xE5, 0xD8, 0xBD),
+            rgb(0xFD, 0xDA, 0xEC),
+            rgb(0xF2, 0xF2, 0xF2),
+        ],
+    },
+    BrewerScheme {
+        name: "Pastel2",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0xB3, 0xE2, 0xCD),
+            rgb(0xFD, 0xCD, 0xAC),
+            rgb(0xCB, 0xD5, 0xE8),
+            rgb(0xF4, 0xCA, 0xE4),
+            rgb(0xE6, 0xF5, 0xC9),
+            rgb(0xFF, 0xF2, 0xAE),
+            rgb(0xF1, 0xE2, 0xCC),
+            rgb(0xCC, 0xCC, 0xCC),
+        ],
+    },
+    BrewerScheme {
+        name: "Set1",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0xE4, 0x1A, 0x1C),
+            rgb(0x37, 0x7E, 0xB8),
+            rgb(0x4D, 0xAF, 0x4A),
+            rgb(0x98, 0x4E, 0xA3),
+            rgb(0xFF, 0x7F, 0x00),
+            rgb(0xFF, 0xFF, 0x33),
+            rgb(0xA6, 0x56, 0x28),
+            rgb(0xF7, 0x81, 0xBF),
+            rgb(0x99, 0x99, 0x99),
+        ],
+    },
+    BrewerScheme {
+        name: "Set2",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0x66, 0xC2, 0xA5),
+            rgb(0xFC, 0x8D, 0x62),
+            rgb(0x8D, 0xA0, 0xCB),
+            rgb(0xE7, 0x8A, 0xC3),
+            rgb(0xA6, 0xD8, 0x54),
+            rgb(0xFF, 0xD9, 0x2F),
+            rgb(0xE5, 0xC4, 0x94),
+            rgb(0xB3, 0xB3, 0xB3),
+        ],
+    },
+    BrewerScheme {
+        name: "Set3",
+        kind: BrewerKind::Qualitative,
+        colors: &[
+            rgb(0x8D, 0xD3, 0xC7),
+            rgb(0xFF, 0xFF, 0xB3),
+            rgb(0xBE, 0xBA, 0xDA),
+            rgb(0xFB, 0x80, 0x72),
+            rgb(0x80, 0xB1, 0xD3),
+            rgb(0xFD, 0xB4, 0x62),
+            rgb(0xB3, 0xDE, 0x69),
+            rgb(0xFC, 0xCD, 0xE5),
+            rgb(0xD9, 0xD9, 0xD9),
+            rgb(0xBC, 0x80, 0xBD),
+            rgb(0xCC, 0xEB, 0xC5),
+            rgb(0xFF, 0xED, 0x6F),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{BrewerKind, BREWER_SCHEMES};
+    use crate::{brewer, brewer_scheme, rgb, Color};
+
+    fn hexes(name: &str, classes: usize) -> Vec<String> {
+        brewer(name, classes)
+            .unwrap()
+            .iter()
+            .map(|color| color.to_rgb().to_hex())
+            .collect()
+    }
+
+    #[test]
+    fn has_reference_values() {
+        assert_eq!(
+            hexes("Blues", 5),
+            ["#eff3ff", "#bdd7e7", "#6baed6", "#3182bd", "#08519c"]
+        );
+        assert_eq!(
+            hexes("RdBu", 7),
+            ["#b2182b", "#ef8a62", "#fddbc7", "#f7f7f7", "#d1e5f0", "#67a9cf", "#2166ac"]
+        );
+        assert_eq!(
+            hexes("YlOrRd", 4),
+            ["#ffffb2", "#fecc5c", "#fd8d3c", "#e31a1c"]
+        );
+        assert_eq!(hexes("Set1", 3), ["#e41a1c", "#377eb8", "#4daf4a"]);
+        assert_eq!(hexes("Paired", 12)[11], "#b15928");
+    }
+
+    #[test]
+    fn has_every_class_count() {
+        assert_eq!(BREWER_SCHEMES.len(), 35);
+
+        for scheme in BREWER_SCHEMES {
+            assert_eq!(scheme.classes(2), None);
+            assert_eq!(scheme.classes(scheme.max_classes() + 1), None);
+
+            for classes in 3..=scheme.max_classes() {
+                assert_eq!(scheme.classes(classes).unwrap().len(), classes);
+            }
+        }
+
+        assert_eq!(brewer_scheme("Greens").unwrap().max_classes(), 9);
+        assert_eq!(brewer_scheme("Spectral").unwrap().max_classes(), 11);
+        assert_eq!(brewer_scheme("Pastel2").unwrap().max_classes(), 8);
+    }
+
+    #[test]
+    fn orders_sequential_schemes() {
+        for scheme in BREWER_SCHEMES {
+            if scheme.kind() != BrewerKind::Sequential {
+                continue;
+            }
+
+            for classes in 3..=scheme.max_classes() {
+                let lightness: Vec<f32> = scheme
+                    .classes(classes)
+                    .unwrap()
+                    .iter()
+                    .map(|color| color.to_oklch().l)
+                    .collect();
+
+                assert!(
+                    lightness.windows(2).all(|pair| pair[0] > pair[1]),
+                    "{} {}",
+                    scheme.name(),
+                    classes
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn centers_diverging_schemes() {
+        for scheme in BREWER_SCHEMES {
+            if scheme.kind() != BrewerKind::Diverging {
+                continue;
+            }
+
+            let palette = scheme.classes(9).unwrap();
+            let lightness = |i: usize| palette.get(i).unwrap().to_oklch().l;
+
+            assert!(lightness(4) > lightness(0) + 0.3, "{}", scheme.name());
+            assert!(lightness(4) > lightness(8) + 0.3, "{}", scheme.name());
+        }
+    }
+
+    #[test]
+    fn finds_schemes_by_name() {
+        assert_eq!(brewer_scheme("PuBuGn").unwrap().name(), "PuBuGn");
+        assert_eq!(brewer_scheme("greys").unwrap().name(), "Greys");
+        assert_eq!(
+            brewer("Dark2", 8).unwrap().get(7),
+            Some(rgb(102, 102, 102).to_rgba())
+        );
+        assert_eq!(brewer("Nope", 3), None);
+        assert_eq!(
+            brewer("Greys", 9)
+                .unwrap()
+                .get(8)
+                .map(|color| color.to_rgb()),
+            Some(rgb(0, 0, 0))
+        );
+    }
+}
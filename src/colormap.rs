@@ -0,0 +1,468 @@
+use super::{rgb, Palette, RGB};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The kinds of colormaps.
+pub enum ColormapKind {
+    /// Colormaps for ordered data, going from low to high.
+    Sequential,
+
+    /// Colormaps for data diverging from a midpoint, with a light or neutral
+    /// middle between two contrasting ends.
+    Diverging,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A struct to represent a colormap: a continuous scheme mapping numbers
+/// between `0.0` and `1.0` to colors, as used to color plots and heatmaps.
+///
+/// The colormaps are stored as evenly spaced stops, interpolated in sRGB like
+/// plotting libraries do.
+pub struct Colormap {
+    name: &'static str,
+    kind: ColormapKind,
+    stops: &'static [RGB],
+}
+
+impl Colormap {
+    /// Returns the name of the colormap, as plotting libraries call it.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn kind(&self) -> ColormapKind {
+        self.kind
+    }
+
+    /// Returns the evenly spaced stops the colormap interpolates between.
+    pub fn stops(&self) -> &'static [RGB] {
+        self.stops
+    }
+
+    /// Samples the colormap at `t`, from `0.0` for its start to `1.0` for its
+    /// end. Values out of that range are clamped, and `NaN` samples the start.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, VIRIDIS};
+    ///
+    /// assert_eq!(VIRIDIS.sample(0.0), rgb(68, 1, 84));
+    /// assert_eq!(VIRIDIS.sample(0.5), rgb(33, 145, 140));
+    /// assert_eq!(VIRIDIS.sample(2.0), rgb(253, 231, 37));
+    /// ```
+    pub fn sample(&self, t: f32) -> RGB {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let position = t * (self.stops.len() - 1) as f32;
+        let index = (position as usize).min(self.stops.len() - 2);
+        let weight = position - index as f32;
+
+        let lhs = self.stops[index];
+        let rhs = self.stops[index + 1];
+        let channel =
+            |lhs: u8, rhs: u8| (lhs as f32 + (rhs as f32 - lhs as f32) * weight).round() as u8;
+
+        rgb(
+            channel(lhs.r.as_u8(), rhs.r.as_u8()),
+            channel(lhs.g.as_u8(), rhs.g.as_u8()),
+            channel(lhs.b.as_u8(), rhs.b.as_u8()),
+        )
+    }
+
+    /// Samples `n` evenly spaced colors, from the start of the colormap to its
+    /// end, such as to color a fixed number of series.
+    pub fn to_palette(&self, n: usize) -> Palette {
+        (0..n)
+            .map(|i| {
+                let t = if n == 1 {
+                    0.5
+                } else {
+                    i as f32 / (n - 1) as f32
+                };
+                self.sample(t)
+            })
+            .collect()
+    }
+}
+
+/// Every built-in colormap.
+pub const COLORMAPS: [Colormap; 10] = [
+    VIRIDIS, MAGMA, INFERNO, PLASMA, CIVIDIS, TURBO, COOLWARM, RD_BU, SPECTRAL, PI_YG,
+];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a cubehelix colormap, from Dave Green's scheme for
+/// rendering astronomical images: a helix around the diagonal of the RGB
+/// cube, from black to white, whose perceived brightness always increases.
+///
+/// For more, see [Green (2011)](https://arxiv.org/abs/1108.5083).
+pub struct Cubehelix {
+    // the hue the helix starts at: 0.0 for blue, 1.0 for red, 2.0 for green
+    pub start: f32,
+
+    // the number of turns around the hue wheel, negative to turn backwards
+    pub rotations: f32,
+
+    // how saturated the colors are, from 0.0 for greys
+    pub hue: f32,
+
+    // above 1.0 to favor dark colors, below to favor light colors
+    pub gamma: f32,
+}
+
+impl Default for Cubehelix {
+    /// Green's default scheme, turning from purple through green and red.
+    fn default() -> Self {
+        Cubehelix {
+            start: 0.5,
+            rotations: -1.5,
+            hue: 1.0,
+            gamma: 1.0,
+        }
+    }
+}
+
+impl Cubehelix {
+    /// Samples the colormap at `t`, from `0.0` for black to `1.0` for white.
+    /// Values out of that range are clamped, and `NaN` samples the start.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Cubehelix};
+    ///
+    /// let cubehelix = Cubehelix::default();
+    ///
+    /// assert_eq!(cubehelix.sample(0.0), rgb(0, 0, 0));
+    /// assert_eq!(cubehelix.sample(0.5), rgb(160, 121, 73));
+    /// assert_eq!(cubehelix.sample(1.0), rgb(255, 255, 255));
+    /// ```
+    pub fn sample(&self, t: f32) -> RGB {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let lightness = t.powf(self.gamma);
+        let amplitude = self.hue * lightness * (1.0 - lightness) / 2.0;
+        let angle = 2.0 * std::f32::consts::PI * (self.start / 3.0 + self.rotations * t);
+        let (sin, cos) = angle.sin_cos();
+
+        let channel = |c: f32| ((lightness + amplitude * c).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        rgb(
+            channel(-0.14861 * cos + 1.78277 * sin),
+            channel(-0.29227 * cos - 0.90649 * sin),
+            channel(1.97294 * cos),
+        )
+    }
+
+    /// Samples `n` evenly spaced colors, from black to white.
+    pub fn to_palette(&self, n: usize) -> Palette {
+        (0..n)
+            .map(|i| {
+                let t = if n == 1 {
+                    0.5
+                } else {
+                    i as f32 / (n - 1) as f32
+                };
+                self.sample(t)
+            })
+            .collect()
+    }
+}
+
+/// Viridis, the perceptually uniform default colormap of matplotlib, from
+/// dark purple through blue and green to yellow.
+pub const VIRIDIS: Colormap = Colormap {
+    name: "viridis",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x44, 0x01, 0x54),
+        rgb(0x48, 0x24, 0x75),
+        rgb(0x41, 0x44, 0x87),
+        rgb(0x35, 0x5F, 0x8D),
+        rgb(0x2A, 0x78, 0x8E),
+        rgb(0x21, 0x91, 0x8C),
+        rgb(0x22, 0xA8, 0x84),
+        rgb(0x44, 0xBF, 0x70),
+        rgb(0x7A, 0xD1, 0x51),
+        rgb(0xBD, 0xDF, 0x26),
+        rgb(0xFD, 0xE7, 0x25),
+    ],
+};
+
+/// Magma, a perceptually uniform colormap from black through purple and
+/// red to pale yellow.
+pub const MAGMA: Colormap = Colormap {
+    name: "magma",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x00, 0x00, 0x04),
+        rgb(0x14, 0x0E, 0x36),
+        rgb(0x3B, 0x0F, 0x70),
+        rgb(0x64, 0x1A, 0x80),
+        rgb(0x8C, 0x29, 0x81),
+        rgb(0xB7, 0x37, 0x79),
+        rgb(0xDE, 0x49, 0x68),
+        rgb(0xF7, 0x70, 0x5C),
+        rgb(0xFE, 0x9F, 0x6D),
+        rgb(0xFE, 0xCF, 0x92),
+        rgb(0xFC, 0xFD, 0xBF),
+    ],
+};
+
+/// Inferno, a perceptually uniform colormap from black through purple and
+/// orange to pale yellow.
+pub const INFERNO: Colormap = Colormap {
+    name: "inferno",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x00, 0x00, 0x04),
+        rgb(0x16, 0x0B, 0x39),
+        rgb(0x42, 0x0A, 0x68),
+        rgb(0x6A, 0x17, 0x6E),
+        rgb(0x93, 0x26, 0x67),
+        rgb(0xBC, 0x37, 0x54),
+        rgb(0xDD, 0x51, 0x3A),
+        rgb(0xF3, 0x78, 0x19),
+        rgb(0xFC, 0xA5, 0x0A),
+        rgb(0xF6, 0xD7, 0x46),
+        rgb(0xFC, 0xFF, 0xA4),
+    ],
+};
+
+/// Plasma, a perceptually uniform colormap from blue through purple and
+/// orange to yellow.
+pub const PLASMA: Colormap = Colormap {
+    name: "plasma",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x0D, 0x08, 0x87),
+        rgb(0x41, 0x04, 0x9D),
+        rgb(0x6A, 0x00, 0xA8),
+        rgb(0x8F, 0x0D, 0xA4),
+        rgb(0xB1, 0x2A, 0x90),
+        rgb(0xCC, 0x47, 0x78),
+        rgb(0xE1, 0x64, 0x62),
+        rgb(0xF2, 0x84, 0x4B),
+        rgb(0xFC, 0xA6, 0x36),
+        rgb(0xFC, 0xCE, 0x25),
+        rgb(0xF0, 0xF9, 0x21),
+    ],
+};
+
+/// Cividis, a variant of viridis from blue to yellow, designed to look
+/// the same with and without red-green color vision deficiencies.
+pub const CIVIDIS: Colormap = Colormap {
+    name: "cividis",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x00, 0x20, 0x4D),
+        rgb(0x00, 0x33, 0x6F),
+        rgb(0x39, 0x48, 0x6B),
+        rgb(0x57, 0x5C, 0x6D),
+        rgb(0x70, 0x71, 0x73),
+        rgb(0x8A, 0x87, 0x79),
+        rgb(0xA6, 0x9D, 0x75),
+        rgb(0xC4, 0xB5, 0x6C),
+        rgb(0xE4, 0xCF, 0x5B),
+        rgb(0xFF, 0xEA, 0x46),
+    ],
+};
+
+/// Turbo, Google's improved rainbow colormap, from dark blue through green
+/// and yellow to dark red. It is not perceptually uniform, but has smoother
+/// lightness than the classic jet.
+pub const TURBO: Colormap = Colormap {
+    name: "turbo",
+    kind: ColormapKind::Sequential,
+    stops: &[
+        rgb(0x30, 0x12, 0x3B),
+        rgb(0x46, 0x62, 0xD7),
+        rgb(0x36, 0xAA, 0xF9),
+        rgb(0x1A, 0xE4, 0xB6),
+        rgb(0x72, 0xFE, 0x5E),
+        rgb(0xC8, 0xEF, 0x34),
+        rgb(0xFA, 0xBA, 0x39),
+        rgb(0xF6, 0x6B, 0x19),
+        rgb(0xCB, 0x2A, 0x04),
+        rgb(0x7A, 0x04, 0x03),
+    ],
+};
+
+/// Kenneth Moreland's cool to warm diverging colormap, from blue through
+/// light grey to red.
+pub const COOLWARM: Colormap = Colormap {
+    name: "coolwarm",
+    kind: ColormapKind::Diverging,
+    stops: &[
+        rgb(0x3B, 0x4C, 0xC0),
+        rgb(0x5A, 0x77, 0xE3),
+        rgb(0x7C, 0x9F, 0xF9),
+        rgb(0x9F, 0xBE, 0xFF),
+        rgb(0xC0, 0xD4, 0xF5),
+        rgb(0xDD, 0xDD, 0xDD),
+        rgb(0xF2, 0xCB, 0xB7),
+        rgb(0xF7, 0xAD, 0x8F),
+        rgb(0xEE, 0x85, 0x68),
+        rgb(0xD7, 0x53, 0x44),
+        rgb(0xB4, 0x04, 0x26),
+    ],
+};
+
+/// ColorBrewer's red to blue diverging colormap, through white.
+pub const RD_BU: Colormap = Colormap {
+    name: "RdBu",
+    kind: ColormapKind::Diverging,
+    stops: &[
+        rgb(0x67, 0x00, 0x1F),
+        rgb(0xB2, 0x18, 0x2B),
+        rgb(0xD6, 0x60, 0x4D),
+        rgb(0xF4, 0xA5, 0x82),
+        rgb(0xFD, 0xDB, 0xC7),
+        rgb(0xF7, 0xF7, 0xF7),
+        rgb(0xD1, 0xE5, 0xF0),
+        rgb(0x92, 0xC5, 0xDE),
+        rgb(0x43, 0x93, 0xC3),
+        rgb(0x21, 0x66, 0xAC),
+        rgb(0x05, 0x30, 0x61),
+    ],
+};
+
+/// ColorBrewer's spectral diverging colormap, from red through pale yellow
+/// to blue.
+pub const SPECTRAL: Colormap = Colormap {
+    name: "Spectral",
+    kind: ColormapKind::Diverging,
+    stops: &[
+        rgb(0x9E, 0x01, 0x42),
+        rgb(0xD5, 0x3E, 0x4F),
+        rgb(0xF4, 0x6D, 0x43),
+        rgb(0xFD, 0xAE, 0x61),
+        rgb(0xFE, 0xE0, 0x8B),
+        rgb(0xFF, 0xFF, 0xBF),
+        rgb(0xE6, 0xF5, 0x98),
+        rgb(0xAB, 0xDD, 0xA4),
+        rgb(0x66, 0xC2, 0xA5),
+        rgb(0x32, 0x88, 0xBD),
+        rgb(0x5E, 0x4F, 0xA2),
+    ],
+};
+
+/// ColorBrewer's pink to green diverging colormap, through white.
+pub const PI_YG: Colormap = Colormap {
+    name: "PiYG",
+    kind: ColormapKind::Diverging,
+    stops: &[
+        rgb(0x8E, 0x01, 0x52),
+        rgb(0xC5, 0x1B, 0x7D),
+        rgb(0xDE, 0x77, 0xAE),
+        rgb(0xF1, 0xB6, 0xDA),
+        rgb(0xFD, 0xE0, 0xEF),
+        rgb(0xF7, 0xF7, 0xF7),
+        rgb(0xE6, 0xF5, 0xD0),
+        rgb(0xB8, 0xE1, 0x86),
+        rgb(0x7F, 0xBC, 0x41),
+        rgb(0x4D, 0x92, 0x21),
+        rgb(0x27, 0x64, 0x19),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::{ColormapKind, Cubehelix, CIVIDIS, COLORMAPS, COOLWARM, INFERNO, TURBO, VIRIDIS};
+    use crate::{rgb, Color};
+
+    #[test]
+    fn has_reference_values() {
+        assert_eq!(VIRIDIS.sample(0.3), rgb(53, 95, 141));
+        assert_eq!(INFERNO.sample(1.0), rgb(252, 255, 164));
+        assert_eq!(TURBO.sample(0.0), rgb(48, 18, 59));
+        assert_eq!(COOLWARM.sample(0.5), rgb(221, 221, 221));
+        assert_eq!(CIVIDIS.name(), "cividis");
+    }
+
+    #[test]
+    fn interpolates_between_stops() {
+        assert_eq!(VIRIDIS.sample(0.05), rgb(70, 19, 101));
+        assert_eq!(VIRIDIS.sample(f32::NAN), VIRIDIS.sample(0.0));
+        assert_eq!(VIRIDIS.sample(-1.0), VIRIDIS.sample(0.0));
+    }
+
+    #[test]
+    fn increases_sequential_lightness() {
+        for colormap in COLORMAPS {
+            if colormap.kind() != ColormapKind::Sequential || colormap.name() == "turbo" {
+                continue;
+            }
+
+            let lightness: Vec<f32> = colormap.stops().iter().map(|c| c.to_oklch().l).collect();
+            assert!(
+                lightness.windows(2).all(|pair| pair[0] < pair[1]),
+                "{}",
+                colormap.name()
+            );
+        }
+    }
+
+    #[test]
+    fn lightens_diverging_midpoints() {
+        for colormap in COLORMAPS {
+            if colormap.kind() != ColormapKind::Diverging {
+                continue;
+            }
+
+            let middle = colormap.sample(0.5).to_oklch().l;
+            assert!(middle > colormap.sample(0.0).to_oklch().l);
+            assert!(middle > colormap.sample(1.0).to_oklch().l);
+        }
+    }
+
+    #[test]
+    fn can_build_palettes() {
+        let palette = VIRIDIS.to_palette(3);
+
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette.get(0), Some(rgb(68, 1, 84).to_rgba()));
+        assert_eq!(palette.get(2), Some(rgb(253, 231, 37).to_rgba()));
+        assert_eq!(
+            VIRIDIS.to_palette(1).get(0),
+            Some(VIRIDIS.sample(0.5).to_rgba())
+        );
+        assert!(VIRIDIS.to_palette(0).is_empty());
+    }
+
+    #[test]
+    fn can_sample_cubehelix() {
+        let cubehelix = Cubehelix::default();
+
+        assert_eq!(cubehelix.sample(0.25), rgb(22, 83, 76));
+        assert_eq!(cubehelix.sample(-1.0), rgb(0, 0, 0));
+        assert_eq!(cubehelix.sample(f32::NAN), rgb(0, 0, 0));
+
+        let greys = Cubehelix {
+            hue: 0.0,
+            ..Cubehelix::default()
+        };
+        assert_eq!(greys.sample(0.5), rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn increases_cubehelix_brightness() {
+        for cubehelix in [
+            Cubehelix::default(),
+            Cubehelix {
+                start: 2.0,
+                rotations: 1.0,
+                hue: 0.8,
+                gamma: 0.6,
+            },
+        ] {
+            let brightness: Vec<f32> = cubehelix
+                .to_palette(64)
+                .iter()
+                .map(|color| color.perceived_brightness())
+                .collect();
+
+            assert!(
+                brightness.windows(2).all(|pair| pair[0] <= pair[1]),
+                "{:?}",
+                brightness
+            );
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use super::{Color, OKLCH, RGB, RGBA};
+use std::fmt;
+
+/// The conformance levels defined by the [WCAG 2.x contrast criteria](https://www.w3.org/TR/WCAG21/#contrast-minimum).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Level {
+    /// Minimum contrast (success criterion 1.4.3).
+    AA,
+
+    /// Enhanced contrast (success criterion 1.4.6).
+    AAA,
+}
+
+/// The size category of the text being checked, as WCAG relaxes the
+/// requirements for large text.
+///
+/// Large text is at least 18 point, or 14 point when bold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TextSize {
+    Normal,
+    Large,
+}
+
+impl Level {
+    /// Returns the minimum contrast ratio required to conform to `self`
+    /// for text of the given size.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Level, TextSize};
+    ///
+    /// assert_eq!(Level::AA.threshold(TextSize::Large), 3.0);
+    /// assert_eq!(Level::AA.threshold(TextSize::Normal), 4.5);
+    /// assert_eq!(Level::AAA.threshold(TextSize::Normal), 7.0);
+    /// ```
+    pub fn threshold(self, size: TextSize) -> f32 {
+        match (self, size) {
+            (Level::AA, TextSize::Large) => 3.0,
+            (Level::AA, TextSize::Normal) | (Level::AAA, TextSize::Large) => 4.5,
+            (Level::AAA, TextSize::Normal) => 7.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// A struct that represents the WCAG 2.x contrast ratio between two colors.
+/// Legal values range from `1.0` (no contrast) to `21.0` (black on white).
+///
+/// For more, see the [WCAG definition](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+pub struct ContrastRatio(f32);
+
+impl ContrastRatio {
+    pub fn new(ratio: f32) -> Self {
+        assert!(ratio >= 1.0, "Invalid contrast ratio");
+        assert!(ratio <= 21.0, "Invalid contrast ratio");
+
+        ContrastRatio(ratio)
+    }
+
+    pub fn as_f32(self) -> f32 {
+        self.0
+    }
+
+    /// Returns whether the ratio is high enough to conform to `level` for
+    /// text of the given size.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, Level, TextSize, rgb};
+    ///
+    /// let ratio = rgb(255, 255, 255).contrast_ratio(rgb(118, 118, 118));
+    ///
+    /// assert!(ratio.meets(Level::AA, TextSize::Normal));
+    /// assert!(!ratio.meets(Level::AAA, TextSize::Normal));
+    /// ```
+    pub fn meets(self, level: Level, size: TextSize) -> bool {
+        self.0 >= level.threshold(size)
+    }
+}
+
+impl fmt::Display for ContrastRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.02}:1", self.0)
+    }
+}
+
+pub(crate) fn contrast_ratio<A: Color, B: Color>(lhs: A, rhs: B) -> ContrastRatio {
+    let lhs = lhs.relative_luminance();
+    let rhs = rhs.relative_luminance();
+
+    let (lighter, darker) = if lhs > rhs { (lhs, rhs) } else { (rhs, lhs) };
+
+    // Guard against floating-point imprecision pushing the ratio just outside of its range.
+    ContrastRatio::new(((lighter + 0.05) / (darker + 0.05)).clamp(1.0, 21.0))
+}
+
+// Nudges the lightness of `color` in OKLCH, keeping its hue, until it reaches
+// the target contrast ratio against `background`. Both directions are tried,
+// and the one needing the smallest change in lightness wins. The chroma is
+// reduced along with the distance to black or white, so that those two stay
+// reachable even for vivid colors that would otherwise be clipped.
+pub(crate) fn ensure_contrast<B: Color>(color: RGBA, background: B, ratio: f32) -> Option<RGBA> {
+    let background = background.to_rgba();
+    let meets = |candidate: RGBA| candidate.contrast_ratio(background).as_f32() >= ratio;
+
+    if meets(color) {
+        return Some(color);
+    }
+
+    let alpha = color.a;
+    let OKLCH { l, c, h } = color.to_oklch();
+
+    let candidate = |extreme: f32, progress: f32| {
+        let RGB { r, g, b } = OKLCH {
+            l: l + (extreme - l) * progress,
+            c: c * (1.0 - progress),
+            h,
+        }
+        .to_rgb();
+
+        RGBA { r, g, b, a: alpha }
+    };
+
+    [0.0, 1.0]
+        .into_iter()
+        .filter(|&extreme| meets(candidate(extreme, 1.0)))
+        .map(|extreme| {
+            // Binary search the smallest step towards the extreme that is enough.
+            let mut lower = 0.0;
+            let mut upper = 1.0;
+
+            for _ in 0..24 {
+                let middle = (lower + upper) / 2.0;
+
+                if meets(candidate(extreme, middle)) {
+                    upper = middle;
+                } else {
+                    lower = middle;
+                }
+            }
+
+            ((extreme - l).abs() * upper, candidate(extreme, upper))
+        })
+        .min_by(|(lhs, _), (rhs, _)| lhs.total_cmp(rhs))
+        .map(|(_, candidate)| candidate)
+}
+
+// The screen luminance of a color, as estimated by APCA. Unlike the WCAG
+// relative luminance, this uses a simple 2.4 exponent rather than the
+// piecewise sRGB transfer function.
+fn apca_luminance(rgb: RGB) -> f32 {
+    let r = rgb.r.as_f32().powf(2.4);
+    let g = rgb.g.as_f32().powf(2.4);
+    let b = rgb.b.as_f32().powf(2.4);
+
+    let luminance = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+
+    // Soft clamp very dark colors, to account for flare and ambient light.
+    if luminance < 0.022 {
+        luminance + (0.022 - luminance).powf(1.414)
+    } else {
+        luminance
+    }
+}
+
+// This algorithm follows the APCA 0.0.98G-4g constants, as found in the
+// reference implementation (https://github.com/Myndex/apca-w3).
+pub(crate) fn apca_contrast<A: Color, B: Color>(text: A, background: B) -> f32 {
+    let text = apca_luminance(text.to_rgb());
+    let background = apca_luminance(background.to_rgb());
+
+    // Colors that are practically identical have no contrast.
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        // Dark text on a light background.
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        // Light text on a dark background.
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+
+    contrast * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, hsla, rgb, rgba, Color, ContrastRatio, Level, TextSize};
+
+    fn assert_apca(text: crate::RGB, background: crate::RGB, expected: f32) {
+        let actual = text.apca_contrast(background);
+
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "text: {}, background: {}, expected: {}, actual: {}",
+            text,
+            background,
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn can_compute_extremes() {
+        assert_eq!(
+            rgb(0, 0, 0).contrast_ratio(rgb(255, 255, 255)).to_string(),
+            "21.00:1"
+        );
+        assert_eq!(
+            rgb(255, 255, 255).contrast_ratio(rgb(0, 0, 0)).to_string(),
+            "21.00:1"
+        );
+        assert_eq!(
+            rgb(100, 149, 237)
+                .contrast_ratio(rgb(100, 149, 237))
+                .as_f32(),
+            1.0
+        );
+        assert_eq!(
+            rgb(255, 255, 255)
+                .contrast_ratio(rgb(118, 118, 118))
+                .to_string(),
+            "4.54:1"
+        );
+    }
+
+    #[test]
+    fn is_symmetric_across_color_models() {
+        let ratio = rgb(255, 99, 71).contrast_ratio(rgb(23, 98, 119));
+
+        assert_eq!(rgb(23, 98, 119).contrast_ratio(rgb(255, 99, 71)), ratio);
+        assert_eq!(
+            rgba(255, 99, 71, 0.5).contrast_ratio(rgb(23, 98, 119)),
+            ratio
+        );
+        assert_eq!(ratio.to_string(), "2.34:1");
+        assert_eq!(
+            hsl(0, 0, 100).contrast_ratio(rgb(0, 0, 0)).to_string(),
+            "21.00:1"
+        );
+    }
+
+    #[test]
+    fn can_check_conformance() {
+        let large_only = ContrastRatio::new(3.0);
+        let aa = ContrastRatio::new(4.5);
+        let aaa = ContrastRatio::new(7.0);
+
+        assert!(large_only.meets(Level::AA, TextSize::Large));
+        assert!(!large_only.meets(Level::AA, TextSize::Normal));
+        assert!(!large_only.meets(Level::AAA, TextSize::Large));
+
+        assert!(aa.meets(Level::AA, TextSize::Normal));
+        assert!(aa.meets(Level::AAA, TextSize::Large));
+        assert!(!aa.meets(Level::AAA, TextSize::Normal));
+
+        assert!(aaa.meets(Level::AAA, TextSize::Normal));
+    }
+
+    #[test]
+    fn can_compute_apca_reference_values() {
+        assert_apca(rgb(136, 136, 136), rgb(255, 255, 255), 63.056);
+        assert_apca(rgb(255, 255, 255), rgb(136, 136, 136), -68.541);
+        assert_apca(rgb(0, 0, 0), rgb(170, 170, 170), 58.146);
+        assert_apca(rgb(170, 170, 170), rgb(0, 0, 0), -56.241);
+        assert_apca(rgb(17, 34, 51), rgb(221, 238, 255), 91.668);
+        assert_apca(rgb(221, 238, 255), rgb(17, 34, 51), -93.068);
+    }
+
+    #[test]
+    fn apca_clips_low_contrast() {
+        assert_eq!(rgb(100, 149, 237).apca_contrast(rgb(100, 149, 237)), 0.0);
+        assert_eq!(rgb(120, 120, 120).apca_contrast(rgb(125, 125, 125)), 0.0);
+    }
+
+    #[test]
+    fn can_pick_readable_text() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+
+        assert_eq!(white.readable_on(), black);
+        assert_eq!(black.readable_on(), white);
+        assert_eq!(rgb(255, 99, 71).readable_on(), black);
+        assert_eq!(rgb(23, 98, 119).readable_on(), white);
+        assert_eq!(hsl(60, 100, 50).readable_on(), black);
+        assert_eq!(rgba(0, 0, 128, 0.5).readable_on(), white);
+    }
+
+    #[test]
+    fn can_pick_best_text_color() {
+        let candidates = [hsl(9, 100, 64), hsl(193, 68, 28), hsl(0, 0, 50)];
+
+        assert_eq!(
+            rgb(255, 255, 255).best_text_color(&candidates),
+            Some(hsl(193, 68, 28))
+        );
+        assert_eq!(
+            rgb(0, 0, 0).best_text_color(&candidates),
+            Some(hsl(9, 100, 64))
+        );
+        assert_eq!(
+            rgb(0, 0, 0).best_text_color(&[rgb(10, 10, 10), rgb(10, 10, 10)]),
+            Some(rgb(10, 10, 10))
+        );
+        assert_eq!(rgb(0, 0, 0).best_text_color::<crate::HSL>(&[]), None);
+    }
+
+    #[test]
+    fn can_ensure_contrast() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+        let tomato = rgb(255, 99, 71);
+
+        let darker = tomato.ensure_contrast(white, 4.5).unwrap();
+        assert!(darker.contrast_ratio(white).as_f32() >= 4.5);
+        assert!(darker.contrast_ratio(white).as_f32() < 4.6);
+        assert!(darker.relative_luminance() < tomato.relative_luminance());
+
+        let lighter = rgb(23, 98, 119).ensure_contrast(black, 7.0).unwrap();
+        assert!(lighter.contrast_ratio(black).as_f32() >= 7.0);
+        assert!(lighter.relative_luminance() > rgb(23, 98, 119).relative_luminance());
+
+        assert_eq!(tomato.ensure_contrast(black, 4.5), Some(tomato.to_rgba()));
+    }
+
+    #[test]
+    fn ensure_contrast_preserves_hue_and_alpha() {
+        let white = rgb(255, 255, 255);
+        let salmon = hsla(6, 93, 71, 0.5);
+        let adjusted = salmon.ensure_contrast(white, 3.0).unwrap();
+
+        assert!(adjusted.contrast_ratio(white).as_f32() >= 3.0);
+        assert_eq!(adjusted.a, salmon.a);
+        assert!((salmon.to_oklch().h - adjusted.to_oklch().h).abs() < 3.0);
+
+        let adjusted = hsl(6, 93, 71).ensure_contrast(white, 4.5).unwrap();
+        assert!(adjusted.contrast_ratio(white).as_f32() >= 4.5);
+
+        let adjusted = rgba(100, 149, 237, 0.25)
+            .ensure_contrast(white, 4.5)
+            .unwrap();
+        assert!(adjusted.contrast_ratio(white).as_f32() >= 4.5);
+        assert_eq!(adjusted.a, rgba(100, 149, 237, 0.25).a);
+    }
+
+    #[test]
+    fn ensure_contrast_reports_failure() {
+        let grey = rgb(128, 128, 128);
+
+        assert_eq!(grey.ensure_contrast(rgb(118, 118, 118), 7.0), None);
+        assert_eq!(grey.ensure_contrast(grey, 21.0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_ratio() {
+        ContrastRatio::new(0.5);
+    }
+}
@@ -0,0 +1,253 @@
+use super::{rgb, Color, ContrastRatio, Level, TextSize, RGBA};
+use std::fmt;
+
+/// Constructs the CSS [`contrast-color()`](css-contrast-color) function for
+/// a background color, so it can be resolved ahead of time or serialized.
+///
+/// Without candidates, it resolves to white or black, whichever has the
+/// highest contrast (preferring white when tied). Candidates and a target
+/// contrast can be set with `vs()` and `to()`.
+///
+/// # Example
+/// ```
+/// use farver::{contrast_color, rgb, rgba};
+///
+/// let navy = contrast_color(rgb(0, 0, 128));
+///
+/// assert_eq!(navy.resolve(), rgba(255, 255, 255, 1.0));
+/// assert_eq!(navy.to_string(), "contrast-color(rgb(0, 0, 128))");
+/// ```
+///
+/// [css-contrast-color]: https://www.w3.org/TR/css-color-5/#contrast-color
+pub fn contrast_color<T: Color>(background: T) -> ContrastColor {
+    ContrastColor {
+        background: background.to_rgba(),
+        candidates: Vec::new(),
+        target: None,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The target contrast of a `contrast-color()` function, either as one of
+/// the WCAG keywords or as an explicit ratio.
+pub enum ContrastTarget {
+    Level(Level, TextSize),
+    Ratio(f32),
+}
+
+impl ContrastTarget {
+    /// Returns the minimum contrast ratio this target requires.
+    pub fn threshold(self) -> f32 {
+        match self {
+            ContrastTarget::Level(level, size) => level.threshold(size),
+            ContrastTarget::Ratio(ratio) => ratio,
+        }
+    }
+}
+
+impl fmt::Display for ContrastTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContrastTarget::Level(Level::AA, TextSize::Normal) => write!(f, "AA"),
+            ContrastTarget::Level(Level::AA, TextSize::Large) => write!(f, "AA-large"),
+            ContrastTarget::Level(Level::AAA, TextSize::Normal) => write!(f, "AAA"),
+            ContrastTarget::Level(Level::AAA, TextSize::Large) => write!(f, "AAA-large"),
+            ContrastTarget::Ratio(ratio) => write!(f, "{}", ratio),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct to represent a CSS `contrast-color()` function: a background
+/// color, the candidate colors to pick from, and an optional target contrast.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-5/#contrast-color).
+pub struct ContrastColor {
+    background: RGBA,
+    candidates: Vec<RGBA>,
+    target: Option<ContrastTarget>,
+}
+
+impl ContrastColor {
+    /// Sets the candidate colors to pick from, which are considered in order.
+    /// Panics if there are fewer than two candidates, as `contrast-color()`
+    /// does not accept a single one.
+    pub fn vs<T: Color + Copy>(mut self, candidates: &[T]) -> Self {
+        assert!(candidates.len() >= 2, "Invalid contrast-color candidates");
+
+        self.candidates = candidates.iter().map(|&color| color.to_rgba()).collect();
+        self
+    }
+
+    /// Sets the target contrast. The first candidate meeting it is picked,
+    /// rather than the one with the highest contrast.
+    pub fn to(mut self, target: ContrastTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Resolves the function into the color it represents.
+    ///
+    /// Without a target, the candidate with the highest contrast ratio is
+    /// picked, the earliest one winning ties. With a target, the first
+    /// candidate meeting it is picked. If none do, white or black is picked,
+    /// whichever has the highest contrast.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{contrast_color, rgb, rgba, ContrastTarget, Level, TextSize};
+    ///
+    /// let function = contrast_color(rgb(255, 255, 255))
+    ///     .vs(&[rgb(255, 99, 71), rgb(23, 98, 119), rgb(0, 0, 0)])
+    ///     .to(ContrastTarget::Level(Level::AA, TextSize::Normal));
+    ///
+    /// assert_eq!(function.resolve(), rgba(23, 98, 119, 1.0));
+    /// ```
+    pub fn resolve(&self) -> RGBA {
+        let white = rgb(255, 255, 255).to_rgba();
+        let black = rgb(0, 0, 0).to_rgba();
+
+        if self.candidates.is_empty() {
+            return self.highest_contrast(&[white, black]);
+        }
+
+        match self.target {
+            Some(target) => self
+                .candidates
+                .iter()
+                .copied()
+                .find(|&candidate| self.ratio(candidate).as_f32() >= target.threshold())
+                .unwrap_or_else(|| self.highest_contrast(&[white, black])),
+            None => self.highest_contrast(&self.candidates),
+        }
+    }
+
+    fn ratio(&self, candidate: RGBA) -> ContrastRatio {
+        candidate.contrast_ratio(self.background)
+    }
+
+    fn highest_contrast(&self, candidates: &[RGBA]) -> RGBA {
+        let mut best = candidates[0];
+
+        for &candidate in &candidates[1..] {
+            if self.ratio(candidate) > self.ratio(best) {
+                best = candidate;
+            }
+        }
+
+        best
+    }
+}
+
+// Opaque colors are serialized without an alpha channel, as they usually
+// are in stylesheets.
+pub(crate) fn css(color: RGBA) -> String {
+    if color.a.as_u8() == 255 {
+        color.to_rgb().to_css()
+    } else {
+        color.to_css()
+    }
+}
+
+impl fmt::Display for ContrastColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "contrast-color({}", css(self.background))?;
+
+        for (i, &candidate) in self.candidates.iter().enumerate() {
+            let separator = if i == 0 { " vs " } else { ", " };
+            write!(f, "{}{}", separator, css(candidate))?;
+        }
+
+        if let Some(target) = self.target {
+            write!(f, " to {}", target)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{contrast_color, hsl, rgb, rgba, Color, ContrastTarget, Level, TextSize};
+
+    #[test]
+    fn resolves_to_white_or_black() {
+        let white = rgba(255, 255, 255, 1.0);
+        let black = rgba(0, 0, 0, 1.0);
+
+        assert_eq!(contrast_color(rgb(255, 255, 255)).resolve(), black);
+        assert_eq!(contrast_color(rgb(255, 255, 0)).resolve(), black);
+        assert_eq!(contrast_color(rgb(0, 0, 0)).resolve(), white);
+        assert_eq!(contrast_color(hsl(240, 100, 25)).resolve(), white);
+    }
+
+    #[test]
+    fn resolves_highest_contrast_candidate() {
+        let function = contrast_color(rgb(0, 0, 0)).vs(&[
+            rgb(23, 98, 119),
+            rgb(100, 149, 237),
+            rgb(255, 99, 71),
+        ]);
+
+        assert_eq!(function.resolve(), rgba(255, 99, 71, 1.0));
+        assert_eq!(
+            contrast_color(rgb(0, 0, 0))
+                .vs(&[
+                    rgba(50, 50, 50, 1.0),
+                    rgba(50, 50, 50, 1.0).fade(crate::percent(50))
+                ])
+                .resolve(),
+            rgba(50, 50, 50, 1.0)
+        );
+    }
+
+    #[test]
+    fn resolves_first_candidate_meeting_target() {
+        let function = contrast_color(rgb(0, 0, 0))
+            .vs(&[rgb(23, 98, 119), rgb(100, 149, 237), rgb(255, 99, 71)])
+            .to(ContrastTarget::Ratio(4.5));
+
+        assert_eq!(function.resolve(), rgba(100, 149, 237, 1.0));
+    }
+
+    #[test]
+    fn falls_back_when_no_candidate_meets_target() {
+        let function = contrast_color(rgb(0, 0, 0))
+            .vs(&[rgb(23, 98, 119), rgb(50, 50, 50)])
+            .to(ContrastTarget::Level(Level::AAA, TextSize::Normal));
+
+        assert_eq!(function.resolve(), rgba(255, 255, 255, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_single_candidate() {
+        let _ = contrast_color(rgb(0, 0, 0)).vs(&[rgb(23, 98, 119)]);
+    }
+
+    #[test]
+    fn can_serialize() {
+        assert_eq!(
+            contrast_color(rgba(0, 0, 0, 0.5)).to_string(),
+            "contrast-color(rgba(0, 0, 0, 0.50))"
+        );
+        assert_eq!(
+            contrast_color(rgb(0, 0, 0))
+                .vs(&[rgb(23, 98, 119), hsl(0, 0, 100).to_rgb()])
+                .to(ContrastTarget::Level(Level::AA, TextSize::Large))
+                .to_string(),
+            "contrast-color(rgb(0, 0, 0) vs rgb(23, 98, 119), rgb(255, 255, 255) to AA-large)"
+        );
+        assert_eq!(
+            contrast_color(rgb(0, 0, 0))
+                .vs(&[rgb(23, 98, 119), rgb(0, 0, 0)])
+                .to(ContrastTarget::Ratio(5.5))
+                .to_string(),
+            "contrast-color(rgb(0, 0, 0) vs rgb(23, 98, 119), rgb(0, 0, 0) to 5.5)"
+        );
+        assert_eq!(
+            ContrastTarget::Level(Level::AAA, TextSize::Normal).to_string(),
+            "AAA"
+        );
+    }
+}
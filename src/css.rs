@@ -0,0 +1,370 @@
+use super::{
+    contrast_color::css, names::KEYWORDS, palette::dark_variant, Color, ColorSpace, Ratio, RGB,
+    RGBA,
+};
+
+/// Constructs a CSS `color-mix()` expression mixing `a` with `b` in the
+/// given color space, `weight` being the proportion of `a`, rounded to a
+/// whole percentage. Browsers resolve it like `mix_in()` does, so the mix
+/// can follow custom properties rather than being baked in.
+///
+/// # Example
+/// ```
+/// use farver::{percent, rgb, to_color_mix_css, ColorSpace};
+///
+/// assert_eq!(
+///     to_color_mix_css(rgb(255, 0, 0), rgb(0, 0, 255), percent(25), ColorSpace::OKLAB),
+///     "color-mix(in oklab, rgb(255, 0, 0) 25%, rgb(0, 0, 255))"
+/// );
+/// ```
+pub fn to_color_mix_css<A: Color, B: Color>(
+    a: A,
+    b: B,
+    weight: Ratio,
+    space: ColorSpace,
+) -> String {
+    format!(
+        "color-mix(in {}, {} {}%, {})",
+        space.css_name(),
+        css(a.to_rgba()),
+        weight.as_percentage(),
+        css(b.to_rgba())
+    )
+}
+
+/// Constructs a CSS `light-dark()` expression, which browsers resolve to
+/// `light` or `dark` depending on the color scheme in use.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, to_light_dark_css};
+///
+/// assert_eq!(
+///     to_light_dark_css(rgb(255, 255, 255), rgb(17, 17, 17)),
+///     "light-dark(rgb(255, 255, 255), rgb(17, 17, 17))"
+/// );
+/// ```
+pub fn to_light_dark_css<A: Color, B: Color>(light: A, dark: B) -> String {
+    format!(
+        "light-dark({}, {})",
+        css(light.to_rgba()),
+        css(dark.to_rgba())
+    )
+}
+
+/// Constructs a CSS `light-dark()` expression from the color of a light
+/// theme, deriving its dark variant like `Palette::to_dark_mode()` does.
+///
+/// # Example
+/// ```
+/// use farver::{derive_light_dark_css, rgb};
+///
+/// assert_eq!(
+///     derive_light_dark_css(rgb(255, 255, 255)),
+///     "light-dark(rgb(255, 255, 255), rgb(11, 11, 11))"
+/// );
+/// ```
+pub fn derive_light_dark_css<T: Color>(light: T) -> String {
+    let light = light.to_rgba();
+
+    to_light_dark_css(light, dark_variant(light))
+}
+
+// Parses a CSS color: a keyword, a hex color, or one of the `rgb()`,
+// `hsl()`, `lab()`, `lch()`, `oklab()` and `oklch()` functions, in either
+// their legacy comma syntax or their modern space syntax. Colors outside of
+// the sRGB gamut are clipped.
+//
+// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#color-syntax).
+pub(crate) fn parse_color(input: &str) -> Option<RGBA> {
+    let input = input.trim().to_ascii_lowercase();
+
+    if let Some(hex) = input.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    if input == "transparent" {
+        return Some(RGBA {
+            r: Ratio::from_u8(0),
+            g: Ratio::from_u8(0),
+            b: Ratio::from_u8(0),
+            a: Ratio::from_u8(0),
+        });
+    }
+
+    if let Some(&(_, RGB { r, g, b })) = KEYWORDS.iter().find(|(name, _)| *name == input) {
+        return Some(RGBA {
+            r,
+            g,
+            b,
+            a: Ratio::from_u8(255),
+        });
+    }
+
+    let (name, arguments) = input.strip_suffix(')')?.split_once('(')?;
+    let (channels, alpha) = split_arguments(arguments)?;
+
+    // the channels as a color space reads them, and the scale of percentages
+    let (space, scales): (ColorSpace, [f32; 3]) = match name.trim() {
+        "rgb" | "rgba" => (ColorSpace::SRGB, [255.0; 3]),
+        "hsl" | "hsla" => (ColorSpace::HSL, [0.0, 100.0, 100.0]),
+        "lab" => (ColorSpace::LAB, [100.0, 125.0, 125.0]),
+        "lch" => (ColorSpace::LCH, [100.0, 150.0, 0.0]),
+        "oklab" => (ColorSpace::OKLAB, [1.0, 0.4, 0.4]),
+        "oklch" => (ColorSpace::OKLCH, [1.0, 0.4, 0.0]),
+        _ => return None,
+    };
+
+    let mut values = [0.0; 3];
+
+    for (i, (value, token)) in values.iter_mut().zip(channels).enumerate() {
+        *value = if Some(i) == space.hue_channel() {
+            parse_angle(token)?
+        } else if token == "none" {
+            0.0
+        } else if let Some(percentage) = token.strip_suffix('%') {
+            parse_number(percentage)? / 100.0 * scales[i]
+        } else {
+            parse_number(token)?
+        };
+    }
+
+    // sRGB and HSL channels are read between 0.0 and 1.0
+    match space {
+        ColorSpace::SRGB => values = values.map(|value| value / 255.0),
+        ColorSpace::HSL => {
+            values[1] /= 100.0;
+            values[2] /= 100.0;
+        }
+        _ => {}
+    }
+
+    let alpha = match alpha {
+        None => 1.0,
+        Some(token) => parse_alpha(token)?,
+    };
+    let RGB { r, g, b } = space.compose(values);
+
+    Some(RGBA {
+        r,
+        g,
+        b,
+        a: Ratio::from_f32(alpha),
+    })
+}
+
+// Parses a hex color without its leading `#`, in its 3, 4, 6 or 8 digit forms.
+fn parse_hex(hex: &str) -> Option<RGBA> {
+    if !hex.chars().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|digit| u8::from_str_radix(&digit.to_string().repeat(2), 16).ok())
+            .collect::<Option<_>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+
+    Some(RGBA {
+        r: Ratio::from_u8(digits[0]),
+        g: Ratio::from_u8(digits[1]),
+        b: Ratio::from_u8(digits[2]),
+        a: Ratio::from_u8(digits.get(3).copied().unwrap_or(255)),
+    })
+}
+
+// Splits the arguments of a color function into its three channels and its
+// optional alpha, which follows a `/` in the modern syntax and is a fourth
+// argument in the legacy one.
+fn split_arguments(arguments: &str) -> Option<([&str; 3], Option<&str>)> {
+    let (channels, alpha) = match arguments.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (arguments, None),
+    };
+
+    let tokens: Vec<&str> = if channels.contains(',') {
+        channels.split(',').map(str::trim).collect()
+    } else {
+        channels.split_whitespace().collect()
+    };
+
+    match (tokens.as_slice(), alpha) {
+        (&[x, y, z], alpha) => Some(([x, y, z], alpha)),
+        (&[x, y, z, alpha], None) if channels.contains(',') => Some(([x, y, z], Some(alpha))),
+        _ => None,
+    }
+}
+
+fn parse_number(token: &str) -> Option<f32> {
+    token
+        .parse::<f32>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+// Parses an alpha value, as a number or a percentage, clamping it between
+// 0.0 and 1.0 like CSS does.
+fn parse_alpha(token: &str) -> Option<f32> {
+    let alpha = match token.strip_suffix('%') {
+        _ if token == "none" => 0.0,
+        Some(percentage) => parse_number(percentage)? / 100.0,
+        None => parse_number(token)?,
+    };
+
+    Some(alpha.clamp(0.0, 1.0))
+}
+
+// Parses a CSS angle into degrees. Bare numbers are read as degrees, as hues
+// are.
+pub(crate) fn parse_angle(token: &str) -> Option<f32> {
+    if token == "none" {
+        return Some(0.0);
+    }
+
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / std::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    for (unit, scale) in units {
+        if let Some(number) = token.strip_suffix(unit) {
+            return Some(parse_number(number)? * scale);
+        }
+    }
+
+    parse_number(token)
+}
+
+// Splits `input` at the commas that are not nested in parentheses.
+pub(crate) fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+
+    for (i, character) in input.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if character == separator && depth == 0 => {
+                parts.push(&input[start..i]);
+                start = i + character.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&input[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_color;
+    use crate::{
+        derive_light_dark_css, hsl, percent, rgb, rgba, to_color_mix_css, to_light_dark_css,
+        ColorSpace, Palette, Ratio,
+    };
+
+    #[test]
+    fn can_serialize_color_mix() {
+        assert_eq!(
+            to_color_mix_css(
+                hsl(0, 0, 100),
+                rgba(0, 0, 0, 0.5),
+                percent(70),
+                ColorSpace::LinearRGB
+            ),
+            "color-mix(in srgb-linear, rgb(255, 255, 255) 70%, rgba(0, 0, 0, 0.50))"
+        );
+        assert_eq!(
+            to_color_mix_css(
+                rgb(0, 0, 0),
+                rgb(0, 0, 0),
+                Ratio::from_u8(255),
+                ColorSpace::OKLCH
+            ),
+            "color-mix(in oklch, rgb(0, 0, 0) 100%, rgb(0, 0, 0))"
+        );
+    }
+
+    #[test]
+    fn can_serialize_light_dark() {
+        assert_eq!(
+            to_light_dark_css(rgba(0, 0, 0, 0.5), hsl(0, 0, 100)),
+            "light-dark(rgba(0, 0, 0, 0.50), rgb(255, 255, 255))"
+        );
+
+        let accent = rgba(23, 98, 119, 0.75);
+        let dark = Palette::from_iter([accent]).to_dark_mode().get(0).unwrap();
+
+        assert_eq!(
+            derive_light_dark_css(accent),
+            to_light_dark_css(accent, dark)
+        );
+    }
+
+    #[test]
+    fn parses_keywords_and_hex_colors() {
+        assert_eq!(parse_color("Tomato"), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(parse_color("transparent"), Some(rgba(0, 0, 0, 0.0)));
+        assert_eq!(parse_color("#f00"), Some(rgba(255, 0, 0, 1.0)));
+        assert_eq!(parse_color("#ff000080"), Some(rgba(255, 0, 0, 0.5)));
+        assert_eq!(parse_color("#ff00"), Some(rgba(255, 255, 0, 0.0)));
+        assert_eq!(parse_color("#ff00g0"), None);
+        assert_eq!(parse_color("#ff000"), None);
+        assert_eq!(parse_color("notacolor"), None);
+    }
+
+    #[test]
+    fn parses_color_functions() {
+        assert_eq!(
+            parse_color("rgb(255, 99, 71)"),
+            Some(rgba(255, 99, 71, 1.0))
+        );
+        assert_eq!(
+            parse_color("rgba(255, 99, 71, 0.5)"),
+            Some(rgba(255, 99, 71, 0.5))
+        );
+        assert_eq!(
+            parse_color("rgb(100% 0% none / 50%)"),
+            Some(rgba(255, 0, 0, 0.5))
+        );
+        assert_eq!(
+            parse_color("hsl(120deg 100% 25%)"),
+            Some(rgba(0, 128, 0, 1.0))
+        );
+        assert_eq!(
+            parse_color("hsla(0.5turn, 100%, 50%, 1)"),
+            Some(rgba(0, 255, 255, 1.0))
+        );
+        assert_eq!(
+            parse_color("oklch(62.8% 0.2577 29.23)"),
+            Some(rgba(255, 0, 0, 1.0))
+        );
+        assert_eq!(
+            parse_color("oklab(1 0 0 / 0.25)"),
+            Some(rgba(255, 255, 255, 0.25))
+        );
+        assert_eq!(
+            parse_color("lab(54.29 80.8 69.89)"),
+            Some(rgba(255, 0, 0, 1.0))
+        );
+        assert_eq!(parse_color("lch(0% 0 0)"), Some(rgba(0, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn handles_invalid_functions() {
+        assert_eq!(parse_color("rgb(255, 0)"), None);
+        assert_eq!(parse_color("rgb(255 0 0 0)"), None);
+        assert_eq!(parse_color("rgb(red, 0, 0)"), None);
+        assert_eq!(parse_color("color(srgb 1 0 0)"), None);
+        assert_eq!(parse_color("rgb(255, 0, 0"), None);
+    }
+}
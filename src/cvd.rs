@@ -0,0 +1,477 @@
+use super::{
+    linear::{from_linear_channels, linear_channels},
+    percent, Color, Ratio, OKLAB, RGB, RGBA,
+};
+
+/// The color vision deficiencies that can be simulated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Cvd {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protanopia,
+
+    /// Missing or anomalous medium-wavelength (green) cones.
+    Deuteranopia,
+
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritanopia,
+
+    /// No functioning cones at all, leaving only the perception of luminance.
+    Achromatopsia,
+}
+
+impl Cvd {
+    /// All of the simulated deficiencies, in declaration order.
+    pub const ALL: [Cvd; 4] = [
+        Cvd::Protanopia,
+        Cvd::Deuteranopia,
+        Cvd::Tritanopia,
+        Cvd::Achromatopsia,
+    ];
+
+    // The linear RGB transform for the deficiency at the given severity. The
+    // dichromat and anomalous trichromat matrices are published for severities
+    // from 0.0 to 1.0 in steps of 0.1, and the ones in between are interpolated
+    // from the neighbouring steps. Achromatopsia keeps only the Rec. 709
+    // luminance, blended in from the identity.
+    fn matrix(self, severity: f32) -> [[f32; 3]; 3] {
+        let table = match self {
+            Cvd::Protanopia => &PROTANOMALY,
+            Cvd::Deuteranopia => &DEUTERANOMALY,
+            Cvd::Tritanopia => &TRITANOMALY,
+            Cvd::Achromatopsia => {
+                return interpolate(&IDENTITY, &[[0.2126, 0.7152, 0.0722]; 3], severity)
+            }
+        };
+
+        let step = (severity * 10.0).floor().min(9.0);
+        interpolate(
+            &table[step as usize],
+            &table[step as usize + 1],
+            severity * 10.0 - step,
+        )
+    }
+}
+
+// Interpolates each entry of two 3x3 matrices.
+fn interpolate(from: &[[f32; 3]; 3], to: &[[f32; 3]; 3], t: f32) -> [[f32; 3]; 3] {
+    std::array::from_fn(|i| std::array::from_fn(|j| from[i][j] + (to[i][j] - from[i][j]) * t))
+}
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+// The protanomaly, deuteranomaly, and tritanomaly matrices of Machado,
+// Oliveira, and Fernandes (2009), "A Physiologically-based Model for
+// Simulation of Color Vision Deficiency", for severities 0.0, 0.1, ..., 1.0.
+// The last entries are the dichromat matrices.
+const PROTANOMALY: [[[f32; 3]; 3]; 11] = [
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [
+        [0.856167, 0.182038, -0.038205],
+        [0.029342, 0.955115, 0.015544],
+        [-0.002880, -0.001563, 1.004443],
+    ],
+    [
+        [0.734766, 0.334872, -0.069637],
+        [0.051840, 0.919198, 0.028963],
+        [-0.004928, -0.004209, 1.009137],
+    ],
+    [
+        [0.630323, 0.465641, -0.095964],
+        [0.069181, 0.890046, 0.040773],
+        [-0.006308, -0.007724, 1.014032],
+    ],
+    [
+        [0.539009, 0.579343, -0.118352],
+        [0.082546, 0.866121, 0.051332],
+        [-0.007136, -0.011959, 1.019095],
+    ],
+    [
+        [0.458064, 0.679578, -0.137642],
+        [0.092785, 0.846313, 0.060902],
+        [-0.007494, -0.016807, 1.024301],
+    ],
+    [
+        [0.385450, 0.769005, -0.154455],
+        [0.100526, 0.829802, 0.069673],
+        [-0.007442, -0.022190, 1.029632],
+    ],
+    [
+        [0.319627, 0.849633, -0.169261],
+        [0.106241, 0.815969, 0.077790],
+        [-0.007025, -0.028051, 1.035076],
+    ],
+    [
+        [0.259411, 0.923008, -0.182420],
+        [0.110296, 0.804340, 0.085364],
+        [-0.006276, -0.034346, 1.040622],
+    ],
+    [
+        [0.203876, 0.990338, -0.194214],
+        [0.112975, 0.794542, 0.092483],
+        [-0.005222, -0.041043, 1.046265],
+    ],
+    [
+        [0.152286, 1.052583, -0.204868],
+        [0.114503, 0.786281, 0.099216],
+        [-0.003882, -0.048116, 1.051998],
+    ],
+];
+
+const DEUTERANOMALY: [[[f32; 3]; 3]; 11] = [
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [
+        [0.866435, 0.177704, -0.044139],
+        [0.049567, 0.939063, 0.011370],
+        [-0.003453, 0.007233, 0.996220],
+    ],
+    [
+        [0.760729, 0.319078, -0.079807],
+        [0.090568, 0.889315, 0.020117],
+        [-0.006027, 0.013325, 0.992702],
+    ],
+    [
+        [0.675425, 0.433850, -0.109275],
+        [0.125303, 0.847755, 0.026942],
+        [-0.007950, 0.018572, 0.989378],
+    ],
+    [
+        [0.605511, 0.528560, -0.134071],
+        [0.155318, 0.812366, 0.032316],
+        [-0.009376, 0.023176, 0.986200],
+    ],
+    [
+        [0.547494, 0.607765, -0.155259],
+        [0.181692, 0.781742, 0.036566],
+        [-0.010410, 0.027275, 0.983136],
+    ],
+    [
+        [0.498864, 0.674741, -0.173604],
+        [0.205199, 0.754872, 0.039929],
+        [-0.011131, 0.030969, 0.980162],
+    ],
+    [
+        [0.457771, 0.731899, -0.189670],
+        [0.226409, 0.731012, 0.042579],
+        [-0.011595, 0.034333, 0.977261],
+    ],
+    [
+        [0.422823, 0.781057, -0.203881],
+        [0.245752, 0.709602, 0.044646],
+        [-0.011843, 0.037423, 0.974421],
+    ],
+    [
+        [0.392952, 0.823610, -0.216562],
+        [0.263559, 0.690210, 0.046232],
+        [-0.011910, 0.040281, 0.971630],
+    ],
+    [
+        [0.367322, 0.860646, -0.227968],
+        [0.280085, 0.672501, 0.047413],
+        [-0.011820, 0.042940, 0.968881],
+    ],
+];
+
+const TRITANOMALY: [[[f32; 3]; 3]; 11] = [
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    [
+        [0.926670, 0.092514, -0.019184],
+        [0.021191, 0.964503, 0.014306],
+        [0.008437, 0.054813, 0.936750],
+    ],
+    [
+        [0.895720, 0.133330, -0.029050],
+        [0.029997, 0.945400, 0.024603],
+        [0.013027, 0.104707, 0.882266],
+    ],
+    [
+        [0.905871, 0.127791, -0.033662],
+        [0.026856, 0.941251, 0.031893],
+        [0.013410, 0.148296, 0.838294],
+    ],
+    [
+        [0.948035, 0.089490, -0.037526],
+        [0.014364, 0.946792, 0.038844],
+        [0.010853, 0.193991, 0.795156],
+    ],
+    [
+        [1.017277, 0.027029, -0.044306],
+        [-0.006113, 0.958479, 0.047634],
+        [0.006379, 0.248708, 0.744913],
+    ],
+    [
+        [1.104996, -0.046633, -0.058363],
+        [-0.032137, 0.971635, 0.060503],
+        [0.001336, 0.317922, 0.680742],
+    ],
+    [
+        [1.193214, -0.109812, -0.083402],
+        [-0.058496, 0.979410, 0.079086],
+        [-0.002346, 0.403492, 0.598854],
+    ],
+    [
+        [1.257728, -0.139648, -0.118081],
+        [-0.078003, 0.975409, 0.102594],
+        [-0.003316, 0.501214, 0.502102],
+    ],
+    [
+        [1.278864, -0.125333, -0.153531],
+        [-0.084748, 0.957674, 0.127074],
+        [-0.000989, 0.601151, 0.399838],
+    ],
+    [
+        [1.255528, -0.076749, -0.178779],
+        [-0.078411, 0.930809, 0.147602],
+        [0.004733, 0.691367, 0.303900],
+    ],
+];
+
+/// Simulates `colors` under every color vision deficiency, and reports the
+/// pairs that become hard to tell apart: those whose simulated ΔEOK (see
+/// `OKLAB::distance`) falls below `threshold`. Pairs that are already too
+/// close with normal vision are reported as well, under every deficiency.
+/// An empty result means the palette is distinguishable for colorblind users.
+///
+/// # Example
+/// ```
+/// use farver::{check_cvd_safety, rgb, Cvd};
+///
+/// let traffic_light = [rgb(230, 25, 25), rgb(240, 200, 0), rgb(40, 160, 40)];
+/// let conflicts = check_cvd_safety(&traffic_light, 0.05);
+///
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].cvd, Cvd::Deuteranopia);
+/// assert_eq!((conflicts[0].lhs, conflicts[0].rhs), (0, 2));
+///
+/// assert!(check_cvd_safety(&traffic_light[..2], 0.05).is_empty());
+/// ```
+pub fn check_cvd_safety<T: Color + Copy>(colors: &[T], threshold: f32) -> Vec<CvdConflict> {
+    let mut conflicts = Vec::new();
+
+    for cvd in Cvd::ALL {
+        let simulated: Vec<OKLAB> = colors
+            .iter()
+            .map(|&color| color.to_rgba().simulate(cvd, percent(100)).to_oklab())
+            .collect();
+
+        for lhs in 0..simulated.len() {
+            for rhs in lhs + 1..simulated.len() {
+                let delta_e = simulated[lhs].distance(simulated[rhs]);
+
+                if delta_e < threshold {
+                    conflicts.push(CvdConflict {
+                        cvd,
+                        lhs,
+                        rhs,
+                        delta_e,
+                    });
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that represents two colors of a palette, indexed by their position
+/// in the palette, that are hard to tell apart with a color vision deficiency.
+pub struct CvdConflict {
+    pub cvd: Cvd,
+
+    // index of the first color
+    pub lhs: usize,
+
+    // index of the second color
+    pub rhs: usize,
+
+    // the simulated ΔEOK between both colors
+    pub delta_e: f32,
+}
+
+// Applies a 3x3 matrix to linear RGB channels.
+pub(crate) fn transform(matrix: &[[f32; 3]; 3], [r, g, b]: [f32; 3]) -> [f32; 3] {
+    [
+        matrix[0][0] * r + matrix[0][1] * g + matrix[0][2] * b,
+        matrix[1][0] * r + matrix[1][1] * g + matrix[1][2] * b,
+        matrix[2][0] * r + matrix[2][1] * g + matrix[2][2] * b,
+    ]
+}
+
+// Simulates how `color` is seen with the given deficiency, applying its
+// transform at `severity` in linear light.
+pub(crate) fn simulate(color: RGBA, cvd: Cvd, severity: Ratio) -> RGBA {
+    let RGBA { r, g, b, a } = color;
+    let linear = linear_channels(RGB { r, g, b });
+    let simulated = transform(&cvd.matrix(severity.as_f32()), linear);
+
+    let RGB { r, g, b } = from_linear_channels(simulated);
+    RGBA { r, g, b, a }
+}
+
+// Shifts the information lost to a deficiency into channels that remain
+// visible, following Fidaner, Lin, and Ozguven (2005), "Analysis of Color
+// Blindness". Achromatopsia has no such channels, so colors are unchanged.
+pub(crate) fn daltonize(color: RGBA, cvd: Cvd, severity: Ratio) -> RGBA {
+    let correction = match cvd {
+        Cvd::Protanopia | Cvd::Deuteranopia => [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]],
+        Cvd::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+        Cvd::Achromatopsia => return color,
+    };
+
+    let RGBA { r, g, b, a } = color;
+    let linear = linear_channels(RGB { r, g, b });
+    let simulated = linear_channels(simulate(color, cvd, severity).to_rgb());
+
+    let error = std::array::from_fn(|i| linear[i] - simulated[i]);
+    let shift = transform(&correction, error);
+    let corrected = std::array::from_fn(|i| linear[i] + shift[i]);
+
+    let RGB { r, g, b } = from_linear_channels(corrected);
+    RGBA { r, g, b, a }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, percent, rgb, rgba, Color, Cvd, RGBA};
+
+    #[test]
+    fn can_simulate_dichromacy() {
+        assert_eq!(
+            rgb(255, 0, 0).simulate(Cvd::Protanopia, percent(100)),
+            rgba(109, 95, 0, 1.0)
+        );
+        assert_eq!(
+            rgb(255, 0, 0).simulate(Cvd::Deuteranopia, percent(100)),
+            rgba(163, 144, 0, 1.0)
+        );
+        assert_eq!(
+            rgb(0, 0, 255).simulate(Cvd::Tritanopia, percent(100)),
+            rgba(0, 107, 150, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_simulate_achromatopsia() {
+        let grey = rgb(255, 99, 71).simulate(Cvd::Achromatopsia, percent(100));
+
+        assert_eq!(grey.r, grey.g);
+        assert_eq!(grey.g, grey.b);
+        assert!((grey.relative_luminance() - rgb(255, 99, 71).relative_luminance()).abs() < 0.005);
+    }
+
+    #[test]
+    fn keeps_neutrals_and_alpha() {
+        for cvd in Cvd::ALL {
+            assert_eq!(rgb(0, 0, 0).simulate(cvd, percent(100)), rgba(0, 0, 0, 1.0));
+            assert_eq!(
+                rgb(255, 255, 255).simulate(cvd, percent(100)),
+                rgba(255, 255, 255, 1.0)
+            );
+            assert_eq!(
+                rgba(255, 99, 71, 0.5).simulate(cvd, percent(100)).a,
+                rgba(255, 99, 71, 0.5).a
+            );
+        }
+
+        assert_eq!(
+            hsl(0, 0, 50).simulate(Cvd::Protanopia, percent(100)),
+            hsl(0, 0, 50).to_rgba()
+        );
+    }
+
+    #[test]
+    fn can_daltonize() {
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 128, 0);
+
+        let corrected_red = red.daltonize(Cvd::Deuteranopia, percent(100));
+        let corrected_green = green.daltonize(Cvd::Deuteranopia, percent(100));
+        assert_ne!(corrected_red, red.to_rgba());
+        assert!(corrected_red.b.as_u8() > 0);
+
+        // The corrected colors should be further apart once simulated.
+        let distance = |lhs: RGBA, rhs: RGBA| {
+            let lhs = lhs.simulate(Cvd::Deuteranopia, percent(100)).to_oklab();
+            let rhs = rhs.simulate(Cvd::Deuteranopia, percent(100)).to_oklab();
+
+            ((lhs.l - rhs.l).powi(2) + (lhs.a - rhs.a).powi(2) + (lhs.b - rhs.b).powi(2)).sqrt()
+        };
+        assert!(
+            distance(corrected_red, corrected_green) > distance(red.to_rgba(), green.to_rgba())
+        );
+    }
+
+    #[test]
+    fn daltonize_keeps_neutrals_and_alpha() {
+        for cvd in Cvd::ALL {
+            assert_eq!(
+                rgb(128, 128, 128).daltonize(cvd, percent(100)),
+                rgba(128, 128, 128, 1.0)
+            );
+            assert_eq!(
+                rgba(255, 99, 71, 0.5).daltonize(cvd, percent(100)).a,
+                rgba(255, 99, 71, 0.5).a
+            );
+        }
+
+        assert_eq!(
+            hsl(9, 100, 64).daltonize(Cvd::Achromatopsia, percent(100)),
+            hsl(9, 100, 64).to_rgba()
+        );
+        assert_eq!(
+            rgb(255, 99, 71).daltonize(Cvd::Protanopia, percent(0)),
+            rgba(255, 99, 71, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_check_palette_safety() {
+        let palette = [rgb(230, 25, 25), rgb(40, 160, 40), rgb(0, 0, 255)];
+        let conflicts = crate::check_cvd_safety(&palette, 0.1);
+
+        assert!(!conflicts.is_empty());
+        assert!(conflicts
+            .iter()
+            .all(|conflict| conflict.lhs < conflict.rhs && conflict.delta_e < 0.1));
+        assert!(conflicts
+            .iter()
+            .any(|conflict| conflict.cvd == Cvd::Deuteranopia
+                && (conflict.lhs, conflict.rhs) == (0, 1)));
+        assert!(crate::check_cvd_safety(&palette, 0.0).is_empty());
+    }
+
+    #[test]
+    fn reports_identical_colors() {
+        let conflicts = crate::check_cvd_safety(&[hsl(9, 100, 64), hsl(9, 100, 64)], 0.01);
+
+        assert_eq!(conflicts.len(), Cvd::ALL.len());
+        assert!(conflicts.iter().all(|conflict| conflict.delta_e == 0.0));
+    }
+
+    #[test]
+    fn uses_published_severity_steps() {
+        assert_eq!(
+            rgb(255, 0, 0).simulate(Cvd::Protanopia, percent(50)),
+            rgba(180, 86, 0, 1.0)
+        );
+
+        // Severities between the published steps are interpolated.
+        let between = rgb(255, 0, 0).simulate(Cvd::Protanopia, percent(55));
+        assert!(between.r.as_u8() < 180 && between.g.as_u8() > 86);
+    }
+
+    #[test]
+    fn scales_with_severity() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(
+            tomato.simulate(Cvd::Deuteranopia, percent(0)),
+            tomato.to_rgba()
+        );
+
+        let partial = tomato.simulate(Cvd::Deuteranopia, percent(50));
+        let full = tomato.simulate(Cvd::Deuteranopia, percent(100));
+        assert!(partial.r.as_u8() < tomato.r.as_u8());
+        assert!(partial.r.as_u8() > full.r.as_u8());
+    }
+}
@@ -0,0 +1,353 @@
+use super::{LAB, RGB};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The industries the CIE94 color difference has weighting factors for.
+pub enum Application {
+    GraphicArts,
+    Textiles,
+}
+
+impl Application {
+    // The kL, K1, and K2 weighting factors.
+    fn weights(self) -> (f32, f32, f32) {
+        match self {
+            Application::GraphicArts => (1.0, 0.045, 0.015),
+            Application::Textiles => (2.0, 0.048, 0.014),
+        }
+    }
+}
+
+// The euclidean distance between two colors in CIELAB, as defined by the CIE
+// in 1976. A difference of about 2.3 is just noticeable.
+pub(crate) fn delta_e76(lhs: LAB, rhs: LAB) -> f32 {
+    let dl = lhs.l - rhs.l;
+    let da = lhs.a - rhs.a;
+    let db = lhs.b - rhs.b;
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+// The "redmean" approximation of Thiadmer Riemersma, weighting the RGB channels
+// by how red the colors are. It ranges from 0 to about 765, is much cheaper than
+// converting to CIELAB, and is good enough for nearest color lookups.
+pub(crate) fn redmean(lhs: RGB, rhs: RGB) -> f32 {
+    let [r1, g1, b1] = [lhs.r, lhs.g, lhs.b].map(|c| c.as_u8() as f32);
+    let [r2, g2, b2] = [rhs.r, rhs.g, rhs.b].map(|c| c.as_u8() as f32);
+
+    let mean = (r1 + r2) / 2.0;
+    let dr = r1 - r2;
+    let dg = g1 - g2;
+    let db = b1 - b2;
+
+    ((2.0 + mean / 256.0) * dr * dr + 4.0 * dg * dg + (2.0 + (255.0 - mean) / 256.0) * db * db)
+        .sqrt()
+}
+
+// The lightness, chroma, and hue differences between two colors, with the
+// chroma of the reference. The hue difference is returned squared, as it is
+// derived from the other differences and only ever used squared.
+fn differences(reference: LAB, sample: LAB) -> (f32, f32, f32, f32) {
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh = (da * da + db * db - dc * dc).max(0.0);
+
+    (dl, dc, dh, c1)
+}
+
+// The CIE94 color difference. Unlike the other metrics it is not symmetric,
+// as the weights depend on the chroma of the reference color.
+pub(crate) fn delta_e94(reference: LAB, sample: LAB, application: Application) -> f32 {
+    let (kl, k1, k2) = application.weights();
+    let (dl, dc, dh, c1) = differences(reference, sample);
+
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + dh / (sh * sh)).sqrt()
+}
+
+// The CMC l:c color difference of the Colour Measurement Committee of the
+// Society of Dyers and Colourists. Like CIE94, the weights depend on the
+// reference color.
+pub(crate) fn delta_e_cmc(reference: LAB, sample: LAB, lightness: f32, chroma: f32) -> f32 {
+    let (dl, dc, dh, c1) = differences(reference, sample);
+    let l1 = reference.l;
+    let h1 = reference
+        .b
+        .atan2(reference.a)
+        .to_degrees()
+        .rem_euclid(360.0);
+
+    let sl = if l1 < 16.0 {
+        0.511
+    } else {
+        0.040975 * l1 / (1.0 + 0.01765 * l1)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+
+    ((dl / (lightness * sl)).powi(2) + (dc / (chroma * sc)).powi(2) + dh / (sh * sh)).sqrt()
+}
+
+// The CIEDE2000 color difference, with unit weighting factors. Follows the
+// implementation notes of Sharma, Wu, and Dalal (2005), "The CIEDE2000
+// Color-Difference Formula", including the hue rotation term. A difference of
+// about 1.0 is just noticeable.
+pub(crate) fn delta_e2000(lhs: LAB, rhs: LAB) -> f32 {
+    let LAB {
+        l: l1,
+        a: a1,
+        b: b1,
+    } = lhs;
+    let LAB {
+        l: l2,
+        a: a2,
+        b: b2,
+    } = rhs;
+
+    let pow7 = |x: f32| x.powi(7);
+    let hue = |b: f32, a: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let c_mean = (c1 + c2) / 2.0;
+
+    let g = 0.5 * (1.0 - (pow7(c_mean) / (pow7(c_mean) + pow7(25.0))).sqrt());
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let h1 = hue(b1, a1);
+    let h2 = hue(b2, a2);
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos = |degrees: f32| degrees.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_mean - 30.0) + 0.24 * cos(2.0 * h_mean) + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+
+    let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (pow7(c_mean) / (pow7(c_mean) + pow7(25.0))).sqrt();
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+
+    let l = dl / sl;
+    let c = dc / sc;
+    let h = dh / sh;
+
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lab, rgb, Application, Color};
+
+    #[test]
+    fn can_compute_delta_e76() {
+        assert_eq!(rgb(255, 255, 255).delta_e76(rgb(0, 0, 0)).round(), 100.0);
+        assert_eq!(rgb(255, 99, 71).delta_e76(rgb(255, 99, 71)), 0.0);
+        assert_eq!(
+            format!(
+                "{:.4}",
+                super::delta_e76(lab(50.0, 2.6772, -79.7751), lab(50.0, 0.0, -82.7485))
+            ),
+            "4.0011"
+        );
+    }
+
+    #[test]
+    fn can_compute_delta_e2000() {
+        // Reference pairs from Sharma, Wu, and Dalal (2005). Their pair 9 is
+        // left out, as its hues are exactly 180° apart and the branch taken
+        // for the mean hue depends on f32 rounding.
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), "2.0425"),
+            ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), "2.8615"),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), "1.0000"),
+            ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0009), "7.1792"),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), "27.1492"),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                "1.2644",
+            ),
+            (
+                (22.7233, 20.0904, -46.694),
+                (23.0331, 14.973, -42.5619),
+                "2.0373",
+            ),
+            (
+                (90.9257, -0.5406, -0.9208),
+                (88.6381, -0.8985, -0.7239),
+                "1.5381",
+            ),
+            (
+                (2.0776, 0.0795, -1.135),
+                (0.9033, -0.0636, -0.5514),
+                "0.9082",
+            ),
+        ];
+
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lhs = lab(l1, a1, b1);
+            let rhs = lab(l2, a2, b2);
+
+            assert_eq!(format!("{:.4}", super::delta_e2000(lhs, rhs)), expected);
+            assert_eq!(format!("{:.4}", super::delta_e2000(rhs, lhs)), expected);
+        }
+    }
+
+    #[test]
+    fn can_compute_delta_e94() {
+        let pairs = [
+            (
+                (50.0, 2.6772, -79.7751),
+                (50.0, 0.0, -82.7485),
+                "1.3950",
+                "1.4230",
+            ),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), "34.6892", "28.2503"),
+            (
+                (2.0776, 0.0795, -1.135),
+                (0.9033, -0.0636, -0.5514),
+                "1.3065",
+                "0.8191",
+            ),
+        ];
+
+        for ((l1, a1, b1), (l2, a2, b2), graphic_arts, textiles) in pairs {
+            let reference = lab(l1, a1, b1);
+            let sample = lab(l2, a2, b2);
+
+            assert_eq!(
+                format!(
+                    "{:.4}",
+                    super::delta_e94(reference, sample, Application::GraphicArts)
+                ),
+                graphic_arts
+            );
+            assert_eq!(
+                format!(
+                    "{:.4}",
+                    super::delta_e94(reference, sample, Application::Textiles)
+                ),
+                textiles
+            );
+        }
+    }
+
+    #[test]
+    fn can_compute_delta_e_cmc() {
+        let pairs = [
+            (
+                (50.0, 2.6772, -79.7751),
+                (50.0, 0.0, -82.7485),
+                "1.7387",
+                "1.7387",
+            ),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), "37.9233", "42.1088"),
+            (
+                (2.0776, 0.0795, -1.135),
+                (0.9033, -0.0636, -0.5514),
+                "1.4278",
+                "2.4493",
+            ),
+        ];
+
+        for ((l1, a1, b1), (l2, a2, b2), acceptability, perceptibility) in pairs {
+            let reference = lab(l1, a1, b1);
+            let sample = lab(l2, a2, b2);
+
+            assert_eq!(
+                format!("{:.4}", super::delta_e_cmc(reference, sample, 2.0, 1.0)),
+                acceptability
+            );
+            assert_eq!(
+                format!("{:.4}", super::delta_e_cmc(reference, sample, 1.0, 1.0)),
+                perceptibility
+            );
+        }
+    }
+
+    #[test]
+    fn can_compute_redmean_distance() {
+        assert_eq!(rgb(255, 99, 71).redmean_distance(rgb(255, 99, 71)), 0.0);
+        assert_eq!(rgb(0, 0, 0).redmean_distance(rgb(0, 255, 0)), 510.0);
+        assert_eq!(
+            format!("{:.2}", rgb(0, 0, 0).redmean_distance(rgb(255, 255, 255))),
+            "764.83"
+        );
+
+        // Differences in red weigh more among reddish colors.
+        assert!(
+            rgb(255, 0, 0).redmean_distance(rgb(205, 0, 0))
+                > rgb(50, 0, 0).redmean_distance(rgb(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn delta_e2000_handles_greys() {
+        assert_eq!(rgb(128, 128, 128).delta_e2000(rgb(128, 128, 128)), 0.0);
+        assert_eq!(rgb(255, 255, 255).delta_e2000(rgb(0, 0, 0)).round(), 100.0);
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let tomato = rgb(255, 99, 71);
+        let teal = rgb(23, 98, 119);
+
+        assert_eq!(tomato.delta_e76(teal), teal.delta_e76(tomato));
+        assert_eq!(tomato.delta_e2000(teal), teal.delta_e2000(tomato));
+        assert_eq!(tomato.redmean_distance(teal), teal.redmean_distance(tomato));
+        assert_ne!(
+            tomato.delta_e94(teal, Application::GraphicArts),
+            teal.delta_e94(tomato, Application::GraphicArts)
+        );
+    }
+}
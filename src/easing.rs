@@ -0,0 +1,148 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The easing functions of CSS transitions, reshaping progress between
+/// `0.0` and `1.0` to speed up or slow down parts of it.
+///
+/// For more, see the [CSS Easing Spec](https://www.w3.org/TR/css-easing-1/).
+pub enum Easing {
+    /// Keeps progress as it is.
+    Linear,
+
+    /// Starts slowly, speeds up, and ends slowly, the CSS default.
+    Ease,
+
+    /// Starts slowly.
+    EaseIn,
+
+    /// Ends slowly.
+    EaseOut,
+
+    /// Starts and ends slowly.
+    EaseInOut,
+
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)`, with the given control
+    /// points `(x1, y1, x2, y2)`. The `x` coordinates must be between `0.0`
+    /// and `1.0`.
+    CubicBezier(f32, f32, f32, f32),
+
+    /// Jumps through the given number of equal steps, each jump happening at
+    /// the end of its step like CSS `steps(n, jump-end)`.
+    Steps(u32),
+}
+
+impl Easing {
+    /// Applies the easing to `progress`, which is clamped between `0.0` and
+    /// `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.3), 0.3);
+    /// assert!(Easing::EaseIn.apply(0.3) < 0.3);
+    /// assert_eq!(Easing::Steps(4).apply(0.3), 0.25);
+    /// ```
+    pub fn apply(self, progress: f32) -> f32 {
+        let progress = if progress.is_nan() {
+            0.0
+        } else {
+            progress.clamp(0.0, 1.0)
+        };
+
+        match self {
+            Easing::Linear => progress,
+            Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, progress),
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, progress),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, progress),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, progress),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, progress),
+            Easing::Steps(0) => progress,
+            Easing::Steps(steps) => {
+                let steps = steps as f32;
+                ((progress * steps).floor() / steps).min(1.0)
+            }
+        }
+    }
+}
+
+// Evaluates the curve at the point whose x coordinate is `x`, finding its
+// parameter by bisection since x always increases along the curve.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    assert!(
+        (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2),
+        "Invalid control points"
+    );
+
+    let coordinate = |p1: f32, p2: f32, t: f32| {
+        3.0 * (1.0 - t) * (1.0 - t) * t * p1 + 3.0 * (1.0 - t) * t * t * p2 + t * t * t
+    };
+
+    // Curves flat at their ends never quite reach them by bisection.
+    if x <= 0.0 || x >= 1.0 {
+        return x;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..32 {
+        let mid = (low + high) / 2.0;
+
+        if coordinate(x1, x2, mid) < x {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    coordinate(y1, y2, (low + high) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Easing;
+
+    #[test]
+    fn keeps_end_points() {
+        for easing in [
+            Easing::Linear,
+            Easing::Ease,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::CubicBezier(0.1, 0.7, 1.0, 0.1),
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-4, "{:?}", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-4, "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn matches_css_curves() {
+        // Reference values of the CSS keywords, as computed by browsers.
+        assert!((Easing::Ease.apply(0.5) - 0.8024).abs() < 1e-3);
+        assert!((Easing::EaseIn.apply(0.5) - 0.3153).abs() < 1e-3);
+        assert!((Easing::EaseOut.apply(0.5) - 0.6847).abs() < 1e-3);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-3);
+        assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn can_step() {
+        assert_eq!(Easing::Steps(2).apply(0.49), 0.0);
+        assert_eq!(Easing::Steps(2).apply(0.5), 0.5);
+        assert_eq!(Easing::Steps(2).apply(1.0), 1.0);
+        assert_eq!(Easing::Steps(0).apply(0.3), 0.3);
+    }
+
+    #[test]
+    fn clamps_progress() {
+        assert_eq!(Easing::Linear.apply(-1.0), 0.0);
+        assert_eq!(Easing::Linear.apply(2.0), 1.0);
+        assert_eq!(Easing::EaseIn.apply(f32::NAN), Easing::EaseIn.apply(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_control_points() {
+        Easing::CubicBezier(1.5, 0.0, 0.5, 1.0).apply(0.5);
+    }
+}
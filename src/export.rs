@@ -0,0 +1,694 @@
+use super::{linear::linear_channels, Color, Palette};
+use std::fmt::Write;
+
+impl Palette {
+    /// Writes the palette as CSS custom properties on `:root`, one per color,
+    /// named after the prefix and the name of the color, or its position for
+    /// unnamed colors, in kebab case (`--brand-primary`), and prefixed with
+    /// `color-` if they would start with a digit. Names that would repeat one
+    /// before them, like `a b` after `a-b`, get the position of their color
+    /// appended (`--a-b-2`). Colors are written as hex strings. See
+    /// `to_css_variables_with_dark()` to set other values for dark color
+    /// schemes.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    /// palette.push(rgba(0, 0, 0, 0.5));
+    ///
+    /// assert_eq!(
+    ///     palette.to_css_variables("brand"),
+    ///     ":root {\n  --brand-primary: #fa8072;\n  --brand-2: #00000080;\n}\n"
+    /// );
+    /// ```
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        format!(
+            ":root {{\n{}}}\n",
+            self.variables("  --", prefix, ": ", ";", str::to_owned)
+        )
+    }
+
+    /// Writes the palette as CSS custom properties like `to_css_variables()`,
+    /// followed by a `prefers-color-scheme: dark` media query setting the
+    /// properties of the `dark` palette, which is usually the dark variant of
+    /// the palette from `to_dark_mode()`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("surface", rgb(255, 255, 255));
+    ///
+    /// assert_eq!(
+    ///     palette.to_css_variables_with_dark("", &palette.to_dark_mode()),
+    ///     concat!(
+    ///         ":root {\n  --surface: #ffffff;\n}\n\n",
+    ///         "@media (prefers-color-scheme: dark) {\n",
+    ///         "  :root {\n    --surface: #0b0b0b;\n  }\n",
+    ///         "}\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_css_variables_with_dark(&self, prefix: &str, dark: &Palette) -> String {
+        format!(
+            "{}\n@media (prefers-color-scheme: dark) {{\n  :root {{\n{}  }}\n}}\n",
+            self.to_css_variables(prefix),
+            dark.variables("    --", prefix, ": ", ";", str::to_owned)
+        )
+    }
+
+    /// Writes the palette as a file of SCSS variables, one per color, named
+    /// like `to_css_variables()` names them (`$brand-primary: #fa8072;`).
+    /// As Sass treats hyphens and underscores as the same, names only
+    /// differing by those, like `a_b` after `a-b`, are made unique as well.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(palette.to_scss_variables("brand"), "$brand-primary: #fa8072;\n");
+    /// ```
+    pub fn to_scss_variables(&self, prefix: &str) -> String {
+        self.variables("$", prefix, ": ", ";", sass_key)
+    }
+
+    /// Writes the palette as a file of LESS variables, one per color, named
+    /// and kept unique like `to_css_variables()` names and keeps them
+    /// (`@brand-primary: #fa8072;`).
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(palette.to_less_variables("brand"), "@brand-primary: #fa8072;\n");
+    /// ```
+    pub fn to_less_variables(&self, prefix: &str) -> String {
+        self.variables("@", prefix, ": ", ";", str::to_owned)
+    }
+
+    /// Writes the palette as an Android resource file of colors, to be saved
+    /// as `res/values/colors.xml`. Colors are named like
+    /// `to_css_variables()` names them, in the snake case of Android
+    /// resources (`brand_primary`), and written as hex, prefixed with their
+    /// alpha channel if they are translucent (`#80000000`). As resource names
+    /// must be unique, names that would repeat one before them, like `a_b`
+    /// after `a-b`, get the position of their color appended (`a_b_2`).
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    /// palette.push_named("scrim", rgba(0, 0, 0, 0.5));
+    ///
+    /// assert_eq!(
+    ///     palette.to_android_xml("brand"),
+    ///     concat!(
+    ///         "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n",
+    ///         "<resources>\n",
+    ///         "    <color name=\"brand_primary\">#FA8072</color>\n",
+    ///         "    <color name=\"brand_scrim\">#80000000</color>\n",
+    ///         "</resources>\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_android_xml(&self, prefix: &str) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+        let mut names: Vec<String> = Vec::new();
+
+        for (i, (name, color)) in self.iter_named().enumerate() {
+            let base = identifier(prefix, name, i)
+                .to_ascii_lowercase()
+                .replace('-', "_");
+            let name = unique_name(&mut names, base, i, "_", str::to_owned);
+
+            let alpha = match color.a.as_u8() {
+                255 => String::new(),
+                alpha => format!("{:02X}", alpha),
+            };
+
+            let _ = writeln!(
+                xml,
+                "    <color name=\"{}\">#{}{:02X}{:02X}{:02X}</color>",
+                name,
+                alpha,
+                color.r.as_u8(),
+                color.g.as_u8(),
+                color.b.as_u8()
+            );
+        }
+
+        xml.push_str("</resources>\n");
+        xml
+    }
+
+    /// Writes the palette as a Swift file extending SwiftUI's `Color` with a
+    /// static constant per color, named like `to_css_variables()` names them,
+    /// in camel case (`brandPrimary`), and built like `to_swiftui()` builds
+    /// them. Names that are Swift keywords, like `default`, are escaped with
+    /// backticks. Names that would repeat one before them once in camel
+    /// case, like `a_b` after `a-b`, get the position of their color appended
+    /// (`aB2`), and names left empty, like `-`, are replaced by that position.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(
+    ///     palette.to_swiftui("brand"),
+    ///     concat!(
+    ///         "import SwiftUI\n\n",
+    ///         "extension Color {\n",
+    ///         "    static let brandPrimary = Color(red: 0.980, green: 0.502, blue: 0.447)\n",
+    ///         "}\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_swiftui(&self, prefix: &str) -> String {
+        let mut swift = String::from("import SwiftUI\n\nextension Color {\n");
+        let mut names: Vec<String> = Vec::new();
+
+        for (i, (name, color)) in self.iter_named().enumerate() {
+            let base = native_name(prefix, name, i, false);
+            let mut name = unique_name(&mut names, base, i, "", str::to_owned);
+            if SWIFT_KEYWORDS.contains(&name.as_str()) {
+                name = format!("`{}`", name);
+            }
+
+            let _ = writeln!(swift, "    static let {} = {}", name, color.to_swiftui());
+        }
+
+        swift.push_str("}\n");
+        swift
+    }
+
+    /// Writes the palette as a Kotlin file of Jetpack Compose colors, with a
+    /// constant per color, named like `to_css_variables()` names them, in
+    /// Pascal case as Compose names colors (`BrandPrimary`), and built like
+    /// `to_compose()` builds them. Names are kept unique like `to_swiftui()`
+    /// keeps them (`AB2`).
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("primary", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(
+    ///     palette.to_compose("brand"),
+    ///     "import androidx.compose.ui.graphics.Color\n\nval BrandPrimary = Color(0xFFFA8072)\n"
+    /// );
+    /// ```
+    pub fn to_compose(&self, prefix: &str) -> String {
+        let mut kotlin = String::from("import androidx.compose.ui.graphics.Color\n\n");
+        let mut names: Vec<String> = Vec::new();
+
+        for (i, (name, color)) in self.iter_named().enumerate() {
+            let base = native_name(prefix, name, i, true);
+            let name = unique_name(&mut names, base, i, "", str::to_owned);
+            let _ = writeln!(kotlin, "val {} = {}", name, color.to_compose());
+        }
+
+        kotlin
+    }
+
+    // Writes a line declaring every color, named like `identifier()` names
+    // it and kept unique once `key` normalizes names, as a hex string.
+    fn variables(
+        &self,
+        start: &str,
+        prefix: &str,
+        separator: &str,
+        end: &str,
+        key: fn(&str) -> String,
+    ) -> String {
+        let mut lines = String::new();
+        let mut names: Vec<String> = Vec::new();
+
+        for (i, ((name, _), hex)) in self.iter_named().zip(self.to_hex()).enumerate() {
+            let name = unique_name(&mut names, identifier(prefix, name, i), i, "-", key);
+            let _ = writeln!(lines, "{}{}{}{}{}", start, name, separator, hex, end);
+        }
+
+        lines
+    }
+}
+
+// Names a color in kebab case after a prefix and its name, or its position
+// from 1 if it is unnamed, replacing the characters identifiers cannot hold.
+// Names starting with a digit are prefixed with `color-`, which most
+// languages need.
+fn identifier(prefix: &str, name: Option<&str>, index: usize) -> String {
+    let name = name.map_or_else(|| (index + 1).to_string(), str::to_string);
+    let joined = if prefix.is_empty() {
+        name
+    } else {
+        format!("{}-{}", prefix, name)
+    };
+
+    let identifier: String = joined
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("color-{}", identifier)
+    } else {
+        identifier
+    }
+}
+
+// Names a color like `identifier()` names it, in camel case or in Pascal
+// case. Names without a letter or a digit to keep, which casing leaves
+// empty, fall back to the position of the color.
+fn native_name(prefix: &str, name: Option<&str>, index: usize, pascal: bool) -> String {
+    let cased = camel_case(&identifier(prefix, name, index), pascal);
+
+    if cased.is_empty() {
+        camel_case(&identifier(prefix, None, index), pascal)
+    } else {
+        cased
+    }
+}
+
+// Keeps the names of a file unique, as most formats need them to be, once
+// `key` normalizes them. A name repeating one of `taken` gets the position
+// of its color appended after `separator`. The name with a position appended
+// can be taken as well, in which case the count goes on until a free one is
+// found.
+fn unique_name(
+    taken: &mut Vec<String>,
+    base: String,
+    index: usize,
+    separator: &str,
+    key: fn(&str) -> String,
+) -> String {
+    let mut name = base.clone();
+    let mut position = index + 1;
+    while taken.contains(&key(&name)) {
+        name = format!("{}{}{}", base, separator, position);
+        position += 1;
+    }
+
+    taken.push(key(&name));
+    name
+}
+
+// Sass treats hyphens and underscores in names as the same character, so
+// `$a-b` and `$a_b` are the same variable.
+fn sass_key(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+// Joins the words of a kebab case identifier in camel case, capitalizing
+// the first one for Pascal case.
+fn camel_case(identifier: &str, pascal: bool) -> String {
+    let mut joined = String::new();
+
+    for (i, word) in identifier
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .enumerate()
+    {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if i > 0 || pascal {
+                joined.push(first.to_ascii_uppercase());
+            } else {
+                joined.push(first.to_ascii_lowercase());
+            }
+            joined.extend(chars);
+        }
+    }
+
+    joined
+}
+
+// The Swift keywords that can only name a constant between backticks. Names
+// are in camel case, so only the lowercase ones are needed.
+const SWIFT_KEYWORDS: [&str; 56] = [
+    "as",
+    "associatedtype",
+    "await",
+    "borrowing",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "consuming",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "nonisolated",
+    "open",
+    "operator",
+    "precedencegroup",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+pub(crate) fn swiftui<T: Color>(color: T) -> String {
+    let color = color.to_rgba();
+    let mut swift = format!(
+        "Color(red: {:.3}, green: {:.3}, blue: {:.3}",
+        color.r.as_f32(),
+        color.g.as_f32(),
+        color.b.as_f32()
+    );
+
+    if color.a.as_u8() < 255 {
+        let _ = write!(swift, ", opacity: {:.3}", color.a.as_f32());
+    }
+
+    swift.push(')');
+    swift
+}
+
+pub(crate) fn compose<T: Color>(color: T) -> String {
+    let color = color.to_rgba();
+
+    format!(
+        "Color(0x{:02X}{:02X}{:02X}{:02X})",
+        color.a.as_u8(),
+        color.r.as_u8(),
+        color.g.as_u8(),
+        color.b.as_u8()
+    )
+}
+
+// Writes the linear light channels and alpha value of a color as the
+// arguments of a shader vector constructor.
+pub(crate) fn vec4<T: Color>(color: T, constructor: &str, precision: usize) -> String {
+    let color = color.to_rgba();
+    let [r, g, b] = linear_channels(color.to_rgb());
+
+    format!(
+        "{}({:.*}, {:.*}, {:.*}, {:.*})",
+        constructor,
+        precision,
+        r,
+        precision,
+        g,
+        precision,
+        b,
+        precision,
+        color.a.as_f32()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{camel_case, identifier};
+    use crate::{rgb, rgba, Color, Palette};
+
+    #[test]
+    fn can_write_css_variables() {
+        let scale = rgb(23, 98, 119).tailwind_scale();
+        let css = scale.to_css_variables("teal");
+
+        assert!(css.starts_with(":root {\n  --teal-50: #"));
+        assert!(css.contains("\n  --teal-700: #176277;\n"));
+        assert_eq!(css.lines().count(), 13);
+        assert_eq!(Palette::new().to_css_variables("x"), ":root {\n}\n");
+    }
+
+    #[test]
+    fn disambiguates_css_variables() {
+        let mut palette = Palette::new();
+        palette.push_named("a-b", rgb(255, 0, 0));
+        palette.push_named("a b", rgb(0, 255, 0));
+        palette.push_named("a-b-2", rgb(0, 0, 255));
+
+        assert_eq!(
+            palette.to_css_variables(""),
+            ":root {\n  --a-b: #ff0000;\n  --a-b-2: #00ff00;\n  --a-b-2-3: #0000ff;\n}\n"
+        );
+    }
+
+    #[test]
+    fn can_write_dark_css_variables() {
+        let mut palette = Palette::new();
+        palette.push_named("text", rgb(17, 17, 17));
+        palette.push_named("surface", rgb(255, 255, 255));
+
+        let css = palette.to_css_variables_with_dark("app", &palette.to_dark_mode());
+        let (light, dark) = css.split_once("@media").unwrap();
+
+        assert!(light.contains("--app-text: #111111;"));
+        assert!(dark.contains("    --app-text: #"));
+        assert!(!dark.contains("#111111"));
+        assert!(css.ends_with("  }\n}\n"));
+    }
+
+    #[test]
+    fn can_write_preprocessor_variables() {
+        let mut palette = Palette::new();
+        palette.push_named("Deep Blue", rgb(0, 105, 148));
+        palette.push(rgba(255, 0, 0, 0.5));
+
+        assert_eq!(
+            palette.to_scss_variables(""),
+            "$Deep-Blue: #006994;\n$color-2: #ff000080;\n"
+        );
+        assert_eq!(
+            palette.to_less_variables("x"),
+            "@x-Deep-Blue: #006994;\n@x-2: #ff000080;\n"
+        );
+        assert_eq!(Palette::new().to_scss_variables("x"), "");
+    }
+
+    #[test]
+    fn disambiguates_preprocessor_variables() {
+        let mut palette = Palette::new();
+        palette.push_named("a-b", rgb(255, 0, 0));
+        palette.push_named("a_b", rgb(0, 255, 0));
+        palette.push_named("a b", rgb(0, 0, 255));
+
+        assert_eq!(
+            palette.to_scss_variables(""),
+            "$a-b: #ff0000;\n$a_b-2: #00ff00;\n$a-b-3: #0000ff;\n"
+        );
+        assert_eq!(
+            palette.to_less_variables(""),
+            "@a-b: #ff0000;\n@a_b: #00ff00;\n@a-b-3: #0000ff;\n"
+        );
+    }
+
+    #[test]
+    fn can_write_android_xml() {
+        let mut palette = Palette::new();
+        palette.push_named("On Surface", rgb(0, 105, 148));
+        palette.push(rgba(255, 0, 0, 0.2));
+
+        let xml = palette.to_android_xml("");
+        let colors: Vec<_> = xml.lines().filter(|line| line.contains("<color")).collect();
+
+        assert_eq!(
+            colors,
+            [
+                "    <color name=\"on_surface\">#006994</color>",
+                "    <color name=\"color_2\">#33FF0000</color>"
+            ]
+        );
+        assert_eq!(
+            Palette::new().to_android_xml("x"),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n</resources>\n"
+        );
+    }
+
+    #[test]
+    fn disambiguates_android_names() {
+        let mut palette = Palette::new();
+        palette.push_named("a-b", rgb(255, 0, 0));
+        palette.push_named("a_b", rgb(0, 255, 0));
+        palette.push_named("A B", rgb(0, 0, 255));
+        palette.push_named("a_b_2", rgb(0, 0, 0));
+
+        let xml = palette.to_android_xml("");
+        let names: Vec<_> = xml
+            .lines()
+            .filter(|line| line.contains("<color"))
+            .filter_map(|line| line.split('"').nth(1))
+            .collect();
+
+        assert_eq!(names, ["a_b", "a_b_2", "a_b_3", "a_b_2_4"]);
+    }
+
+    #[test]
+    fn can_write_native_colors() {
+        assert_eq!(
+            rgb(255, 255, 255).to_swiftui(),
+            "Color(red: 1.000, green: 1.000, blue: 1.000)"
+        );
+        assert_eq!(rgba(1, 2, 3, 0.0).to_compose(), "Color(0x00010203)");
+
+        let mut palette = Palette::new();
+        palette.push_named("on-surface", rgb(0, 105, 148));
+        palette.push(rgba(255, 0, 0, 0.2));
+
+        assert_eq!(
+            palette.to_swiftui(""),
+            concat!(
+                "import SwiftUI\n\n",
+                "extension Color {\n",
+                "    static let onSurface = Color(red: 0.000, green: 0.412, blue: 0.580)\n",
+                "    static let color2 = Color(red: 1.000, green: 0.000, blue: 0.000, opacity: 0.200)\n",
+                "}\n",
+            )
+        );
+        assert_eq!(
+            palette.to_compose("app"),
+            concat!(
+                "import androidx.compose.ui.graphics.Color\n\n",
+                "val AppOnSurface = Color(0xFF006994)\n",
+                "val App2 = Color(0x33FF0000)\n",
+            )
+        );
+    }
+
+    #[test]
+    fn escapes_native_keywords() {
+        let mut palette = Palette::new();
+        palette.push_named("default", rgb(0, 0, 0));
+        palette.push_named("object", rgb(255, 255, 255));
+
+        assert_eq!(
+            palette.to_swiftui(""),
+            concat!(
+                "import SwiftUI\n\n",
+                "extension Color {\n",
+                "    static let `default` = Color(red: 0.000, green: 0.000, blue: 0.000)\n",
+                "    static let object = Color(red: 1.000, green: 1.000, blue: 1.000)\n",
+                "}\n",
+            )
+        );
+        assert!(palette
+            .to_swiftui("brand")
+            .contains("static let brandDefault ="));
+
+        // Kotlin keywords are all lowercase, which Pascal case never is.
+        assert_eq!(
+            palette.to_compose(""),
+            concat!(
+                "import androidx.compose.ui.graphics.Color\n\n",
+                "val Default = Color(0xFF000000)\n",
+                "val Object = Color(0xFFFFFFFF)\n",
+            )
+        );
+    }
+
+    #[test]
+    fn disambiguates_native_names() {
+        let mut palette = Palette::new();
+        palette.push_named("a-b", rgb(255, 0, 0));
+        palette.push_named("a_b", rgb(0, 255, 0));
+        palette.push_named("A B", rgb(0, 0, 255));
+        palette.push_named("", rgb(0, 0, 0));
+        palette.push_named("-", rgb(255, 255, 255));
+
+        let swift = palette.to_swiftui("");
+        let names: Vec<_> = swift
+            .lines()
+            .filter_map(|line| line.strip_prefix("    static let "))
+            .filter_map(|line| line.split(' ').next())
+            .collect();
+        assert_eq!(names, ["aB", "aB2", "aB3", "color4", "color5"]);
+
+        let kotlin = palette.to_compose("");
+        let names: Vec<_> = kotlin
+            .lines()
+            .filter_map(|line| line.strip_prefix("val "))
+            .filter_map(|line| line.split(' ').next())
+            .collect();
+        assert_eq!(names, ["AB", "AB2", "AB3", "Color4", "Color5"]);
+    }
+
+    #[test]
+    fn can_write_shader_vectors() {
+        let grey = rgb(128, 128, 128);
+
+        assert_eq!(grey.to_glsl_vec4(3), "vec4(0.216, 0.216, 0.216, 1.000)");
+        assert_eq!(grey.to_wgsl_vec4(0), "vec4<f32>(0, 0, 0, 1)");
+        assert_eq!(
+            rgba(0, 0, 0, 0.0).to_glsl_vec4(1),
+            "vec4(0.0, 0.0, 0.0, 0.0)"
+        );
+        assert_eq!(grey.to_hsl().to_glsl_vec4(3), grey.to_glsl_vec4(3));
+    }
+
+    #[test]
+    fn joins_camel_case() {
+        assert_eq!(camel_case("brand-primary", false), "brandPrimary");
+        assert_eq!(camel_case("Deep-Blue_dark", false), "deepBlueDark");
+        assert_eq!(camel_case("color-50", true), "Color50");
+        assert_eq!(camel_case("--a", true), "A");
+    }
+
+    #[test]
+    fn names_identifiers() {
+        assert_eq!(identifier("brand", Some("primary"), 0), "brand-primary");
+        assert_eq!(identifier("", Some("on surface.dim"), 0), "on-surface-dim");
+        assert_eq!(identifier("", Some("50"), 0), "color-50");
+        assert_eq!(identifier("", None, 2), "color-3");
+        assert_eq!(identifier("gray", None, 2), "gray-3");
+    }
+}
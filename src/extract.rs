@@ -0,0 +1,450 @@
+use super::{oklab, Color, Palette, RGB};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The algorithms dominant colors can be extracted with.
+pub enum ExtractMethod {
+    /// K-means clustering, seeded with k-means++. Slower, but finds the
+    /// clusters that best fit the pixels.
+    KMeans,
+
+    /// Median cut, which repeatedly splits the box of pixels with the widest
+    /// range in two, where the split best separates them. Faster and fully
+    /// deterministic.
+    MedianCut,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A struct to configure `Palette::extract()`.
+pub struct ExtractOptions {
+    pub method: ExtractMethod,
+
+    // the maximum number of k-means iterations
+    pub iterations: usize,
+
+    // the seed of the k-means++ initialization, so results are reproducible
+    pub seed: u64,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        ExtractOptions {
+            method: ExtractMethod::KMeans,
+            iterations: 16,
+            seed: 0,
+        }
+    }
+}
+
+impl Palette {
+    /// Extracts up to `k` dominant colors from `pixels`, clustering them in
+    /// OKLab so the clusters match what looks similar. The colors are ordered
+    /// from the most common to the least common.
+    ///
+    /// Fewer than `k` colors are returned if the pixels have fewer distinct
+    /// colors, and none if there are no pixels.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, ExtractMethod, ExtractOptions, Palette};
+    ///
+    /// let mut pixels = vec![rgb(250, 250, 250); 60];
+    /// pixels.extend([rgb(255, 99, 71); 30]);
+    /// pixels.extend([rgb(250, 105, 75); 10]);
+    ///
+    /// let palette = Palette::extract(&pixels, 2, ExtractOptions::default());
+    ///
+    /// assert_eq!(palette.get(0), Some(rgba(250, 250, 250, 1.0)));
+    /// assert_eq!(palette.get(1), Some(rgba(254, 101, 72, 1.0)));
+    ///
+    /// let options = ExtractOptions {
+    ///     method: ExtractMethod::MedianCut,
+    ///     ..ExtractOptions::default()
+    /// };
+    ///
+    /// assert_eq!(Palette::extract(&pixels, 2, options), palette);
+    /// ```
+    pub fn extract(pixels: &[RGB], k: usize, options: ExtractOptions) -> Palette {
+        let points = unique_points(pixels);
+
+        if points.is_empty() || k == 0 {
+            return Palette::new();
+        }
+
+        let mut clusters = match options.method {
+            ExtractMethod::KMeans => k_means(&points, k, options),
+            ExtractMethod::MedianCut => median_cut(&points, k),
+        };
+
+        // The sort is stable, so clusters of equal size keep their order.
+        clusters.sort_by(|lhs, rhs| rhs.weight.total_cmp(&lhs.weight));
+
+        clusters
+            .into_iter()
+            .map(|cluster| {
+                let [l, a, b] = cluster.center;
+                oklab(l, a, b).to_rgb()
+            })
+            .collect()
+    }
+}
+
+// A distinct color in OKLab, weighted by how many pixels share it.
+#[derive(Copy, Clone)]
+struct Point {
+    lab: [f32; 3],
+    weight: f32,
+}
+
+struct Cluster {
+    center: [f32; 3],
+    weight: f32,
+}
+
+// Merges identical pixels, which are common in images and make clustering a
+// lot cheaper. Points are kept in the order they first appear.
+fn unique_points(pixels: &[RGB]) -> Vec<Point> {
+    let mut indices: HashMap<u32, usize> = HashMap::new();
+    let mut points: Vec<Point> = Vec::new();
+
+    for &pixel in pixels {
+        let key = u32::from_be_bytes([0, pixel.r.as_u8(), pixel.g.as_u8(), pixel.b.as_u8()]);
+
+        match indices.get(&key) {
+            Some(&index) => points[index].weight += 1.0,
+            None => {
+                let lab = pixel.to_oklab();
+                indices.insert(key, points.len());
+                points.push(Point {
+                    lab: [lab.l, lab.a, lab.b],
+                    weight: 1.0,
+                });
+            }
+        }
+    }
+
+    points
+}
+
+fn distance_squared(lhs: [f32; 3], rhs: [f32; 3]) -> f32 {
+    (0..3).map(|i| (lhs[i] - rhs[i]).powi(2)).sum()
+}
+
+// Computes the weighted mean of points, along with their total weight.
+fn centroid<'a>(points: impl Iterator<Item = &'a Point>) -> Cluster {
+    let mut center = [0.0; 3];
+    let mut weight = 0.0;
+
+    for point in points {
+        for (sum, channel) in center.iter_mut().zip(point.lab) {
+            *sum += channel * point.weight;
+        }
+        weight += point.weight;
+    }
+
+    Cluster {
+        center: center.map(|sum| sum / weight),
+        weight,
+    }
+}
+
+// A xorshift64* generator, which is plenty for picking initial centers.
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        // Xorshift gets stuck on zero, so mix the seed with an odd constant.
+        Random(seed ^ 0x9e37_79b9_7f4a_7c15)
+    }
+
+    // Returns a float between 0.0 (inclusive) and 1.0 (exclusive).
+    fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+
+        let bits = self.0.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 40;
+        bits as f32 / (1u64 << 24) as f32
+    }
+}
+
+// Picks the initial centers with k-means++: each one is a point drawn with a
+// probability proportional to its squared distance to the closest center.
+fn k_means_plus_plus(points: &[Point], k: usize, random: &mut Random) -> Vec<[f32; 3]> {
+    let mut centers = Vec::with_capacity(k);
+    let mut distances = vec![f32::INFINITY; points.len()];
+
+    while centers.len() < k {
+        let weights: Vec<f32> = if centers.is_empty() {
+            points.iter().map(|point| point.weight).collect()
+        } else {
+            points
+                .iter()
+                .zip(&distances)
+                .map(|(point, distance)| point.weight * distance)
+                .collect()
+        };
+
+        let total: f32 = weights.iter().sum();
+
+        // Every point already is a center.
+        if total <= 0.0 {
+            break;
+        }
+
+        let mut target = random.next_f32() * total;
+        let mut chosen = points.len() - 1;
+
+        for (i, &weight) in weights.iter().enumerate() {
+            if weight > 0.0 && target < weight {
+                chosen = i;
+                break;
+            }
+            target -= weight;
+        }
+
+        // Rounding can leave the target past the last candidate.
+        while weights[chosen] <= 0.0 {
+            chosen -= 1;
+        }
+
+        let center = points[chosen].lab;
+        centers.push(center);
+
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(distance_squared(point.lab, center));
+        }
+    }
+
+    centers
+}
+
+fn nearest_center(centers: &[[f32; 3]], lab: [f32; 3]) -> usize {
+    let mut nearest = 0;
+
+    for (i, &center) in centers.iter().enumerate().skip(1) {
+        if distance_squared(lab, center) < distance_squared(lab, centers[nearest]) {
+            nearest = i;
+        }
+    }
+
+    nearest
+}
+
+fn k_means(points: &[Point], k: usize, options: ExtractOptions) -> Vec<Cluster> {
+    let mut random = Random::new(options.seed);
+    let mut centers = k_means_plus_plus(points, k, &mut random);
+    let mut assignments = vec![usize::MAX; points.len()];
+
+    for _ in 0..options.iterations.max(1) {
+        let mut changed = false;
+
+        for (assignment, point) in assignments.iter_mut().zip(points) {
+            let nearest = nearest_center(&centers, point.lab);
+
+            if *assignment != nearest {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+
+        for (i, center) in centers.iter_mut().enumerate() {
+            let members = points
+                .iter()
+                .zip(&assignments)
+                .filter(|&(_, &assignment)| assignment == i)
+                .map(|(point, _)| point);
+
+            // Keep the previous center for clusters that lost every point.
+            let cluster = centroid(members);
+            if cluster.weight > 0.0 {
+                *center = cluster.center;
+            }
+        }
+    }
+
+    (0..centers.len())
+        .map(|i| {
+            centroid(
+                points
+                    .iter()
+                    .zip(&assignments)
+                    .filter(|&(_, &assignment)| assignment == i)
+                    .map(|(point, _)| point),
+            )
+        })
+        .filter(|cluster| cluster.weight > 0.0)
+        .collect()
+}
+
+// Returns the axis along which the points spread the most, and by how much.
+fn widest_axis(points: &[Point]) -> (usize, f32) {
+    (0..3)
+        .map(|axis| {
+            let (min, max) = points
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), point| {
+                    (min.min(point.lab[axis]), max.max(point.lab[axis]))
+                });
+
+            (axis, max - min)
+        })
+        .fold(
+            (0, 0.0),
+            |widest, axis| if axis.1 > widest.1 { axis } else { widest },
+        )
+}
+
+fn median_cut(points: &[Point], k: usize) -> Vec<Cluster> {
+    let mut boxes = vec![points.to_vec()];
+
+    while boxes.len() < k {
+        // Split the box with the widest range, if any can still be split.
+        let (index, (axis, range)) = boxes
+            .iter()
+            .map(|points| widest_axis(points))
+            .enumerate()
+            .fold((0, (0, 0.0)), |widest, (i, axis)| {
+                if axis.1 > widest.1 .1 {
+                    (i, axis)
+                } else {
+                    widest
+                }
+            });
+
+        if range <= 0.0 {
+            break;
+        }
+
+        let mut points = std::mem::take(&mut boxes[index]);
+        points.sort_by(|lhs, rhs| lhs.lab[axis].total_cmp(&rhs.lab[axis]));
+
+        // Rather than at the plain median, which can cut a cluster in half,
+        // split where the two halves vary the least along the axis.
+        let sums = |points: &[Point]| {
+            points.iter().fold((0.0, 0.0, 0.0), |(w, x, xx), point| {
+                let value = point.lab[axis];
+                (
+                    w + point.weight,
+                    x + point.weight * value,
+                    xx + point.weight * value * value,
+                )
+            })
+        };
+        let total = sums(&points);
+        let mut lower = (0.0, 0.0, 0.0);
+        let mut split = 1;
+        let mut best = f32::INFINITY;
+
+        for i in 1..points.len() {
+            let point = points[i - 1];
+            let value = point.lab[axis];
+            lower.0 += point.weight;
+            lower.1 += point.weight * value;
+            lower.2 += point.weight * value * value;
+
+            let upper = (total.0 - lower.0, total.1 - lower.1, total.2 - lower.2);
+            let variance =
+                lower.2 - lower.1 * lower.1 / lower.0 + upper.2 - upper.1 * upper.1 / upper.0;
+
+            if variance < best {
+                best = variance;
+                split = i;
+            }
+        }
+
+        let upper = points.split_off(split);
+        boxes[index] = points;
+        boxes.push(upper);
+    }
+
+    boxes.iter().map(|points| centroid(points.iter())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{rgb, rgba, Color, ExtractMethod, ExtractOptions, Palette, RGB};
+
+    const METHODS: [ExtractMethod; 2] = [ExtractMethod::KMeans, ExtractMethod::MedianCut];
+
+    fn options(method: ExtractMethod) -> ExtractOptions {
+        ExtractOptions {
+            method,
+            ..ExtractOptions::default()
+        }
+    }
+
+    fn pixels() -> Vec<RGB> {
+        let mut pixels = Vec::new();
+
+        for i in 0..50 {
+            pixels.push(rgb(20 + i % 5, 40, 200));
+        }
+        for i in 0..30 {
+            pixels.push(rgb(240, 200 + i % 10, 20));
+        }
+        for i in 0..20 {
+            pixels.push(rgb(10, 160, 40 + i % 3));
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn finds_dominant_colors() {
+        for method in METHODS {
+            let palette = Palette::extract(&pixels(), 3, options(method));
+
+            assert_eq!(palette.len(), 3);
+
+            for (color, expected) in
+                palette
+                    .iter()
+                    .zip([rgb(22, 40, 200), rgb(240, 204, 20), rgb(10, 160, 41)])
+            {
+                assert!(
+                    color.delta_e2000(expected) < 1.0,
+                    "{:?} {:?}",
+                    method,
+                    palette
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_deterministic() {
+        for method in METHODS {
+            assert_eq!(
+                Palette::extract(&pixels(), 4, options(method)),
+                Palette::extract(&pixels(), 4, options(method))
+            );
+        }
+    }
+
+    #[test]
+    fn handles_few_distinct_colors() {
+        let pixels = [rgb(255, 0, 0), rgb(0, 0, 255), rgb(255, 0, 0)];
+
+        for method in METHODS {
+            let palette = Palette::extract(&pixels, 5, options(method));
+
+            assert_eq!(
+                palette.to_rgba(),
+                vec![rgba(255, 0, 0, 1.0), rgba(0, 0, 255, 1.0)]
+            );
+        }
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        for method in METHODS {
+            assert!(Palette::extract(&[], 3, options(method)).is_empty());
+            assert!(Palette::extract(&pixels(), 0, options(method)).is_empty());
+        }
+    }
+}
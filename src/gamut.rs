@@ -0,0 +1,374 @@
+use super::{
+    lab,
+    linear::{from_xyz, to_xyz},
+    oklch, OKLAB, OKLCH,
+};
+
+/// The RGB gamuts colors can be checked against and mapped into, which
+/// differ in how saturated their primaries are.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Gamut {
+    /// The gamut of sRGB, and of every [`RGB`](crate::RGB) color, and the
+    /// default.
+    #[default]
+    Srgb,
+
+    /// The wider gamut of Display P3, shown by most recent phones and laptops.
+    DisplayP3,
+
+    /// The much wider gamut of Rec. 2020, used by HDR video.
+    Rec2020,
+}
+
+// The matrices below convert between D65 CIE XYZ and the linear channels of
+// each gamut, rounded from the ones of the CSS Color spec.
+#[allow(clippy::excessive_precision)]
+const FROM_XYZ_P3: [[f64; 3]; 3] = [
+    [2.4934969119, -0.9313836179, -0.4027107845],
+    [-0.8294889696, 1.7626640603, 0.0236246858],
+    [0.0358458302, -0.0761723893, 0.9568845240],
+];
+
+#[allow(clippy::excessive_precision)]
+const FROM_XYZ_REC2020: [[f64; 3]; 3] = [
+    [1.7166511880, -0.3556707838, -0.2533662814],
+    [-0.6666843518, 1.6164812366, 0.0157685458],
+    [0.0176398574, -0.0427706133, 0.9421031212],
+];
+
+#[allow(clippy::excessive_precision)]
+const TO_XYZ_P3: [[f64; 3]; 3] = [
+    [0.4865709486, 0.2656676932, 0.1982172852],
+    [0.2289745641, 0.6917385218, 0.0792869141],
+    [0.0, 0.0451133819, 1.0439443689],
+];
+
+#[allow(clippy::excessive_precision)]
+const TO_XYZ_REC2020: [[f64; 3]; 3] = [
+    [0.6369580483, 0.1446169036, 0.1688809752],
+    [0.2627002120, 0.6779980715, 0.0593017165],
+    [0.0, 0.0280726930, 1.0609850577],
+];
+
+// The difference clipping may leave in a color during gamut mapping, and the
+// precision the chroma is searched to, as given by the CSS Color spec.
+const JND: f32 = 0.02;
+const EPSILON: f32 = 0.0001;
+
+impl Gamut {
+    // Converts linear sRGB channels into the linear channels of `self`, which
+    // fall outside of the 0.0-1.0 range for colors outside of it.
+    pub(crate) fn channels(self, linear: [f32; 3]) -> [f32; 3] {
+        let matrix = match self {
+            Gamut::Srgb => return linear,
+            Gamut::DisplayP3 => FROM_XYZ_P3,
+            Gamut::Rec2020 => FROM_XYZ_REC2020,
+        };
+
+        let xyz = to_xyz(linear.map(|channel| channel as f64));
+        matrix.map(|row| (row[0] * xyz[0] + row[1] * xyz[1] + row[2] * xyz[2]) as f32)
+    }
+
+    // Converts the linear channels of `self` back into linear sRGB channels.
+    pub(crate) fn to_srgb(self, channels: [f32; 3]) -> [f32; 3] {
+        let matrix = match self {
+            Gamut::Srgb => return channels,
+            Gamut::DisplayP3 => TO_XYZ_P3,
+            Gamut::Rec2020 => TO_XYZ_REC2020,
+        };
+
+        let channels = channels.map(|channel| channel as f64);
+        let xyz =
+            matrix.map(|row| row[0] * channels[0] + row[1] * channels[1] + row[2] * channels[2]);
+        from_xyz(xyz).map(|channel| channel as f32)
+    }
+
+    // Clips linear sRGB channels channel by channel into `self`.
+    pub(crate) fn clip(self, linear: [f32; 3]) -> [f32; 3] {
+        self.to_srgb(self.channels(linear).map(|channel| channel.clamp(0.0, 1.0)))
+    }
+
+    // Returns whether linear sRGB channels fall within `self`, with every
+    // channel allowed to stray up to `tolerance` outside of it.
+    pub(crate) fn contains(self, linear: [f32; 3], tolerance: f32) -> bool {
+        self.channels(linear)
+            .iter()
+            .all(|channel| (-tolerance..=1.0 + tolerance).contains(channel))
+    }
+}
+
+/// Returns the highest OKLCH chroma a color with the lightness `l` and the hue
+/// `h` can have while staying inside the `gamut`, as the most vivid version of
+/// that color a display can show. Lightnesses of black and white, or outside
+/// of them, only hold greys.
+///
+/// # Example
+/// ```
+/// use farver::{max_chroma, oklch, Gamut};
+///
+/// let c = max_chroma(0.7, 150.0, Gamut::Srgb);
+///
+/// assert!(oklch(0.7, c, 150.0).in_gamut(Gamut::Srgb, 1e-4));
+/// assert!(max_chroma(0.7, 150.0, Gamut::DisplayP3) > c);
+/// assert_eq!(max_chroma(1.0, 150.0, Gamut::Srgb), 0.0);
+/// ```
+pub fn max_chroma(l: f32, h: f32, gamut: Gamut) -> f32 {
+    if !(l > 0.0 && l < 1.0) {
+        return 0.0;
+    }
+
+    // no color of any of the gamuts reaches this chroma
+    let (mut low, mut high) = (0.0, 0.5);
+
+    for _ in 0..20 {
+        let mid = (low + high) / 2.0;
+
+        if oklch(l, mid, h).in_gamut(gamut, 1e-4) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+/// Returns the highest CIE LCH chroma a color with the lightness `l` and the
+/// hue `h` can have while staying inside the `gamut`, like `max_chroma()` does
+/// for OKLCH. CIE LCH is the polar form of [`LAB`](crate::LAB), so lightnesses
+/// range between `0.0` and `100.0`, and the chroma is that of
+/// `lab(l, c * cos(h), c * sin(h))`. Lightnesses of black and white, or
+/// outside of them, only hold greys.
+///
+/// # Example
+/// ```
+/// use farver::{lab, max_chroma_lch, Gamut};
+///
+/// let c = max_chroma_lch(70.0, 150.0, Gamut::Srgb);
+/// let (sin, cos) = 150f32.to_radians().sin_cos();
+///
+/// assert!(lab(70.0, c * cos, c * sin).in_gamut(Gamut::Srgb, 1e-4));
+/// assert!(max_chroma_lch(70.0, 150.0, Gamut::DisplayP3) > c);
+/// assert_eq!(max_chroma_lch(100.0, 150.0, Gamut::Srgb), 0.0);
+/// ```
+pub fn max_chroma_lch(l: f32, h: f32, gamut: Gamut) -> f32 {
+    if !(l > 0.0 && l < 100.0) {
+        return 0.0;
+    }
+
+    // no color of any of the gamuts reaches this chroma
+    let (mut low, mut high) = (0.0, 250.0);
+    let (sin, cos) = h.to_radians().sin_cos();
+
+    for _ in 0..30 {
+        let mid = (low + high) / 2.0;
+
+        if lab(l, mid * cos, mid * sin).in_gamut(gamut, 1e-4) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+// Maps a color into `gamut` with the gamut mapping algorithm of the CSS Color
+// spec, a binary search of the chroma at which clipping the color changes it
+// by less than a just noticeable difference.
+pub(crate) fn fit_in_gamut(color: OKLCH, gamut: Gamut) -> OKLCH {
+    if color.l >= 1.0 {
+        return oklch(1.0, 0.0, color.h);
+    }
+    if color.l <= 0.0 {
+        return oklch(0.0, 0.0, color.h);
+    }
+    if color.in_gamut(gamut, 0.0) {
+        return color;
+    }
+
+    let clip = |color: OKLCH| {
+        let clipped = OKLAB::from_linear(gamut.clip(color.to_oklab().to_linear()));
+        (clipped, clipped.distance(color.to_oklab()))
+    };
+
+    let (mut clipped, difference) = clip(color);
+    if difference < JND {
+        return clipped.to_oklch();
+    }
+
+    let (mut min, mut max) = (0.0, color.c);
+    let mut min_in_gamut = true;
+
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        let current = oklch(color.l, chroma, color.h);
+
+        if min_in_gamut && current.in_gamut(gamut, 0.0) {
+            min = chroma;
+            continue;
+        }
+
+        let difference;
+        (clipped, difference) = clip(current);
+
+        if difference < JND {
+            if JND - difference < EPSILON {
+                break;
+            }
+
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped.to_oklch()
+}
+
+// Converts D65 CIE XYZ into linear sRGB channels, for the float types that go
+// through it.
+pub(crate) fn xyz_to_srgb(xyz: [f32; 3]) -> [f32; 3] {
+    from_xyz(xyz.map(|channel| channel as f64)).map(|channel| channel as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_chroma, max_chroma_lch, Gamut};
+    use crate::{lab, oklch, rgb, xyz, Color};
+
+    #[test]
+    fn contains_srgb_colors() {
+        for gamut in [Gamut::Srgb, Gamut::DisplayP3, Gamut::Rec2020] {
+            for color in [rgb(255, 0, 0), rgb(0, 255, 0), rgb(255, 255, 255)] {
+                assert!(color.to_oklab().in_gamut(gamut, 1e-4), "{:?}", gamut);
+                assert!(color.to_oklch().in_gamut(gamut, 1e-4), "{:?}", gamut);
+                assert!(color.to_lab().in_gamut(gamut, 1e-3), "{:?}", gamut);
+                assert!(color.to_xyz().in_gamut(gamut, 1e-4), "{:?}", gamut);
+            }
+        }
+    }
+
+    #[test]
+    fn detects_wide_gamut_colors() {
+        // A red between the primaries of sRGB and Display P3.
+        let red = oklch(0.64, 0.27, 29.0);
+
+        assert!(!red.in_gamut(Gamut::Srgb, 1e-4));
+        assert!(red.in_gamut(Gamut::DisplayP3, 1e-4));
+        assert!(red.in_gamut(Gamut::Rec2020, 1e-4));
+
+        let green = oklch(0.8, 0.35, 145.0);
+        assert!(!green.in_gamut(Gamut::DisplayP3, 1e-4));
+        assert!(!lab(50.0, 0.0, -150.0).in_gamut(Gamut::Rec2020, 1e-4));
+        assert!(!xyz(1.5, 1.5, 1.5).in_gamut(Gamut::Srgb, 1e-4));
+    }
+
+    #[test]
+    fn fits_colors_in_gamut() {
+        for gamut in [Gamut::Srgb, Gamut::DisplayP3, Gamut::Rec2020] {
+            for hue in (0..360).step_by(15) {
+                for l in [0.2, 0.5, 0.8, 0.95] {
+                    let color = oklch(l, 0.5, hue as f32);
+                    let fitted = color.fit_in_gamut(gamut);
+
+                    assert!(fitted.in_gamut(gamut, 1e-3), "{} {:?}", color, gamut);
+                    assert!((fitted.l - l).abs() < 0.03, "{} {}", color, fitted);
+                    assert!(fitted.c < 0.5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_colors_in_gamut() {
+        let color = oklch(0.6, 0.1, 250.0);
+
+        assert_eq!(color.fit_in_gamut(Gamut::Srgb), color);
+        assert_eq!(
+            oklch(1.2, 0.3, 30.0).fit_in_gamut(Gamut::Srgb),
+            oklch(1.0, 0.0, 30.0)
+        );
+        assert_eq!(oklch(-0.1, 0.3, 30.0).fit_in_gamut(Gamut::Srgb).l, 0.0);
+        assert!(rgb(255, 0, 0)
+            .to_oklab()
+            .fit_in_gamut(Gamut::Srgb)
+            .in_gamut(Gamut::Srgb, 1e-4));
+    }
+
+    #[test]
+    fn clips_colors_to_gamut() {
+        for gamut in [Gamut::Srgb, Gamut::DisplayP3, Gamut::Rec2020] {
+            let color = oklch(0.5, 0.4, 265.0);
+            let clipped = color.clip_to_gamut(gamut);
+
+            assert!(clipped.in_gamut(gamut, 1e-3), "{:?}", gamut);
+            assert_eq!(
+                clipped.clip_to_gamut(gamut).to_string(),
+                clipped.to_string()
+            );
+        }
+
+        // Clipping shifts hues more than gamut mapping does.
+        let blue = oklch(0.5, 0.4, 265.0);
+        let drift = |color: crate::OKLCH| (color.h - blue.h).abs();
+        assert!(drift(blue.clip_to_gamut(Gamut::Srgb)) > drift(blue.fit_in_gamut(Gamut::Srgb)));
+
+        let color = oklch(0.6, 0.1, 250.0).to_oklab();
+        assert_eq!(
+            color.clip_to_gamut(Gamut::Srgb).to_string(),
+            color.to_string()
+        );
+    }
+
+    #[test]
+    fn finds_max_chroma() {
+        for hue in (0..360).step_by(10) {
+            let h = hue as f32;
+            let srgb = max_chroma(0.6, h, Gamut::Srgb);
+            let p3 = max_chroma(0.6, h, Gamut::DisplayP3);
+
+            assert!(oklch(0.6, srgb, h).in_gamut(Gamut::Srgb, 1e-4));
+            assert!(!oklch(0.6, srgb + 1e-3, h).in_gamut(Gamut::Srgb, 1e-4));
+            // Display P3 reaches a hair outside of Rec. 2020 around its red.
+            assert!(p3 >= srgb && max_chroma(0.6, h, Gamut::Rec2020) >= srgb);
+        }
+
+        // The sRGB red primary is as vivid as its hue gets.
+        let red = rgb(255, 0, 0).to_oklch();
+        assert!((max_chroma(red.l, red.h, Gamut::Srgb) - red.c).abs() < 1e-3);
+        assert_eq!(max_chroma(0.0, 0.0, Gamut::Srgb), 0.0);
+        assert_eq!(max_chroma(f32::NAN, 0.0, Gamut::Srgb), 0.0);
+    }
+
+    #[test]
+    fn finds_max_lch_chroma() {
+        for hue in (0..360).step_by(10) {
+            let h = hue as f32;
+            let (sin, cos) = h.to_radians().sin_cos();
+            let srgb = max_chroma_lch(60.0, h, Gamut::Srgb);
+            let rec2020 = max_chroma_lch(60.0, h, Gamut::Rec2020);
+
+            assert!(lab(60.0, srgb * cos, srgb * sin).in_gamut(Gamut::Srgb, 1e-4));
+            assert!(!lab(60.0, (srgb + 0.1) * cos, (srgb + 0.1) * sin).in_gamut(Gamut::Srgb, 1e-4));
+            assert!(rec2020 >= srgb && rec2020 < 250.0);
+        }
+
+        // The sRGB blue primary is as vivid as its hue gets.
+        let blue = rgb(0, 0, 255).to_lab();
+        let (c, h) = (blue.a.hypot(blue.b), blue.b.atan2(blue.a).to_degrees());
+        assert!((max_chroma_lch(blue.l, h, Gamut::Srgb) - c).abs() < 0.1);
+        assert_eq!(max_chroma_lch(0.0, 0.0, Gamut::Srgb), 0.0);
+        assert_eq!(max_chroma_lch(f32::NAN, 0.0, Gamut::Srgb), 0.0);
+    }
+
+    #[test]
+    fn allows_tolerance() {
+        let white = xyz(0.9505, 1.0, 1.0891);
+
+        assert!(white.in_gamut(Gamut::Srgb, 0.01));
+        assert!(!xyz(0.96, 1.01, 1.1).in_gamut(Gamut::Srgb, 0.0));
+        assert!(xyz(0.96, 1.01, 1.1).in_gamut(Gamut::Srgb, 0.02));
+    }
+}
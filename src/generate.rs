@@ -0,0 +1,567 @@
+use super::{
+    difference::delta_e2000,
+    max_chroma,
+    oklab::normalize_hue,
+    oklch,
+    ramp::{fit_chroma, in_gamut},
+    Color, Gamut, Palette, LAB, RGB,
+};
+use std::hash::{Hash, Hasher};
+
+/// Maps any hashable value, like a user name or a label, to a color. The same
+/// value always maps to the same color, across runs and platforms, so it can
+/// be used to color avatars or tags without storing the colors anywhere.
+///
+/// Colors are spread over every OKLCH hue, with a lightness between 0.55 and
+/// 0.75 and a chroma between 0.1 and 0.15, so none of them is garish, washed
+/// out, or too dark to tell apart.
+///
+/// # Example
+/// ```
+/// use farver::{from_hash, Color};
+///
+/// assert_eq!(from_hash("nyxkrage"), from_hash("nyxkrage"));
+/// assert_ne!(from_hash("nyxkrage"), from_hash("farver"));
+///
+/// let lightness = from_hash(42).to_oklch().l;
+/// assert!(lightness > 0.54 && lightness < 0.76);
+/// ```
+pub fn from_hash(input: impl Hash) -> RGB {
+    let mut hasher = Fnv::default();
+    input.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let channel = |shift: u32| ((hash >> shift) & 0xFFFF) as f32 / 65535.0;
+
+    fit_chroma(
+        0.55 + 0.2 * channel(16),
+        0.1 + 0.05 * channel(32),
+        360.0 * channel(0),
+    )
+}
+
+// The golden angle, 360° divided by the golden ratio squared. Stepping by it
+// never lands twice on the same hue, and always lands in the widest gap left.
+const GOLDEN_ANGLE: f32 = 137.50776;
+
+/// Returns an endless sequence of colors with hues spread as far apart as
+/// possible, for assigning colors to any number of chart series.
+///
+/// The sequence starts at `seed`, then keeps its OKLCH lightness and chroma
+/// while stepping the hue by the golden angle (about 137.5°), so however
+/// many colors are taken, their hues are close to evenly spread. Hues the
+/// sRGB gamut cannot hold at that chroma get the highest chroma it can.
+///
+/// # Example
+/// ```
+/// use farver::{golden_hues, rgb, Color, HueSpace};
+///
+/// let series: Vec<_> = golden_hues(rgb(23, 98, 119)).take(3).collect();
+///
+/// assert_eq!(series[0], rgb(23, 98, 119));
+/// assert!(series[0].hue_distance(series[1], HueSpace::OKLCH) > 120.0);
+/// assert!(series[1].hue_distance(series[2], HueSpace::OKLCH) > 120.0);
+/// ```
+pub fn golden_hues<T: Color>(seed: T) -> GoldenHues {
+    GoldenHues {
+        seed: seed.to_rgb(),
+        index: 0,
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An endless iterator over colors with hues spread by the golden angle,
+/// created by `golden_hues()`.
+pub struct GoldenHues {
+    seed: RGB,
+    index: u32,
+}
+
+impl Iterator for GoldenHues {
+    type Item = RGB;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+
+        if index == 0 {
+            return Some(self.seed);
+        }
+
+        // Reducing the steps first keeps hues precise far into the sequence.
+        let oklch = self.seed.to_oklch();
+        let turn = (index as f64 * GOLDEN_ANGLE as f64 % 360.0) as f32;
+
+        Some(fit_chroma(oklch.l, oklch.c, normalize_hue(oklch.h + turn)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Returns an endless sequence of colors going around the hue circle from
+/// `seed`, stepping the OKLCH hue by `stride` degrees every color, for
+/// coloring log lines, traces or live series that keep coming.
+///
+/// Every color has the lightness and chroma of `seed`, so they all look
+/// related. When some hues can't hold that chroma in sRGB, the chroma of
+/// the whole sequence is lowered to the highest one every hue can hold.
+/// Negative strides go around the circle the other way.
+///
+/// # Example
+/// ```
+/// use farver::{hue_cycle, oklch, Color};
+///
+/// let mut cycle = hue_cycle(oklch(0.7, 0.1, 30.0).to_rgb(), 90.0);
+///
+/// assert_eq!(format!("{:.0}", cycle.next().unwrap().to_oklch().h), "30");
+/// assert_eq!(format!("{:.0}", cycle.next().unwrap().to_oklch().h), "120");
+/// assert_eq!(cycle.nth(2).unwrap(), cycle.nth(3).unwrap());
+/// ```
+pub fn hue_cycle<T: Color>(seed: T, stride: f32) -> HueCycle {
+    let oklch = seed.to_oklch();
+    let l = oklch.l.clamp(0.0, 1.0);
+
+    // the highest chroma every hue can hold, found hue by hue
+    let c = (0..360)
+        .map(|hue| max_chroma(l, hue as f32, Gamut::Srgb))
+        .fold(oklch.c, f32::min);
+
+    HueCycle {
+        l,
+        c,
+        h: if oklch.h.is_nan() { 0.0 } else { oklch.h },
+        stride,
+        index: 0,
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An endless iterator over colors going around the hue circle at a fixed
+/// stride, created by `hue_cycle()`.
+pub struct HueCycle {
+    l: f32,
+    c: f32,
+    h: f32,
+    stride: f32,
+    index: u32,
+}
+
+impl Iterator for HueCycle {
+    type Item = RGB;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+
+        // Reducing the steps first keeps hues precise far into the sequence.
+        let turn = (index as f64 * self.stride as f64 % 360.0) as f32;
+
+        Some(fit_chroma(self.l, self.c, normalize_hue(self.h + turn)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to configure `categorical()`, bounding the OKLCH lightness and
+/// chroma of the colors it picks.
+pub struct CategoricalConstraints {
+    pub min_lightness: f32,
+    pub max_lightness: f32,
+    pub min_chroma: f32,
+    pub max_chroma: f32,
+}
+
+impl Default for CategoricalConstraints {
+    fn default() -> Self {
+        CategoricalConstraints {
+            min_lightness: 0.45,
+            max_lightness: 0.85,
+            min_chroma: 0.08,
+            max_chroma: 0.2,
+        }
+    }
+}
+
+/// Picks `n` colors as distinguishable from each other as possible, for
+/// categorical data visualizations.
+///
+/// Colors are picked among the sRGB colors within `constraints`, to maximize
+/// the smallest CIEDE2000 difference between any two of them. The search
+/// starts from the farthest-point picks, then keeps swapping colors for
+/// better ones until none can improve. The same arguments always give the
+/// same palette. Fewer colors are returned when the constraints do not allow
+/// `n`, and panics if a minimum is above its maximum.
+///
+/// # Example
+/// ```
+/// use farver::{categorical, CategoricalConstraints, Color};
+///
+/// let palette = categorical(8, CategoricalConstraints::default());
+///
+/// assert_eq!(palette.len(), 8);
+/// for (i, lhs) in palette.iter().enumerate() {
+///     for rhs in palette.iter().skip(i + 1) {
+///         assert!(lhs.delta_e2000(rhs) > 15.0);
+///     }
+/// }
+/// ```
+pub fn categorical(n: usize, constraints: CategoricalConstraints) -> Palette {
+    let CategoricalConstraints {
+        min_lightness,
+        max_lightness,
+        min_chroma,
+        max_chroma,
+    } = constraints;
+
+    assert!(min_lightness <= max_lightness, "Invalid lightness bounds");
+    assert!(min_chroma <= max_chroma, "Invalid chroma bounds");
+
+    let mut candidates: Vec<(RGB, LAB)> = grid(min_lightness, max_lightness, 6)
+        .flat_map(|l| grid(min_chroma, max_chroma, 4).map(move |c| (l, c)))
+        .flat_map(|(l, c)| (0..36).map(move |h| (l, c, h as f32 * 10.0)))
+        .filter(|&(l, c, h)| in_gamut(l, c, h))
+        .map(|(l, c, h)| {
+            let color = oklch(l, c, h).to_rgb();
+            (color, color.to_lab())
+        })
+        .collect();
+
+    // Greys are the same at every hue, so only keep each color once.
+    let mut seen = Vec::with_capacity(candidates.len());
+    candidates.retain(|&(color, _)| {
+        let unique = !seen.contains(&color);
+        seen.push(color);
+        unique
+    });
+
+    let n = n.min(candidates.len());
+
+    if n == 0 {
+        return Palette::new();
+    }
+
+    // The smallest difference between a candidate and the picks, ignoring the
+    // pick at `skip`.
+    let spread = |picks: &[usize], candidate: usize, skip: Option<usize>| {
+        picks
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| Some(i) != skip)
+            .map(|(_, &pick)| delta_e2000(candidates[candidate].1, candidates[pick].1))
+            .fold(f32::INFINITY, f32::min)
+    };
+
+    let best = |picks: &[usize], skip: Option<usize>| {
+        (0..candidates.len())
+            .filter(|candidate| !picks.contains(candidate))
+            .map(|candidate| (candidate, spread(picks, candidate, skip)))
+            .max_by(|lhs, rhs| lhs.1.total_cmp(&rhs.1).then(rhs.0.cmp(&lhs.0)))
+    };
+
+    // Start with the most chromatic candidate, then add farthest points.
+    let first = (0..candidates.len())
+        .max_by(|&lhs, &rhs| {
+            let chroma = |i: usize| candidates[i].0.to_oklch().c;
+            chroma(lhs).total_cmp(&chroma(rhs)).then(rhs.cmp(&lhs))
+        })
+        .unwrap();
+    let mut picks = vec![first];
+
+    while picks.len() < n {
+        let (candidate, _) = best(&picks, None).unwrap();
+        picks.push(candidate);
+    }
+
+    // Swap picks for candidates that are farther from the rest, until the
+    // picks settle. Each swap improves a pick, so this always ends.
+    for _ in 0..32 {
+        let mut improved = false;
+
+        for i in 0..n {
+            let current = spread(&picks, picks[i], Some(i));
+
+            if let Some((candidate, distance)) = best(&picks, Some(i)) {
+                if distance > current + 1e-3 {
+                    picks[i] = candidate;
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    picks.into_iter().map(|pick| candidates[pick].0).collect()
+}
+
+// Returns `steps` values evenly spread from `min` to `max`, or `min` alone if
+// they are the same.
+fn grid(min: f32, max: f32, steps: usize) -> impl Iterator<Item = f32> {
+    let steps = if min == max { 1 } else { steps };
+
+    (0..steps).map(move |i| {
+        if steps == 1 {
+            min
+        } else {
+            min + (max - min) * i as f32 / (steps - 1) as f32
+        }
+    })
+}
+
+// The 64-bit FNV-1a hash, which unlike the standard library's hasher is
+// guaranteed to never change. Integers are hashed as little endian bytes,
+// and sizes as 64-bit integers, so hashes are the same on every platform.
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Fnv(0xCBF2_9CE4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+
+    fn write_u8(&mut self, n: u8) {
+        self.write(&[n]);
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        // FNV barely mixes its last bytes in, so inputs differing only by
+        // their end are scrambled with the SplitMix64 finalizer.
+        let mut hash = self.0;
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^ (hash >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        categorical, from_hash, golden_hues, hsl, hue_cycle, oklch, rgb, CategoricalConstraints,
+        Color, HueSpace, Palette,
+    };
+
+    fn min_difference(palette: &Palette) -> f32 {
+        let mut min = f32::INFINITY;
+
+        for (i, lhs) in palette.iter().enumerate() {
+            for rhs in palette.iter().skip(i + 1) {
+                min = min.min(lhs.delta_e2000(rhs));
+            }
+        }
+
+        min
+    }
+
+    #[test]
+    fn hashes_deterministically() {
+        assert_eq!(from_hash("alice"), from_hash(String::from("alice")));
+        assert_eq!(from_hash(1234_u32), from_hash(1234_u32));
+        assert_eq!(from_hash("alice").to_hex(), "#cf8b4a");
+        assert_eq!(from_hash("bob").to_hex(), "#b868ae");
+
+        // Sizes hash the same on 32-bit and 64-bit platforms.
+        assert_eq!(from_hash(1234_usize), from_hash(1234_u64));
+    }
+
+    #[test]
+    fn keeps_colors_within_bounds() {
+        for i in 0..500 {
+            let color = from_hash(i).to_oklch();
+
+            assert!(color.l > 0.54 && color.l < 0.76, "{}", color);
+            assert!(color.c < 0.16, "{}", color);
+        }
+    }
+
+    #[test]
+    fn spreads_similar_inputs() {
+        let user1 = from_hash("user1");
+        let user2 = from_hash("user2");
+
+        assert_ne!(user1, user2);
+        assert!(user1.hue_distance(user2, HueSpace::OKLCH) > 10.0);
+    }
+
+    #[test]
+    fn steps_hues_by_golden_angle() {
+        let series: Vec<_> = golden_hues(hsl(0, 100, 40)).take(12).collect();
+        let seed = series[0].to_oklch();
+
+        for (i, color) in series.iter().enumerate().skip(1) {
+            let oklch = color.to_oklch();
+            let expected = (seed.h + i as f32 * 137.50776) % 360.0;
+            let distance = (oklch.h - expected).abs();
+
+            assert!(distance.min(360.0 - distance) < 1.5, "{} {}", i, oklch);
+            assert!((oklch.l - seed.l).abs() < 0.01, "{} {}", i, oklch);
+        }
+    }
+
+    #[test]
+    fn spreads_hues_evenly() {
+        let mut hues: Vec<f32> = golden_hues(rgb(23, 98, 119))
+            .take(20)
+            .map(|color| color.to_oklch().h)
+            .collect();
+        hues.sort_by(f32::total_cmp);
+
+        let gaps: Vec<f32> = hues.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert!(
+            gaps.iter().all(|&gap| gap > 5.0 && gap < 35.0),
+            "{:?}",
+            gaps
+        );
+    }
+
+    #[test]
+    fn never_ends() {
+        let mut series = golden_hues(rgb(23, 98, 119));
+
+        assert_eq!(series.size_hint(), (usize::MAX, None));
+        assert!(series.nth(10_000).is_some());
+    }
+
+    #[test]
+    fn cycles_hues_at_constant_lightness_and_chroma() {
+        let colors: Vec<_> = hue_cycle(oklch(0.65, 0.08, 200.0).to_rgb(), 25.0)
+            .take(40)
+            .collect();
+        let first = colors[0].to_oklch();
+
+        for (i, color) in colors.iter().enumerate() {
+            let oklch = color.to_oklch();
+            let hue = (200.0 + 25.0 * i as f32) % 360.0;
+
+            assert!((oklch.l - first.l).abs() < 0.01, "{}", oklch);
+            assert!((oklch.c - first.c).abs() < 0.01, "{}", oklch);
+            let distance = (oklch.h - hue + 540.0) % 360.0 - 180.0;
+            assert!(distance.abs() < 3.0, "{}", oklch);
+        }
+
+        // Stepping 360° around the circle comes back to the start.
+        assert_eq!(
+            colors[0],
+            hue_cycle(oklch(0.65, 0.08, 200.0).to_rgb(), 360.0)
+                .nth(7)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn lowers_chroma_for_every_hue() {
+        let seed = rgb(255, 0, 0);
+        let colors: Vec<_> = hue_cycle(seed, -10.0).take(36).collect();
+        let chroma = colors[0].to_oklch().c;
+
+        assert!(chroma < seed.to_oklch().c);
+        for color in &colors {
+            assert!((color.to_oklch().c - chroma).abs() < 0.01, "{}", color);
+        }
+        assert_eq!(
+            hue_cycle(rgb(128, 128, 128), 30.0).nth(5),
+            Some(rgb(128, 128, 128))
+        );
+    }
+
+    #[test]
+    fn picks_distinct_categories() {
+        let palette = categorical(14, CategoricalConstraints::default());
+
+        assert_eq!(palette.len(), 14);
+        assert!(
+            min_difference(&palette) > 25.0,
+            "{}",
+            min_difference(&palette)
+        );
+        assert!(
+            min_difference(&categorical(4, CategoricalConstraints::default()))
+                > min_difference(&palette)
+        );
+    }
+
+    #[test]
+    fn respects_constraints() {
+        let constraints = CategoricalConstraints {
+            min_lightness: 0.7,
+            max_lightness: 0.8,
+            min_chroma: 0.05,
+            max_chroma: 0.1,
+        };
+
+        for color in categorical(10, constraints).iter() {
+            let oklch = color.to_oklch();
+
+            // Rounding to 8-bit channels moves colors slightly.
+            assert!(oklch.l > 0.69 && oklch.l < 0.81, "{}", oklch);
+            assert!(oklch.c > 0.04 && oklch.c < 0.11, "{}", oklch);
+        }
+    }
+
+    #[test]
+    fn picks_deterministically() {
+        assert_eq!(
+            categorical(6, CategoricalConstraints::default()),
+            categorical(6, CategoricalConstraints::default())
+        );
+    }
+
+    #[test]
+    fn handles_tight_constraints() {
+        let constraints = CategoricalConstraints {
+            min_lightness: 0.6,
+            max_lightness: 0.6,
+            min_chroma: 0.0,
+            max_chroma: 0.0,
+        };
+
+        assert_eq!(categorical(5, constraints).len(), 1);
+        assert!(categorical(0, CategoricalConstraints::default()).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_constraints() {
+        categorical(
+            5,
+            CategoricalConstraints {
+                min_chroma: 0.2,
+                max_chroma: 0.1,
+                ..CategoricalConstraints::default()
+            },
+        );
+    }
+}
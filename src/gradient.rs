@@ -0,0 +1,1701 @@
+use super::{
+    contrast_color::css,
+    css::{parse_angle, parse_color, split_top_level},
+    ramp::fit_chroma,
+    space::{interpolate, Segment},
+    Angle, Color, ColorSpace, Easing, HueInterpolation, Ratio, RGB, RGBA,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that represents a color stop of a `Gradient`.
+pub struct GradientStop {
+    pub position: f32,
+    pub color: RGBA,
+
+    // the easing of the transition from this stop to the next one
+    pub easing: Easing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct to represent a gradient: colors placed at positions along a line,
+/// blending into each other in between, like a CSS gradient.
+///
+/// Stops are kept ordered by position. Before the first stop and after the
+/// last, the gradient keeps their colors, and two stops at the same position
+/// make a hard edge between them. Colors are interpolated in OKLab by
+/// default, with premultiplied alpha like CSS does.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, rgba, ColorSpace, Gradient};
+///
+/// let mut gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)])
+///     .space(ColorSpace::SRGB);
+/// gradient.add_stop(0.5, rgb(255, 255, 255));
+///
+/// assert_eq!(gradient.at(0.25), rgba(255, 128, 128, 1.0));
+/// assert_eq!(gradient.at(0.5), rgba(255, 255, 255, 1.0));
+/// ```
+pub struct Gradient {
+    stops: Vec<GradientStop>,
+    space: ColorSpace,
+    arc: HueInterpolation,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Gradient {
+            stops: Vec::new(),
+            space: ColorSpace::OKLAB,
+            arc: HueInterpolation::Shorter,
+        }
+    }
+}
+
+impl Gradient {
+    /// Constructs a gradient without any stops.
+    pub fn new() -> Self {
+        Gradient::default()
+    }
+
+    /// Constructs a gradient with `colors` spread evenly from `0.0` to `1.0`.
+    /// A single color is placed at `0.0`.
+    pub fn from_colors<T: Color + Copy>(colors: &[T]) -> Self {
+        let mut gradient = Gradient::new();
+
+        for (i, &color) in colors.iter().enumerate() {
+            let position = if colors.len() == 1 {
+                0.0
+            } else {
+                i as f32 / (colors.len() - 1) as f32
+            };
+
+            gradient.add_stop(position, color);
+        }
+
+        gradient
+    }
+
+    /// Parses the color stops of a CSS `linear-gradient()`,
+    /// `radial-gradient()` or `conic-gradient()`, along with their color
+    /// interpolation method. Returns `None` if the gradient is invalid.
+    ///
+    /// Stops are positioned like CSS does: missing positions are spread
+    /// evenly, and positions before an earlier one are moved up to it. The
+    /// direction, shape and center of the gradient are ignored, and lengths
+    /// other than percentages can't be read without the size of the box, so
+    /// they make the gradient invalid. Interpolation hints are approximated
+    /// by a stop halfway between their neighbours.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, ColorSpace, Gradient};
+    ///
+    /// let gradient =
+    ///     Gradient::from_css("linear-gradient(to right in srgb, red, #0000ff 80%)").unwrap();
+    ///
+    /// assert_eq!(gradient.color_space(), ColorSpace::SRGB);
+    /// assert_eq!(gradient.at(0.4), rgba(128, 0, 128, 1.0));
+    /// assert_eq!(gradient.at(0.9), rgba(0, 0, 255, 1.0));
+    /// ```
+    pub fn from_css(input: &str) -> Option<Self> {
+        let input = input.trim().to_ascii_lowercase();
+        let (name, arguments) = input.strip_suffix(')')?.split_once('(')?;
+        let conic = match name.trim() {
+            "linear-gradient" | "radial-gradient" => false,
+            "conic-gradient" => true,
+            _ => return None,
+        };
+
+        let mut arguments: Vec<&str> = split_top_level(arguments, ',')
+            .into_iter()
+            .map(str::trim)
+            .collect();
+        let mut gradient = Gradient::new();
+
+        // the first argument sets up the gradient, unless it is a color stop
+        let first = arguments[0].split_whitespace().next().unwrap_or("");
+        if parse_color(first).is_none() {
+            let prelude: Vec<&str> = arguments.remove(0).split_whitespace().collect();
+
+            if let Some(i) = prelude.iter().position(|&token| token == "in") {
+                gradient.space = ColorSpace::from_css_name(prelude.get(i + 1)?)?;
+
+                match (prelude.get(i + 2), prelude.get(i + 3)) {
+                    (Some(&arc), Some(&"hue")) if gradient.space.hue_channel().is_some() => {
+                        gradient.arc = HueInterpolation::from_css_name(arc)?;
+                    }
+                    (Some(&"hue"), _) | (_, Some(&"hue")) => return None,
+                    _ => {}
+                }
+            }
+        }
+
+        // the colors and positions of every stop, and the hints following them
+        let mut stops: Vec<(RGBA, Option<f32>)> = Vec::new();
+        let mut hints: Vec<Option<f32>> = Vec::new();
+        let position = |token: &str| {
+            match token.strip_suffix('%') {
+                Some(percentage) => percentage.parse::<f32>().ok().map(|value| value / 100.0),
+                None if token == "0" => Some(0.0),
+                None if conic => parse_angle(token).map(|degrees| degrees / 360.0),
+                None => None,
+            }
+            .filter(|position| position.is_finite())
+        };
+
+        for argument in arguments {
+            let tokens: Vec<&str> = split_top_level(argument, ' ')
+                .into_iter()
+                .filter(|token| !token.is_empty())
+                .collect();
+
+            if let [hint] = tokens.as_slice() {
+                if let Some(hint) = position(hint) {
+                    match hints.last_mut() {
+                        Some(last @ None) => *last = Some(hint),
+                        _ => return None,
+                    }
+                    continue;
+                }
+            }
+
+            let (color, positions) = tokens.split_first()?;
+            let color = parse_color(color)?;
+
+            match positions {
+                [] => stops.push((color, None)),
+                [at] => stops.push((color, Some(position(at)?))),
+                [start, end] => {
+                    stops.push((color, Some(position(start)?)));
+                    stops.push((color, Some(position(end)?)));
+                    hints.push(None);
+                }
+                _ => return None,
+            }
+            hints.push(None);
+        }
+
+        if stops.is_empty() || hints.pop()?.is_some() {
+            return None;
+        }
+
+        let last = stops.len() - 1;
+        stops[0].1.get_or_insert(0.0);
+        stops[last].1.get_or_insert(1.0);
+
+        let mut max = f32::NEG_INFINITY;
+        for (_, position) in stops.iter_mut() {
+            if let Some(position) = position {
+                *position = position.max(max);
+                max = *position;
+            }
+        }
+
+        let mut i = 1;
+        while i < last {
+            if stops[i].1.is_some() {
+                i += 1;
+                continue;
+            }
+
+            let end = (i..=last).find(|&j| stops[j].1.is_some())?;
+            let (start, stop) = (stops[i - 1].1?, stops[end].1?);
+
+            for (j, (_, position)) in stops[i..end].iter_mut().enumerate() {
+                let progress = (j + 1) as f32 / (end - i + 1) as f32;
+                *position = Some(start + (stop - start) * progress);
+            }
+            i = end;
+        }
+
+        for (i, &(color, position)) in stops.iter().enumerate() {
+            let position = position?;
+            gradient.stops.push(GradientStop {
+                position,
+                color,
+                easing: Easing::Linear,
+            });
+
+            if let Some(hint) = hints.get(i).copied().flatten() {
+                let (next_color, next) = (stops[i + 1].0, stops[i + 1].1?);
+                gradient.stops.push(GradientStop {
+                    position: hint.clamp(position, next),
+                    color: interpolate(color, next_color, 0.5, gradient.space, gradient.arc),
+                    easing: Easing::Linear,
+                });
+            }
+        }
+
+        Some(gradient)
+    }
+
+    /// Sets the color space the colors are interpolated in.
+    pub fn space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Returns the color space the colors are interpolated in.
+    pub fn color_space(&self) -> ColorSpace {
+        self.space
+    }
+
+    /// Sets the arc hues take around the color wheel between stops, when
+    /// interpolated in a cylindrical color space.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{hsl, Color, ColorSpace, Gradient, HueInterpolation};
+    ///
+    /// let gradient = Gradient::from_colors(&[hsl(0, 100, 50), hsl(120, 100, 50)])
+    ///     .space(ColorSpace::HSL)
+    ///     .hue_interpolation(HueInterpolation::Longer);
+    ///
+    /// assert_eq!(gradient.at(0.5).to_hsl(), hsl(240, 100, 50));
+    /// ```
+    pub fn hue_interpolation(mut self, arc: HueInterpolation) -> Self {
+        self.arc = arc;
+        self
+    }
+
+    /// Returns the arc hues take around the color wheel between stops.
+    pub fn hue_arc(&self) -> HueInterpolation {
+        self.arc
+    }
+
+    /// Returns the number of stops in the gradient.
+    pub fn len(&self) -> usize {
+        self.stops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stops.is_empty()
+    }
+
+    /// Returns the stops of the gradient, ordered by position.
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// Adds a stop, after any other stop at the same position, and returns
+    /// its index. Panics if `position` is not finite.
+    pub fn add_stop<T: Color>(&mut self, position: f32, color: T) -> usize {
+        assert!(position.is_finite(), "Invalid stop position");
+
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+
+        self.stops.insert(
+            index,
+            GradientStop {
+                position,
+                color: color.to_rgba(),
+                easing: Easing::Linear,
+            },
+        );
+
+        index
+    }
+
+    /// Sets the easing of the transition from the stop at `index` to the next
+    /// one. Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, ColorSpace, Easing, Gradient};
+    ///
+    /// let mut gradient = Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)])
+    ///     .space(ColorSpace::SRGB);
+    /// gradient.set_easing(0, Easing::Steps(2));
+    ///
+    /// assert_eq!(gradient.at(0.4), rgba(0, 0, 0, 1.0));
+    /// assert_eq!(gradient.at(0.6), rgba(128, 128, 128, 1.0));
+    /// ```
+    pub fn set_easing(&mut self, index: usize, easing: Easing) {
+        self.stops[index].easing = easing;
+    }
+
+    /// Sets the easing of every transition between stops.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        for stop in &mut self.stops {
+            stop.easing = easing;
+        }
+
+        self
+    }
+
+    /// Removes and returns the stop at `index`, shifting the following stops.
+    /// Panics if `index` is out of bounds.
+    pub fn remove_stop(&mut self, index: usize) -> GradientStop {
+        self.stops.remove(index)
+    }
+
+    /// Reverses the gradient, mirroring the stop positions so that the
+    /// gradient spans the same range.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, Gradient};
+    ///
+    /// let mut gradient = Gradient::new();
+    /// gradient.add_stop(0.0, rgb(255, 0, 0));
+    /// gradient.add_stop(0.2, rgb(0, 0, 255));
+    /// gradient.add_stop(1.0, rgb(0, 255, 0));
+    /// gradient.reverse();
+    ///
+    /// assert_eq!(gradient.stops()[1].position, 0.8);
+    /// assert_eq!(gradient.at(0.0), rgba(0, 255, 0, 1.0));
+    /// ```
+    pub fn reverse(&mut self) {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first.position, last.position),
+            _ => return,
+        };
+
+        // Easings belong to the transition after their stop, so they move
+        // with it and are mirrored, like the positions.
+        let easings: Vec<Easing> = self.stops.iter().map(|stop| stop.easing).collect();
+        self.stops.reverse();
+
+        for (i, stop) in self.stops.iter_mut().enumerate() {
+            stop.position = first + last - stop.position;
+
+            stop.easing = match easings.len().checked_sub(i + 2) {
+                Some(index) => mirror(easings[index]),
+                None => Easing::Linear,
+            };
+        }
+    }
+
+    /// Moves the stops so that the OKLab lightness of the gradient changes
+    /// evenly from its first stop to its last, as sequential colormaps should.
+    /// Stops are placed in proportion to their lightness, and stops whose
+    /// lightness goes against the rest are spread between their neighbours
+    /// and made lighter or darker to fit, keeping their hue and alpha. The
+    /// ends stay where they are.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Color, ColorSpace, Gradient};
+    ///
+    /// let colors = [rgb(0, 0, 0), rgb(255, 0, 0), rgb(255, 255, 0), rgb(255, 255, 255)];
+    /// let mut gradient = Gradient::from_colors(&colors);
+    /// gradient.correct_lightness();
+    ///
+    /// let l = |t: f32| gradient.at(t).to_oklab().l;
+    /// assert!((l(0.5) - 0.5).abs() < 0.01);
+    /// assert!((l(0.25) - 0.25).abs() < 0.01);
+    /// ```
+    pub fn correct_lightness(&mut self) {
+        if self.stops.len() < 3 {
+            return;
+        }
+
+        let last = self.stops.len() - 1;
+        let lightness: Vec<f32> = self
+            .stops
+            .iter()
+            .map(|stop| stop.color.to_oklab().l)
+            .collect();
+        let (start, end) = (lightness[0], lightness[last]);
+
+        if (end - start).abs() < 1e-4 {
+            return;
+        }
+
+        // How far along from the first lightness to the last each stop is.
+        let progress: Vec<f32> = lightness
+            .iter()
+            .map(|l| (l - start) / (end - start))
+            .collect();
+
+        // Keep the stops going steadily toward the last lightness.
+        let mut kept = vec![0];
+        for (i, &current) in progress.iter().enumerate().take(last).skip(1) {
+            if current > progress[*kept.last().unwrap()] && current < 1.0 {
+                kept.push(i);
+            }
+        }
+        kept.push(last);
+
+        let (first, span) = (
+            self.stops[0].position,
+            self.stops[last].position - self.stops[0].position,
+        );
+
+        for pair in kept.windows(2) {
+            let (lhs, rhs) = (pair[0], pair[1]);
+            let lhs_progress = progress[lhs];
+            let rhs_progress = if rhs == last { 1.0 } else { progress[rhs] };
+
+            for i in lhs + 1..rhs {
+                let share = (i - lhs) as f32 / (rhs - lhs) as f32;
+                let target = lhs_progress + (rhs_progress - lhs_progress) * share;
+                let lch = self.stops[i].color.to_oklch();
+                let RGB { r, g, b } = fit_chroma(start + (end - start) * target, lch.c, lch.h);
+
+                self.stops[i].position = first + span * target;
+                self.stops[i].color = RGBA {
+                    r,
+                    g,
+                    b,
+                    a: self.stops[i].color.a,
+                };
+            }
+
+            if rhs != last {
+                self.stops[rhs].position = first + span * rhs_progress;
+            }
+        }
+    }
+
+    /// Resamples the gradient through OKLab, adding `n` stops spread evenly
+    /// from its first stop to its last, so it keeps looking even when
+    /// interpolated in its own color space. This gets rid of the grey dead
+    /// zones and banding of sRGB between colorful hues, like blue to yellow,
+    /// even for tools that only interpolate in sRGB.
+    ///
+    /// The existing stops and hard edges are kept, and easings are baked into
+    /// the new stops, turning every transition linear.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Color, ColorSpace, Gradient};
+    ///
+    /// let mut gradient = Gradient::from_colors(&[rgb(0, 0, 255), rgb(255, 255, 0)])
+    ///     .space(ColorSpace::SRGB);
+    ///
+    /// // Mixing blue and yellow in sRGB goes through grey.
+    /// assert!(gradient.at(0.5).to_oklch().c < 0.01);
+    ///
+    /// gradient.smooth(9);
+    /// assert!(gradient.at(0.5).to_oklch().c > 0.01);
+    /// assert_eq!(gradient.len(), 9);
+    /// ```
+    pub fn smooth(&mut self, n: usize) {
+        if self.stops.is_empty() {
+            return;
+        }
+
+        let oklab = Gradient {
+            stops: self.stops.clone(),
+            space: ColorSpace::OKLAB,
+            arc: self.arc,
+        };
+        let first = self.stops[0].position;
+        let last = self.stops[self.stops.len() - 1].position;
+
+        for stop in self.stops.iter_mut() {
+            stop.easing = Easing::Linear;
+        }
+
+        for i in 0..n {
+            let position = if n == 1 {
+                (first + last) / 2.0
+            } else {
+                first + (last - first) * i as f32 / (n - 1) as f32
+            };
+
+            if oklab.stops.iter().all(|stop| stop.position != position) {
+                self.add_stop(position, oklab.at(position));
+            }
+        }
+    }
+
+    /// Samples the gradient at `t`. Positions before the first stop get its
+    /// color and positions after the last stop get its color, and `NaN`
+    /// samples the first stop. Panics if the gradient has no stops.
+    pub fn at(&self, t: f32) -> RGBA {
+        assert!(!self.stops.is_empty(), "Invalid empty gradient");
+
+        match self.segment(t) {
+            Ok((index, weight)) => {
+                let (lhs, rhs) = (self.stops[index], self.stops[index + 1]);
+                interpolate(lhs.color, rhs.color, weight, self.space, self.arc)
+            }
+            Err(color) => color,
+        }
+    }
+
+    /// Serializes the gradient as a CSS `linear-gradient()` going towards
+    /// `angle`, with its color space and hue arc as the interpolation method
+    /// and positions as percentages.
+    ///
+    /// CSS only eases stops linearly, so other easings are approximated with
+    /// extra stops sampled along their transition. Panics if the gradient
+    /// has no stops.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, rgb, ColorSpace, Gradient, HueInterpolation};
+    ///
+    /// let gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)]);
+    ///
+    /// assert_eq!(
+    ///     gradient.to_css_linear(deg(90)),
+    ///     "linear-gradient(90deg in oklab, rgb(255, 0, 0) 0%, rgb(0, 0, 255) 100%)"
+    /// );
+    ///
+    /// let rainbow = Gradient::from_colors(&[rgb(255, 0, 0), rgb(255, 0, 0)])
+    ///     .space(ColorSpace::OKLCH)
+    ///     .hue_interpolation(HueInterpolation::Longer);
+    ///
+    /// assert_eq!(
+    ///     rainbow.to_css_linear(deg(180)),
+    ///     "linear-gradient(180deg in oklch longer hue, rgb(255, 0, 0) 0%, rgb(255, 0, 0) 100%)"
+    /// );
+    /// ```
+    pub fn to_css_linear(&self, angle: Angle) -> String {
+        format!(
+            "linear-gradient({} {}, {})",
+            angle,
+            self.space.css_method(self.arc),
+            self.css_stops()
+        )
+    }
+
+    /// Serializes the gradient as a CSS `conic-gradient()` starting at the
+    /// `from` angle and going around `center`, given as fractions of the
+    /// width and height of the box. Stops are written like
+    /// `to_css_linear()`, positions `0.0` and `1.0` meaning a full turn.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, rgb, Gradient};
+    ///
+    /// let gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)]);
+    ///
+    /// assert_eq!(
+    ///     gradient.to_css_conic(deg(90), (0.5, 0.25)),
+    ///     "conic-gradient(from 90deg at 50% 25% in oklab, rgb(255, 0, 0) 0%, rgb(0, 0, 255) 100%)"
+    /// );
+    /// ```
+    pub fn to_css_conic(&self, from: Angle, center: (f32, f32)) -> String {
+        format!(
+            "conic-gradient(from {} at {} {} {}, {})",
+            from,
+            percentage(center.0),
+            percentage(center.1),
+            self.space.css_method(self.arc),
+            self.css_stops()
+        )
+    }
+
+    /// Serializes the gradient as a CSS `radial-gradient()` of the given
+    /// shape and extent, going out of `center`, given as fractions of the
+    /// width and height of the box. Stops are written like
+    /// `to_css_linear()`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Gradient, RadialExtent, RadialShape};
+    ///
+    /// let spotlight = Gradient::from_colors(&[rgba(255, 255, 255, 0.5), rgba(0, 0, 0, 0.0)]);
+    ///
+    /// assert_eq!(
+    ///     spotlight.to_css_radial(RadialShape::Circle, RadialExtent::ClosestSide, (0.3, 0.0)),
+    ///     "radial-gradient(circle closest-side at 30% 0% in oklab, \
+    ///      rgba(255, 255, 255, 0.50) 0%, rgba(0, 0, 0, 0.00) 100%)"
+    /// );
+    /// ```
+    pub fn to_css_radial(
+        &self,
+        shape: RadialShape,
+        extent: RadialExtent,
+        center: (f32, f32),
+    ) -> String {
+        format!(
+            "radial-gradient({} {} at {} {} {}, {})",
+            shape.css_name(),
+            extent.css_name(),
+            percentage(center.0),
+            percentage(center.1),
+            self.space.css_method(self.arc),
+            self.css_stops()
+        )
+    }
+
+    /// Serializes the gradient as an SVG `<linearGradient>` element with the
+    /// given `id`, going from left to right like SVG does by default. It can
+    /// be put in the `<defs>` of a document and used as `fill="url(#id)"`.
+    ///
+    /// SVG only interpolates in sRGB, without premultiplying alpha, so other
+    /// color spaces, transitions between different alphas and easings are
+    /// approximated with extra stops. Positions outside of `0.0` to `1.0`
+    /// are cut off, as SVG can't place stops there. Panics if the gradient
+    /// has no stops.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::from_colors(&[rgba(255, 0, 0, 0.5), rgba(0, 0, 255, 0.5)])
+    ///     .space(ColorSpace::SRGB);
+    ///
+    /// assert_eq!(
+    ///     gradient.to_svg_linear_gradient("fade"),
+    ///     "<linearGradient id=\"fade\">\n\
+    ///      \x20 <stop offset=\"0%\" stop-color=\"#ff0000\" stop-opacity=\"0.5\"/>\n\
+    ///      \x20 <stop offset=\"100%\" stop-color=\"#0000ff\" stop-opacity=\"0.5\"/>\n\
+    ///      </linearGradient>"
+    /// );
+    /// ```
+    pub fn to_svg_linear_gradient(&self, id: &str) -> String {
+        let mut samples =
+            self.samples(|lhs, rhs| self.space != ColorSpace::SRGB || lhs.color.a != rhs.color.a);
+
+        if samples.iter().any(|&(position, _)| position < 0.0) {
+            samples.insert(0, (0.0, self.at(0.0)));
+        }
+
+        if samples.iter().any(|&(position, _)| position > 1.0) {
+            samples.push((1.0, self.at(1.0)));
+        }
+
+        let mut svg = format!("<linearGradient id=\"{}\">\n", escape_xml(id));
+
+        for (position, color) in samples {
+            if !(0.0..=1.0).contains(&position) {
+                continue;
+            }
+
+            svg.push_str(&format!(
+                "  <stop offset=\"{}\" stop-color=\"{}\"",
+                percentage(position),
+                color.to_rgb().to_hex()
+            ));
+
+            if color.a.as_u8() != 255 {
+                let opacity = (color.a.as_f32() * 100.0).round() / 100.0;
+                svg.push_str(&format!(" stop-opacity=\"{}\"", opacity));
+            }
+
+            svg.push_str("/>\n");
+        }
+
+        svg.push_str("</linearGradient>");
+        svg
+    }
+
+    // Serializes the stops as a CSS color stop list.
+    fn css_stops(&self) -> String {
+        let stops: Vec<String> = self
+            .samples(|_, _| false)
+            .into_iter()
+            .map(|(position, color)| format!("{} {}", css(color), percentage(position)))
+            .collect();
+
+        // CSS needs at least two stops
+        if let [only] = stops.as_slice() {
+            return format!("{}, {}", only, only);
+        }
+
+        stops.join(", ")
+    }
+
+    // Lists the positions and colors of the stops, adding stops sampled along
+    // the transitions that other formats can't represent: eased ones, and
+    // those for which `approximate` returns true.
+    fn samples(
+        &self,
+        approximate: impl Fn(&GradientStop, &GradientStop) -> bool,
+    ) -> Vec<(f32, RGBA)> {
+        assert!(!self.stops.is_empty(), "Invalid empty gradient");
+
+        let mut samples = Vec::new();
+
+        for (i, lhs) in self.stops.iter().enumerate() {
+            samples.push((lhs.position, lhs.color));
+
+            let rhs = match self.stops.get(i + 1) {
+                Some(rhs)
+                    if rhs.position > lhs.position
+                        && (lhs.easing != Easing::Linear || approximate(lhs, rhs)) =>
+                {
+                    rhs
+                }
+                _ => continue,
+            };
+
+            for step in 1..=EASED_STOPS {
+                let position = lhs.position
+                    + (rhs.position - lhs.position) * step as f32 / (EASED_STOPS + 1) as f32;
+                samples.push((position, self.at(position)));
+            }
+        }
+
+        samples
+    }
+
+    /// Fills `pixels` with the gradient, the first pixel sampling it at
+    /// `options.start` and the last one at `options.end`, like a texture or a
+    /// lookup table. A single pixel samples the middle.
+    ///
+    /// This gives the same colors as calling `at()` for every pixel, but
+    /// converts the stops once per pair rather than once per pixel. With
+    /// dithering, the rounding to 8-bit channels follows an 8x8 Bayer
+    /// pattern instead, which breaks up the banding of smooth gradients.
+    /// Panics if the gradient has no stops.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Gradient, RasterizeOptions};
+    ///
+    /// let gradient = Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)]);
+    /// let mut pixels = [rgb(0, 0, 0); 256];
+    ///
+    /// gradient.rasterize(&mut pixels, RasterizeOptions::default());
+    ///
+    /// assert_eq!(pixels[0], rgb(0, 0, 0));
+    /// assert_eq!(pixels[255], rgb(255, 255, 255));
+    /// ```
+    pub fn rasterize(&self, pixels: &mut [RGB], options: RasterizeOptions) {
+        self.fill(pixels.len(), options, |i, [r, g, b, _]| {
+            pixels[i] = RGB { r, g, b };
+        });
+    }
+
+    /// Fills `pixels` with the gradient like `rasterize()`, keeping alpha.
+    pub fn rasterize_rgba(&self, pixels: &mut [RGBA], options: RasterizeOptions) {
+        self.fill(pixels.len(), options, |i, [r, g, b, a]| {
+            pixels[i] = RGBA { r, g, b, a };
+        });
+    }
+
+    fn fill(
+        &self,
+        len: usize,
+        options: RasterizeOptions,
+        mut write: impl FnMut(usize, [Ratio; 4]),
+    ) {
+        assert!(!self.stops.is_empty(), "Invalid empty gradient");
+
+        let RasterizeOptions {
+            start,
+            end,
+            dither,
+            row,
+        } = options;
+
+        // segments are only converted once a pixel falls in them
+        let mut segments: Vec<Option<Segment>> = vec![None; self.stops.len() - 1];
+
+        for i in 0..len {
+            let t = if len == 1 {
+                (start + end) / 2.0
+            } else {
+                start + (end - start) * i as f32 / (len - 1) as f32
+            };
+
+            let channels = match self.segment(t) {
+                Err(color) => [color.r, color.g, color.b, color.a].map(Ratio::as_f32),
+                Ok((index, weight)) => segments[index]
+                    .get_or_insert_with(|| {
+                        let (lhs, rhs) = (self.stops[index], self.stops[index + 1]);
+                        Segment::new(lhs.color, rhs.color, self.space, self.arc)
+                    })
+                    .srgb_at(weight),
+            };
+
+            let threshold = if dither {
+                (BAYER[row % 8][i % 8] as f32 + 0.5) / 64.0 - 0.5
+            } else {
+                0.0
+            };
+
+            write(
+                i,
+                channels.map(|value| {
+                    Ratio::from_u8((value * 255.0 + threshold).round().clamp(0.0, 255.0) as u8)
+                }),
+            );
+        }
+    }
+
+    // Finds the stops around `t`, returning the index of the first one and
+    // the eased progress between them, or the color of the closest stop when
+    // `t` falls outside of them.
+    fn segment(&self, t: f32) -> Result<(usize, f32), RGBA> {
+        if t.is_nan() {
+            return Err(self.stops[0].color);
+        }
+
+        let index = self.stops.partition_point(|stop| stop.position <= t);
+
+        if index == 0 {
+            return Err(self.stops[0].color);
+        }
+
+        if index == self.stops.len() {
+            return Err(self.stops[index - 1].color);
+        }
+
+        let (lhs, rhs) = (self.stops[index - 1], self.stops[index]);
+        let weight = lhs
+            .easing
+            .apply((t - lhs.position) / (rhs.position - lhs.position));
+
+        Ok((index - 1, weight))
+    }
+}
+
+// Formats a fraction as a CSS percentage, with up to two decimals.
+fn percentage(fraction: f32) -> String {
+    format!("{}%", (fraction * 10000.0).round() / 100.0)
+}
+
+// Escapes the characters with a meaning in XML attributes.
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// The number of stops approximating a transition another format can't
+// represent.
+const EASED_STOPS: usize = 7;
+
+// The thresholds of ordered dithering, spreading the rounding errors of
+// neighbouring pixels as evenly as possible.
+const BAYER: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The shapes of a CSS `radial-gradient()`.
+pub enum RadialShape {
+    Circle,
+    Ellipse,
+}
+
+impl RadialShape {
+    /// Returns the keyword of the shape in CSS.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            RadialShape::Circle => "circle",
+            RadialShape::Ellipse => "ellipse",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The sizes of a CSS `radial-gradient()`, as how far its ending shape
+/// reaches towards the sides or corners of the box.
+pub enum RadialExtent {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+}
+
+impl RadialExtent {
+    /// Returns the keyword of the extent in CSS.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            RadialExtent::ClosestSide => "closest-side",
+            RadialExtent::ClosestCorner => "closest-corner",
+            RadialExtent::FarthestSide => "farthest-side",
+            RadialExtent::FarthestCorner => "farthest-corner",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to configure `Gradient::rasterize()`.
+pub struct RasterizeOptions {
+    // the gradient positions sampled by the first and last pixels
+    pub start: f32,
+    pub end: f32,
+
+    // whether to round channels with ordered dithering
+    pub dither: bool,
+
+    // the row of the image being filled, offsetting the dithering pattern
+    pub row: usize,
+}
+
+impl Default for RasterizeOptions {
+    fn default() -> Self {
+        RasterizeOptions {
+            start: 0.0,
+            end: 1.0,
+            dither: false,
+            row: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over evenly spaced colors from one color to another, created
+/// by `Color::steps_to()`.
+pub struct ColorSteps {
+    from: RGBA,
+    to: RGBA,
+    space: ColorSpace,
+    front: usize,
+    back: usize,
+    n: usize,
+}
+
+impl ColorSteps {
+    pub(crate) fn new<A: Color, B: Color>(from: A, to: B, n: usize, space: ColorSpace) -> Self {
+        ColorSteps {
+            from: from.to_rgba(),
+            to: to.to_rgba(),
+            space,
+            front: 0,
+            back: n,
+            n,
+        }
+    }
+
+    fn step(&self, i: usize) -> RGBA {
+        let t = if self.n == 1 {
+            0.0
+        } else {
+            i as f32 / (self.n - 1) as f32
+        };
+
+        interpolate(self.from, self.to, t, self.space, HueInterpolation::Shorter)
+    }
+}
+
+impl Iterator for ColorSteps {
+    type Item = RGBA;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.step(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ColorSteps {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.step(self.back))
+    }
+}
+
+impl ExactSizeIterator for ColorSteps {}
+
+/// Builds a Bézier curve through `colors` in the given color space, like
+/// chroma.js does: the first and last colors are its ends, and the colors in
+/// between are control points pulling the curve toward them. This gives far
+/// smoother scales than going straight from one color to the next. Panics if
+/// there are no colors, or if the space is cylindrical.
+///
+/// # Example
+/// ```
+/// use farver::{bezier, rgb, Color, ColorSpace};
+///
+/// let curve = bezier(&[rgb(255, 255, 224), rgb(255, 0, 0), rgb(0, 0, 0)], ColorSpace::LAB);
+///
+/// assert_eq!(curve.at(0.0), rgb(255, 255, 224).to_rgba());
+/// assert_eq!(curve.at(1.0), rgb(0, 0, 0).to_rgba());
+/// assert_eq!(curve.to_gradient(5).len(), 5);
+/// ```
+pub fn bezier<T: Color + Copy>(colors: &[T], space: ColorSpace) -> Bezier {
+    assert!(!colors.is_empty(), "Invalid bezier colors");
+    assert!(space.hue_channel().is_none(), "Invalid bezier space");
+
+    Bezier {
+        points: colors
+            .iter()
+            .map(|&color| {
+                let color = color.to_rgba();
+                let [x, y, z] = space.decompose(color);
+                [x, y, z, color.a.as_f32()]
+            })
+            .collect(),
+        space,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct to represent a Bézier curve through colors, as built by
+/// `bezier()`.
+pub struct Bezier {
+    // the control points, with their alpha values as a fourth channel
+    points: Vec<[f32; 4]>,
+    space: ColorSpace,
+}
+
+impl Bezier {
+    /// Samples the curve at `t`, from `0.0` for its first color to `1.0` for
+    /// its last. Values out of that range are clamped, and `NaN` samples the
+    /// start.
+    pub fn at(&self, t: f32) -> RGBA {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let mut points = self.points.clone();
+
+        // De Casteljau's algorithm, which stays stable for any degree.
+        for n in (1..points.len()).rev() {
+            for i in 0..n {
+                let next = points[i + 1];
+
+                for (channel, next) in points[i].iter_mut().zip(next) {
+                    *channel += (next - *channel) * t;
+                }
+            }
+        }
+
+        let [x, y, z, a] = points[0];
+        let RGB { r, g, b } = self.space.compose([x, y, z]);
+
+        RGBA {
+            r,
+            g,
+            b,
+            a: Ratio::from_f32(a.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Samples `n` evenly spaced colors along the curve into a gradient, so
+    /// it can be used anywhere gradients are.
+    pub fn to_gradient(&self, n: usize) -> Gradient {
+        let colors: Vec<RGBA> = (0..n)
+            .map(|i| {
+                let t = if n == 1 {
+                    0.0
+                } else {
+                    i as f32 / (n - 1) as f32
+                };
+                self.at(t)
+            })
+            .collect();
+
+        Gradient::from_colors(&colors).space(self.space)
+    }
+}
+
+// Mirrors an easing for a transition going the other way.
+fn mirror(easing: Easing) -> Easing {
+    match easing {
+        Easing::Linear => Easing::Linear,
+        Easing::Ease => Easing::CubicBezier(0.75, 0.0, 0.75, 0.9),
+        Easing::EaseIn => Easing::EaseOut,
+        Easing::EaseOut => Easing::EaseIn,
+        Easing::EaseInOut => Easing::EaseInOut,
+        Easing::CubicBezier(x1, y1, x2, y2) => {
+            Easing::CubicBezier(1.0 - x2, 1.0 - y2, 1.0 - x1, 1.0 - y1)
+        }
+        // Steps cannot be mirrored exactly, as the jumps would come first.
+        Easing::Steps(steps) => Easing::Steps(steps),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bezier, deg, hsl, rgb, rgba, Color, ColorSpace, Easing, Gradient, GradientStop,
+        HueInterpolation, RadialExtent, RadialShape, RasterizeOptions,
+    };
+
+    fn gradient() -> Gradient {
+        Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)]).space(ColorSpace::SRGB)
+    }
+
+    #[test]
+    fn spreads_colors_evenly() {
+        let gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255)]);
+        let positions: Vec<f32> = gradient.stops().iter().map(|stop| stop.position).collect();
+
+        assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+        assert_eq!(
+            Gradient::from_colors(&[rgb(1, 2, 3)]).stops()[0].position,
+            0.0
+        );
+        assert!(Gradient::from_colors::<crate::RGB>(&[]).is_empty());
+    }
+
+    #[test]
+    fn can_sample() {
+        let gradient = gradient();
+
+        assert_eq!(gradient.at(0.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(gradient.at(0.5), rgba(128, 128, 128, 1.0));
+        assert_eq!(gradient.at(1.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(gradient.at(-1.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(gradient.at(2.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(gradient.at(f32::NAN), rgba(0, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn interpolates_alpha() {
+        let gradient = Gradient::from_colors(&[rgba(255, 0, 0, 0.0), rgba(255, 0, 0, 1.0)])
+            .space(ColorSpace::SRGB);
+
+        assert_eq!(gradient.at(0.5), rgba(255, 0, 0, 0.5));
+    }
+
+    #[test]
+    fn can_add_and_remove_stops() {
+        let mut gradient = gradient();
+
+        assert_eq!(gradient.add_stop(0.5, rgb(255, 0, 0)), 1);
+        assert_eq!(gradient.len(), 3);
+        assert_eq!(gradient.at(0.25), rgba(128, 0, 0, 1.0));
+
+        assert_eq!(
+            gradient.remove_stop(1),
+            GradientStop {
+                position: 0.5,
+                color: rgba(255, 0, 0, 1.0),
+                easing: Easing::Linear,
+            }
+        );
+        assert_eq!(gradient, self::gradient());
+    }
+
+    #[test]
+    fn makes_hard_edges() {
+        let mut gradient = gradient();
+        gradient.add_stop(0.5, rgb(255, 0, 0));
+        gradient.add_stop(0.5, rgb(0, 0, 255));
+
+        assert_eq!(gradient.stops()[2].color, rgba(0, 0, 255, 1.0));
+        assert_eq!(gradient.at(0.4999), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.at(0.5), rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn can_reverse() {
+        let mut gradient = gradient();
+        gradient.add_stop(0.25, rgb(255, 0, 0));
+        gradient.reverse();
+
+        assert_eq!(gradient.at(0.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(gradient.at(0.75), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.at(1.0), rgba(0, 0, 0, 1.0));
+
+        let mut empty = Gradient::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn eases_transitions() {
+        let mut gradient = gradient();
+        gradient.add_stop(0.5, rgb(255, 0, 0));
+        gradient.set_easing(1, Easing::EaseIn);
+
+        assert_eq!(gradient.at(0.25), rgba(128, 0, 0, 1.0));
+        assert_eq!(
+            gradient.at(0.75),
+            crate::rgb(255, 0, 0).lerp(
+                rgb(255, 255, 255),
+                Easing::EaseIn.apply(0.5),
+                ColorSpace::SRGB
+            )
+        );
+
+        let eased = self::gradient().easing(Easing::Ease);
+        assert!(eased.stops().iter().all(|stop| stop.easing == Easing::Ease));
+    }
+
+    #[test]
+    fn mirrors_easings_when_reversing() {
+        let mut gradient = gradient();
+        gradient.add_stop(0.5, rgb(255, 0, 0));
+        gradient.set_easing(0, Easing::EaseIn);
+
+        let before = gradient.at(0.2);
+        gradient.reverse();
+
+        assert_eq!(gradient.stops()[1].easing, Easing::EaseOut);
+        assert_eq!(gradient.stops()[0].easing, Easing::Linear);
+        assert_eq!(gradient.stops()[2].easing, Easing::Linear);
+        assert_eq!(gradient.at(0.8), before);
+    }
+
+    #[test]
+    fn corrects_lightness() {
+        let colors = [
+            rgb(0, 0, 0),
+            rgb(0, 0, 255),
+            rgb(0, 255, 255),
+            rgb(255, 255, 255),
+        ];
+        let mut gradient = Gradient::from_colors(&colors);
+        gradient.correct_lightness();
+
+        let lightness: Vec<f32> = (0..=10)
+            .map(|i| gradient.at(i as f32 / 10.0).to_oklab().l)
+            .collect();
+
+        for (i, l) in lightness.iter().enumerate() {
+            assert!((l - i as f32 / 10.0).abs() < 0.02, "{:?}", lightness);
+        }
+
+        assert_eq!(gradient.stops()[0].position, 0.0);
+        assert_eq!(gradient.stops()[3].position, 1.0);
+        assert_eq!(gradient.stops()[1].color, rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn fits_stops_going_against_lightness() {
+        let colors = [
+            rgba(0, 0, 0, 1.0),
+            rgba(255, 255, 255, 0.5),
+            rgba(128, 128, 128, 1.0),
+            rgba(255, 255, 255, 1.0),
+        ];
+        let mut gradient = Gradient::from_colors(&colors);
+        gradient.correct_lightness();
+
+        let lightness: Vec<f32> = (0..=20)
+            .map(|i| gradient.at(i as f32 / 20.0).to_oklab().l)
+            .collect();
+
+        // Rounding to 8-bit channels flattens the darkest steps.
+        assert!(
+            lightness.windows(2).all(|pair| pair[0] <= pair[1]),
+            "{:?}",
+            lightness
+        );
+        assert!((lightness[10] - 0.5).abs() < 0.05, "{:?}", lightness);
+        assert_eq!(gradient.stops()[1].color.a, rgba(255, 255, 255, 0.5).a);
+        assert!(gradient.stops()[1].position < gradient.stops()[2].position);
+    }
+
+    #[test]
+    fn rasterizes_like_sampling() {
+        let gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 128, 0), rgb(0, 0, 255)])
+            .space(ColorSpace::OKLCH);
+        let mut gradient = gradient.easing(Easing::EaseIn);
+        gradient.add_stop(0.75, rgba(255, 255, 0, 0.5));
+
+        let options = RasterizeOptions {
+            start: -0.1,
+            end: 1.1,
+            ..RasterizeOptions::default()
+        };
+        let mut pixels = [rgba(0, 0, 0, 0.0); 37];
+        gradient.rasterize_rgba(&mut pixels, options);
+
+        for (i, &pixel) in pixels.iter().enumerate() {
+            assert_eq!(pixel, gradient.at(-0.1 + 1.2 * i as f32 / 36.0));
+        }
+
+        let mut pixels = [rgb(0, 0, 0); 1];
+        gradient.rasterize(&mut pixels, RasterizeOptions::default());
+
+        assert_eq!(pixels[0], gradient.at(0.5).to_rgb());
+    }
+
+    #[test]
+    fn dithers_between_rounded_values() {
+        let gradient = Gradient::from_colors(&[rgb(0, 0, 0), rgb(4, 4, 4)]).space(ColorSpace::SRGB);
+        let options = RasterizeOptions {
+            dither: true,
+            ..RasterizeOptions::default()
+        };
+        let mut pixels = [rgb(0, 0, 0); 64];
+        gradient.rasterize(&mut pixels, options);
+
+        // Stops fall on 8-bit values, so dithering leaves them untouched.
+        assert_eq!(pixels[0], rgb(0, 0, 0));
+        assert_eq!(pixels[63], rgb(4, 4, 4));
+
+        // In between, neighbouring pixels get both values around the exact one.
+        let mut plain = [rgb(0, 0, 0); 64];
+        gradient.rasterize(&mut plain, RasterizeOptions::default());
+
+        assert_ne!(pixels, plain);
+        for (pixel, plain) in pixels.iter().zip(plain) {
+            assert!((pixel.r.as_u8() as i32 - plain.r.as_u8() as i32).abs() <= 1);
+        }
+
+        // Over a whole tile of rows, the dithered values average to the exact ones.
+        let mut sum = 0.0;
+
+        for row in 0..8 {
+            let options = RasterizeOptions { row, ..options };
+            gradient.rasterize(&mut pixels, options);
+            sum += pixels
+                .iter()
+                .map(|pixel| pixel.r.as_u8() as f32)
+                .sum::<f32>();
+        }
+
+        assert!((sum / 512.0 - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_rasterizing_empty_gradient() {
+        Gradient::new().rasterize(&mut [rgb(0, 0, 0)], RasterizeOptions::default());
+    }
+
+    #[test]
+    fn can_serialize_linear_css() {
+        let mut gradient =
+            Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)]).space(ColorSpace::LinearRGB);
+        gradient.add_stop(1.0 / 3.0, rgba(0, 128, 0, 0.5));
+
+        assert_eq!(
+            gradient.to_css_linear(deg(45)),
+            "linear-gradient(45deg in srgb-linear, rgb(255, 0, 0) 0%, \
+             rgba(0, 128, 0, 0.50) 33.33%, rgb(0, 0, 255) 100%)"
+        );
+        assert_eq!(
+            Gradient::from_colors(&[rgb(0, 0, 0)])
+                .space(ColorSpace::HSL)
+                .hue_interpolation(HueInterpolation::Decreasing)
+                .to_css_linear(deg(0)),
+            "linear-gradient(0deg in hsl decreasing hue, rgb(0, 0, 0) 0%, rgb(0, 0, 0) 0%)"
+        );
+    }
+
+    #[test]
+    fn can_serialize_conic_and_radial_css() {
+        let mut gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)])
+            .space(ColorSpace::OKLCH)
+            .hue_interpolation(HueInterpolation::Increasing);
+        gradient.add_stop(0.125, rgb(0, 128, 0));
+
+        assert_eq!(
+            gradient.to_css_conic(deg(0), (0.5, 0.5)),
+            "conic-gradient(from 0deg at 50% 50% in oklch increasing hue, \
+             rgb(255, 0, 0) 0%, rgb(0, 128, 0) 12.5%, rgb(0, 0, 255) 100%)"
+        );
+        assert_eq!(
+            gradient.to_css_radial(
+                RadialShape::Ellipse,
+                RadialExtent::FarthestCorner,
+                (1.0, 0.333)
+            ),
+            "radial-gradient(ellipse farthest-corner at 100% 33.3% in oklch increasing hue, \
+             rgb(255, 0, 0) 0%, rgb(0, 128, 0) 12.5%, rgb(0, 0, 255) 100%)"
+        );
+    }
+
+    #[test]
+    fn round_trips_css() {
+        let mut gradient = Gradient::from_colors(&[rgb(255, 0, 0), rgb(0, 0, 255)])
+            .space(ColorSpace::LCH)
+            .hue_interpolation(HueInterpolation::Longer);
+        gradient.add_stop(0.25, rgba(0, 128, 0, 0.5));
+
+        let outputs = [
+            gradient.to_css_linear(deg(30)),
+            gradient.to_css_conic(deg(30), (0.2, 0.8)),
+            gradient.to_css_radial(RadialShape::Circle, RadialExtent::ClosestCorner, (0.0, 0.0)),
+        ];
+
+        for output in outputs {
+            assert_eq!(
+                Gradient::from_css(&output),
+                Some(gradient.clone()),
+                "{}",
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn can_export_svg() {
+        let gradient =
+            Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)]).space(ColorSpace::OKLAB);
+        let svg = gradient.to_svg_linear_gradient("a&\"b");
+
+        assert!(svg.starts_with("<linearGradient id=\"a&amp;&quot;b\">\n"));
+        assert!(svg.ends_with("</linearGradient>"));
+        assert_eq!(svg.matches("<stop ").count(), 9);
+        assert!(svg.contains(&format!(
+            "<stop offset=\"50%\" stop-color=\"{}\"/>",
+            gradient.at(0.5).to_rgb().to_hex()
+        )));
+
+        let plain = gradient.space(ColorSpace::SRGB);
+        assert_eq!(
+            plain.to_svg_linear_gradient("g").matches("<stop ").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn cuts_svg_stops_to_bounds() {
+        let mut gradient = Gradient::new().space(ColorSpace::SRGB);
+        gradient.add_stop(-1.0, rgb(0, 0, 0));
+        gradient.add_stop(0.5, rgb(255, 0, 0));
+        gradient.add_stop(3.0, rgb(0, 0, 0));
+        let svg = gradient.to_svg_linear_gradient("g");
+
+        assert!(svg.contains(&format!(
+            "<stop offset=\"0%\" stop-color=\"{}\"/>",
+            gradient.at(0.0).to_rgb().to_hex()
+        )));
+        assert!(svg.contains(&format!(
+            "<stop offset=\"100%\" stop-color=\"{}\"/>",
+            gradient.at(1.0).to_rgb().to_hex()
+        )));
+        assert!(!svg.contains("offset=\"-"));
+        assert!(!svg.contains("300%"));
+    }
+
+    #[test]
+    fn approximates_easings_in_css() {
+        let mut gradient =
+            Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)]).space(ColorSpace::SRGB);
+        gradient.set_easing(0, Easing::EaseIn);
+        let css = gradient.to_css_linear(deg(90));
+
+        assert_eq!(css.matches("rgb(").count(), 9);
+        assert!(css.contains(&format!("{} 50%", gradient.at(0.5).to_rgb())));
+        assert!(css.ends_with("rgb(255, 255, 255) 100%)"));
+    }
+
+    #[test]
+    fn can_parse_css() {
+        let gradient = Gradient::from_css(
+            "linear-gradient(45deg in oklch longer hue, rgb(255 0 0 / 50%) 10%, lime, blue 90% 100%)",
+        )
+        .unwrap();
+        let positions: Vec<_> = gradient
+            .stops()
+            .iter()
+            .map(|stop| (stop.position * 1000.0).round() / 1000.0)
+            .collect();
+
+        assert_eq!(gradient.color_space(), ColorSpace::OKLCH);
+        assert_eq!(gradient.hue_arc(), HueInterpolation::Longer);
+        assert_eq!(positions, vec![0.1, 0.5, 0.9, 1.0]);
+        assert_eq!(gradient.stops()[0].color, rgba(255, 0, 0, 0.5));
+        assert_eq!(gradient.stops()[3].color, rgba(0, 0, 255, 1.0));
+
+        let gradient = Gradient::from_css("radial-gradient(circle at center, red, blue)").unwrap();
+        assert_eq!(gradient.color_space(), ColorSpace::OKLAB);
+        assert_eq!(gradient.len(), 2);
+    }
+
+    #[test]
+    fn positions_css_stops() {
+        let positions = |input: &str| -> Vec<f32> {
+            Gradient::from_css(input)
+                .unwrap()
+                .stops()
+                .iter()
+                .map(|stop| (stop.position * 1000.0).round() / 1000.0)
+                .collect()
+        };
+
+        assert_eq!(
+            positions("linear-gradient(red 20%, lime, blue 10%, white)"),
+            vec![0.2, 0.2, 0.2, 1.0]
+        );
+        assert_eq!(
+            positions("linear-gradient(red, lime, blue, white 60%, black)"),
+            vec![0.0, 0.2, 0.4, 0.6, 1.0]
+        );
+        assert_eq!(
+            positions("conic-gradient(from 90deg, red 90deg, blue 0.5turn, lime)"),
+            vec![0.25, 0.5, 1.0]
+        );
+    }
+
+    #[test]
+    fn approximates_css_hints() {
+        let gradient = Gradient::from_css("linear-gradient(in srgb, black, 25%, white)").unwrap();
+
+        assert_eq!(gradient.len(), 3);
+        assert_eq!(gradient.stops()[1].position, 0.25);
+        assert_eq!(gradient.at(0.25), rgba(128, 128, 128, 1.0));
+    }
+
+    #[test]
+    fn handles_invalid_css() {
+        let invalid = [
+            "linear-gradient()",
+            "linear-gradient(red 10px, blue)",
+            "linear-gradient(red, 50%)",
+            "linear-gradient(red, 20%, 50%, blue)",
+            "linear-gradient(in cmyk, red, blue)",
+            "linear-gradient(in oklab longer hue, red, blue)",
+            "linear-gradient(red, notacolor)",
+            "linear-gradient(red, blue",
+            "repeating-linear-gradient(red, blue)",
+            "conic-gradient(red 10deg 20deg 30deg, blue)",
+        ];
+
+        for input in invalid {
+            assert_eq!(Gradient::from_css(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn smooths_through_oklab() {
+        let mut gradient =
+            Gradient::from_colors(&[rgb(0, 0, 255), rgb(255, 255, 0)]).space(ColorSpace::SRGB);
+        let oklab = gradient.clone().space(ColorSpace::OKLAB);
+        gradient.smooth(17);
+
+        assert_eq!(gradient.len(), 17);
+        assert_eq!(gradient.color_space(), ColorSpace::SRGB);
+
+        for i in 0..=64 {
+            let t = i as f32 / 64.0;
+            assert!(gradient.at(t).delta_e2000(oklab.at(t)) < 1.5, "{}", t);
+        }
+    }
+
+    #[test]
+    fn smooths_keeping_stops_and_edges() {
+        let mut gradient = Gradient::new().space(ColorSpace::SRGB);
+        gradient.add_stop(0.0, rgb(255, 0, 0));
+        gradient.add_stop(0.5, rgb(0, 255, 0));
+        gradient.add_stop(0.5, rgb(0, 0, 255));
+        gradient.add_stop(1.0, rgb(255, 255, 255));
+        gradient.set_easing(0, Easing::EaseIn);
+
+        let eased = gradient.clone().space(ColorSpace::OKLAB);
+        gradient.smooth(5);
+
+        assert_eq!(gradient.len(), 6);
+        assert!(gradient
+            .stops()
+            .iter()
+            .all(|stop| stop.easing == Easing::Linear));
+        assert_eq!(gradient.at(0.5), rgba(0, 0, 255, 1.0));
+        assert_eq!(gradient.at(0.25), eased.at(0.25));
+        assert_eq!(gradient.at(0.4999), rgba(0, 255, 0, 1.0));
+
+        let mut empty = Gradient::new();
+        empty.smooth(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn can_step_between_colors() {
+        let steps: Vec<_> = rgb(0, 0, 0)
+            .steps_to(rgb(255, 255, 255), 5, ColorSpace::SRGB)
+            .collect();
+
+        assert_eq!(
+            steps,
+            vec![
+                rgba(0, 0, 0, 1.0),
+                rgba(64, 64, 64, 1.0),
+                rgba(128, 128, 128, 1.0),
+                rgba(191, 191, 191, 1.0),
+                rgba(255, 255, 255, 1.0)
+            ]
+        );
+        assert_eq!(
+            hsl(0, 100, 50)
+                .steps_to(hsl(240, 100, 50), 3, ColorSpace::HSL)
+                .nth(1)
+                .map(|color| color.to_hsl()),
+            Some(hsl(300, 100, 50))
+        );
+    }
+
+    #[test]
+    fn steps_from_both_ends() {
+        let mut steps = rgb(0, 0, 0).steps_to(rgb(255, 255, 255), 3, ColorSpace::SRGB);
+
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps.next_back(), Some(rgba(255, 255, 255, 1.0)));
+        assert_eq!(steps.next(), Some(rgba(0, 0, 0, 1.0)));
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps.next_back(), Some(rgba(128, 128, 128, 1.0)));
+        assert_eq!(steps.next(), None);
+    }
+
+    #[test]
+    fn handles_few_steps() {
+        let red = rgb(255, 0, 0);
+
+        assert_eq!(red.steps_to(rgb(0, 0, 0), 0, ColorSpace::OKLAB).count(), 0);
+        assert_eq!(
+            red.steps_to(rgb(0, 0, 0), 1, ColorSpace::OKLAB)
+                .collect::<Vec<_>>(),
+            vec![red.to_rgba()]
+        );
+    }
+
+    #[test]
+    fn can_build_bezier_curves() {
+        let colors = [rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255)];
+        let curve = bezier(&colors, ColorSpace::OKLAB);
+
+        assert_eq!(curve.at(0.0), rgba(255, 0, 0, 1.0));
+        assert_eq!(curve.at(1.0), rgba(0, 0, 255, 1.0));
+        assert_eq!(curve.at(-1.0), curve.at(0.0));
+
+        // A quadratic curve only passes halfway to its control point.
+        let [l, a, b] = ColorSpace::OKLAB.decompose(curve.at(0.5));
+        let points: Vec<[f32; 3]> = colors
+            .iter()
+            .map(|&color| ColorSpace::OKLAB.decompose(color))
+            .collect();
+
+        for (channel, value) in [l, a, b].into_iter().enumerate() {
+            let expected =
+                0.25 * points[0][channel] + 0.5 * points[1][channel] + 0.25 * points[2][channel];
+            assert!((value - expected).abs() < 0.01, "{} {}", value, expected);
+        }
+    }
+
+    #[test]
+    fn smooths_lightness() {
+        let colors = [rgb(255, 255, 224), rgb(255, 0, 0), rgb(0, 0, 0)];
+        let curve = bezier(&colors, ColorSpace::LAB);
+        let lightness: Vec<f32> = (0..=20)
+            .map(|i| curve.at(i as f32 / 20.0).to_lab().l)
+            .collect();
+
+        let steps: Vec<f32> = lightness.windows(2).map(|pair| pair[0] - pair[1]).collect();
+        assert!(steps.iter().all(|&step| step > 0.0), "{:?}", steps);
+    }
+
+    #[test]
+    fn samples_bezier_curves_into_gradients() {
+        let curve = bezier(
+            &[rgba(0, 0, 0, 0.0), rgba(255, 255, 255, 1.0)],
+            ColorSpace::SRGB,
+        );
+        let gradient = curve.to_gradient(3);
+
+        assert_eq!(gradient.color_space(), ColorSpace::SRGB);
+        assert_eq!(gradient.stops()[1].color, rgba(128, 128, 128, 0.5));
+        assert_eq!(
+            bezier(&[rgb(1, 2, 3)], ColorSpace::LAB).at(0.5),
+            rgba(1, 2, 3, 1.0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_cylindrical_bezier_spaces() {
+        bezier(&[rgb(0, 0, 0)], ColorSpace::OKLCH);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_empty_gradients() {
+        Gradient::new().at(0.5);
+    }
+}
@@ -0,0 +1,184 @@
+use super::{hue::rotate_hue, ramp::fit_chroma, Color, HueSpace, Palette, RGB, RGBA};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The classic color schemes, built by rotating the hue of a base color
+/// around the color wheel.
+pub enum Harmony {
+    /// The base color and its opposite, 180° away.
+    Complementary,
+
+    /// Three colors evenly spaced around the wheel, 120° apart.
+    Triadic,
+
+    /// Four colors evenly spaced around the wheel, 90° apart.
+    Tetradic,
+
+    /// The base color and its neighbours, 30° away on either side.
+    Analogous,
+
+    /// The base color and the two neighbours of its opposite, 150° away on
+    /// either side.
+    SplitComplementary,
+}
+
+impl Harmony {
+    /// Returns the hue rotations making up the scheme, in degrees, starting
+    /// with the base color itself.
+    pub fn angles(self) -> &'static [f32] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+            Harmony::Analogous => &[0.0, -30.0, 30.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+        }
+    }
+}
+
+// Rotates the hue of `color` by each of `angles`, in order.
+pub(crate) fn harmony_at<T: Color>(color: T, angles: &[f32], space: HueSpace) -> Palette {
+    let color = color.to_rgba();
+
+    angles
+        .iter()
+        .map(|&angle| rotate_hue(color, angle, space))
+        .collect()
+}
+
+// The WCAG 2.x contrast ratio accents keep against their base, the minimum
+// for user interface components.
+const ACCENT_CONTRAST: f32 = 3.0;
+
+// Suggests accents for `color`: its OKLCH complement and split complements,
+// with the same lightness and chroma where the gamut allows, then pushed
+// lighter or darker until they stand out from `color`.
+pub(crate) fn suggest_accent<T: Color>(color: T) -> Palette {
+    let color = color.to_rgba();
+    let alpha = color.a;
+    let base = color.to_oklch();
+
+    [180.0, 150.0, 210.0]
+        .into_iter()
+        .filter_map(|angle: f32| {
+            let RGB { r, g, b } = fit_chroma(base.l, base.c, (base.h + angle) % 360.0);
+
+            RGBA { r, g, b, a: alpha }.ensure_contrast(color, ACCENT_CONTRAST)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, hsla, rgb, Color, Harmony, HueSpace};
+
+    #[test]
+    fn can_suggest_accents() {
+        for base in [rgb(23, 98, 119), rgb(255, 99, 71), rgb(250, 235, 215)] {
+            let accents = base.suggest_accent();
+
+            assert_eq!(accents.len(), 3, "{}", base);
+
+            for (accent, angle) in accents.iter().zip([180.0_f32, 150.0, 210.0]) {
+                assert!(accent.contrast_ratio(base).as_f32() >= 3.0);
+
+                // Pale colors have too little chroma for their hue to hold up
+                // under the lightness change.
+                if base.to_oklch().c > 0.05 {
+                    let distance = base.hue_distance(accent, HueSpace::OKLCH);
+                    assert!(
+                        (distance - angle.min(360.0 - angle)).abs() < 10.0,
+                        "{}",
+                        accent
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_accent_chroma_and_alpha() {
+        let teal = hsla(193, 68, 28, 0.5);
+        let accent = teal.suggest_accent().get(0).unwrap();
+
+        assert_eq!(accent.a, teal.a);
+        assert!((accent.to_oklch().c - teal.to_oklch().c).abs() < 0.05);
+    }
+
+    #[test]
+    fn can_build_hsl_harmonies() {
+        let red = hsl(0, 100, 50);
+
+        assert_eq!(
+            red.harmony(Harmony::Complementary, HueSpace::HSL).to_hsl(),
+            vec![hsl(0, 100, 50), hsl(180, 100, 50)]
+        );
+        assert_eq!(
+            red.harmony(Harmony::Triadic, HueSpace::HSL).to_hsl(),
+            vec![hsl(0, 100, 50), hsl(120, 100, 50), hsl(240, 100, 50)]
+        );
+        assert_eq!(
+            red.harmony(Harmony::Tetradic, HueSpace::HSL).to_hsl(),
+            vec![
+                hsl(0, 100, 50),
+                hsl(90, 100, 50),
+                hsl(180, 100, 50),
+                hsl(270, 100, 50)
+            ]
+        );
+        assert_eq!(
+            red.harmony(Harmony::Analogous, HueSpace::HSL).to_hsl(),
+            vec![hsl(0, 100, 50), hsl(330, 100, 50), hsl(30, 100, 50)]
+        );
+        assert_eq!(
+            red.harmony(Harmony::SplitComplementary, HueSpace::HSL)
+                .to_hsl(),
+            vec![hsl(0, 100, 50), hsl(150, 100, 50), hsl(210, 100, 50)]
+        );
+    }
+
+    #[test]
+    fn can_build_perceptual_harmonies() {
+        let tomato = rgb(255, 99, 71);
+
+        for space in [HueSpace::LCH, HueSpace::OKLCH] {
+            let palette = tomato.harmony(Harmony::Triadic, space);
+
+            assert_eq!(palette.len(), 3);
+            assert_eq!(palette.get(0), Some(tomato.to_rgba()));
+
+            for (i, color) in palette.iter().enumerate().skip(1) {
+                let distance = tomato.hue_distance(color, space);
+                assert!((distance - 120.0).abs() < 8.0, "{} {}", i, distance);
+            }
+        }
+    }
+
+    #[test]
+    fn can_build_custom_harmonies() {
+        let red = hsl(0, 100, 50);
+
+        assert_eq!(
+            red.harmony_at(&[30.0, 180.0, 210.0], HueSpace::HSL)
+                .to_hsl(),
+            vec![hsl(30, 100, 50), hsl(180, 100, 50), hsl(210, 100, 50)]
+        );
+        assert_eq!(
+            red.harmony_at(&[-90.0, 450.0], HueSpace::HSL).to_hsl(),
+            vec![hsl(270, 100, 50), hsl(90, 100, 50)]
+        );
+        assert_eq!(
+            red.harmony_at(Harmony::Triadic.angles(), HueSpace::OKLCH),
+            red.harmony(Harmony::Triadic, HueSpace::OKLCH)
+        );
+        assert!(red.harmony_at(&[], HueSpace::HSL).is_empty());
+    }
+
+    #[test]
+    fn keeps_alpha() {
+        let palette = hsla(10, 90, 50, 0.5).harmony(Harmony::Complementary, HueSpace::OKLCH);
+
+        assert!(palette
+            .iter()
+            .all(|color| color.a == hsla(10, 90, 50, 0.5).a));
+    }
+}
@@ -0,0 +1,546 @@
+use super::{oklab::normalize_hue, Ratio, RGB};
+use std::{f64::consts::PI, fmt};
+
+/// Constructs an HCT Color from numerical values, the color space Material
+/// Design 3 builds its palettes in.
+///
+/// The hue component is expressed in degrees. Values outside of the 0-360°
+/// range will be normalized accordingly. The chroma component starts at `0.0`
+/// for greys, and the tone component ranges between `0.0` (black) and `100.0`
+/// (white).
+///
+/// # Example
+/// ```
+/// use farver::hct;
+///
+/// let purple = hct(-77.21, 36.0, 40.0);
+///
+/// assert_eq!(purple.to_string(), "hct(282.79 36.00 40.00)");
+/// ```
+pub fn hct(h: f32, c: f32, t: f32) -> HCT {
+    HCT {
+        h: normalize_hue(h),
+        c,
+        t,
+    }
+}
+
+// The conversions below run in double precision, so the palettes they build
+// match those of Google's material-color-utilities to the last bit.
+
+// Converts a gamma-encoded sRGB channel into linear light, between 0.0 and
+// 100.0.
+fn to_linear(channel: Ratio) -> f64 {
+    to_linear_value(channel.as_u8() as f64 / 255.0)
+}
+
+fn to_linear_value(value: f64) -> f64 {
+    100.0
+        * if value <= 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+}
+
+// Converts a linear light channel between 0.0 and 100.0 back into its
+// gamma-encoded sRGB value.
+fn from_linear_value(value: f64) -> f64 {
+    let value = value / 100.0;
+
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Builds a color from linear light channels between 0.0 and 100.0, clipping
+// the channels falling outside of the sRGB gamut.
+fn from_linear(linear: [f64; 3]) -> RGB {
+    let [r, g, b] = linear.map(|value| {
+        Ratio::from_u8((from_linear_value(value).clamp(0.0, 1.0) * 255.0).round() as u8)
+    });
+
+    RGB { r, g, b }
+}
+
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+// Converts a CIE luminance between 0.0 and 100.0 into its L* lightness.
+fn lstar_from_y(y: f64) -> f64 {
+    let y = y / 100.0;
+
+    if y <= EPSILON {
+        KAPPA * y
+    } else {
+        116.0 * y.cbrt() - 16.0
+    }
+}
+
+// Converts an L* lightness into its CIE luminance, between 0.0 and 100.0.
+fn y_from_lstar(lstar: f64) -> f64 {
+    let f = (lstar + 16.0) / 116.0;
+    let f3 = f * f * f;
+
+    100.0 * if f3 > EPSILON { f3 } else { lstar / KAPPA }
+}
+
+// The matrix from XYZ into the CAM16 cone responses.
+const CAM16_FROM_XYZ: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+// The matrix from the adapted, discounted CAM16 responses back into linear
+// sRGB, scaled between 0.0 and 100.0.
+#[allow(clippy::excessive_precision)]
+const LINEAR_FROM_CAM16: [[f64; 3]; 3] = [
+    [1373.2198709594231, -1100.4251190754821, -7.278681089101213],
+    [-271.815969077903, 559.6580465940733, -32.46047482791194],
+    [1.9622899599665666, -57.173814538844006, 308.7233197812385],
+];
+
+// The inverse of the matrix above.
+#[allow(clippy::excessive_precision)]
+const CAM16_FROM_LINEAR: [[f64; 3]; 3] = [
+    [
+        0.001200833568784504,
+        0.0023896944921708886,
+        0.00027957428858611235,
+    ],
+    [
+        0.0005891086651376,
+        0.002978550257343876,
+        0.00032706661040083983,
+    ],
+    [
+        0.00010146692491640575,
+        0.0005364214359186694,
+        0.0032979401770712076,
+    ],
+];
+
+const Y_FROM_LINEAR: [f64; 3] = [0.2126, 0.7152, 0.0722];
+
+// The CAM16 viewing conditions Material Design uses: a D65 white point, an
+// average surround, a background of L* 50, and an adapting luminance of about
+// 11.72 cd/m².
+struct Viewing {
+    n: f64,
+    aw: f64,
+    nbb: f64,
+    c: f64,
+    z: f64,
+    fl: f64,
+    rgb_d: [f64; 3],
+}
+
+impl Viewing {
+    fn new() -> Self {
+        let white = [95.047, 100.0, 108.883];
+        let adapting_luminance = (200.0 / PI) * y_from_lstar(50.0) / 100.0;
+        let background = y_from_lstar(50.0);
+
+        let rgb_w: [f64; 3] =
+            std::array::from_fn(|i| (0..3).map(|j| CAM16_FROM_XYZ[i][j] * white[j]).sum());
+
+        // An average surround gives F = 1.0, and so c = 0.69 and Nc = 1.0.
+        let f = 1.0;
+        let c = 0.69;
+        let d =
+            (f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let rgb_d = rgb_w.map(|w| d * (100.0 / w) + 1.0 - d);
+
+        let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+        let k4 = k * k * k * k;
+        let fl = k4 * adapting_luminance
+            + 0.1 * (1.0 - k4) * (1.0 - k4) * (5.0 * adapting_luminance).cbrt();
+
+        let n = background / white[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+
+        let rgb_a: [f64; 3] = std::array::from_fn(|i| {
+            let factor = (fl * rgb_d[i] * rgb_w[i] / 100.0).powf(0.42);
+            400.0 * factor / (factor + 27.13)
+        });
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+
+        Viewing {
+            n,
+            aw,
+            nbb,
+            c,
+            z,
+            fl,
+            rgb_d,
+        }
+    }
+
+    // The first factor of the CAM16 chroma, which only depends on the viewing
+    // conditions.
+    fn chroma_factor(&self) -> f64 {
+        (1.64 - 0.29f64.powf(self.n)).powf(0.73)
+    }
+
+    // The eccentricity factor of CAM16, scaled by its constants.
+    fn p1(&self, hue: f64) -> f64 {
+        let eccentricity = 0.25 * ((hue.to_radians() + 2.0).cos() + 3.8);
+
+        50000.0 / 13.0 * eccentricity * self.nbb
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in HCT, the color space of Material Design 3.
+/// It pairs the hue and chroma of the CAM16 color appearance model with the
+/// L* lightness of CIELAB, called tone.
+///
+/// The hue `h` is a degree on the color wheel between `0.0` and `360.0`, the
+/// chroma `c` starts at `0.0` for greys, and the tone `t` ranges between `0.0`
+/// (black) and `100.0` (white).
+///
+/// For more, see [the introduction of HCT](https://material.io/blog/science-of-color-design).
+pub struct HCT {
+    // hue
+    pub h: f32,
+
+    // chroma
+    pub c: f32,
+
+    // tone
+    pub t: f32,
+}
+
+impl HCT {
+    pub(crate) fn from_rgb(rgb: RGB) -> Self {
+        let viewing = Viewing::new();
+        let [r, g, b] = [to_linear(rgb.r), to_linear(rgb.g), to_linear(rgb.b)];
+
+        let xyz = [
+            0.41233895 * r + 0.35762064 * g + 0.18051042 * b,
+            0.2126 * r + 0.7152 * g + 0.0722 * b,
+            0.01932141 * r + 0.11916382 * g + 0.9503448 * b,
+        ];
+
+        let [r, g, b]: [f64; 3] = std::array::from_fn(|i| {
+            let cone: f64 = (0..3).map(|j| CAM16_FROM_XYZ[i][j] * xyz[j]).sum();
+            let discounted = viewing.rgb_d[i] * cone;
+            let factor = (viewing.fl * discounted.abs() / 100.0).powf(0.42);
+
+            discounted.signum() * 400.0 * factor / (factor + 27.13)
+        });
+
+        let a = (11.0 * r - 12.0 * g + b) / 11.0;
+        let b_ = (r + g - 2.0 * b) / 9.0;
+        let u = (20.0 * r + 20.0 * g + 21.0 * b) / 20.0;
+        let p2 = (40.0 * r + 20.0 * g + b) / 20.0;
+
+        let hue = b_.atan2(a).to_degrees().rem_euclid(360.0);
+        let j = 100.0
+            * (p2 * viewing.nbb / viewing.aw)
+                .max(0.0)
+                .powf(viewing.c * viewing.z);
+        let t = viewing.p1(hue) * a.hypot(b_) / (u + 0.305);
+        let chroma = viewing.chroma_factor() * t.powf(0.9) * (j / 100.0).sqrt();
+
+        HCT {
+            h: normalize_hue(hue as f32),
+            c: chroma as f32,
+            t: lstar_from_y(xyz[1]) as f32,
+        }
+    }
+
+    /// Converts `self` into its RGB representation. Colors falling outside of
+    /// the sRGB gamut keep their hue and tone, and are given the highest
+    /// chroma available, as Material Design does.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_hct().to_rgb(), tomato);
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        let [h, c, t] = [self.h, self.c, self.t].map(|value| value as f64);
+
+        if c < 1e-4 || t <= 1e-4 || t >= 100.0 - 1e-4 {
+            return grey(t);
+        }
+
+        let viewing = Viewing::new();
+        let y = y_from_lstar(t);
+
+        if let Some(linear) = solve(&viewing, h, c, y) {
+            return from_linear(linear);
+        }
+
+        from_linear(bisect_to_limit(y, h.to_radians()))
+    }
+}
+
+// Returns the grey with the given tone.
+fn grey(tone: f64) -> RGB {
+    let y = y_from_lstar(tone.clamp(0.0, 100.0));
+
+    from_linear([y; 3])
+}
+
+// Finds the linear sRGB channels, between 0.0 and 100.0, of the color with the
+// given hue, chroma, and luminance, by iterating on its CAM16 lightness J with
+// Newton's method. Returns `None` if the color falls outside of the gamut.
+// Follows the solver of Google's material-color-utilities.
+fn solve(viewing: &Viewing, hue: f64, chroma: f64, y: f64) -> Option<[f64; 3]> {
+    let mut j = y.sqrt() * 11.0;
+
+    let t_factor = 1.0 / viewing.chroma_factor();
+    let p1 = viewing.p1(hue);
+    let (sin, cos) = hue.to_radians().sin_cos();
+
+    for round in 0..5 {
+        let j_normalized = j / 100.0;
+        let alpha = if chroma == 0.0 || j == 0.0 {
+            0.0
+        } else {
+            chroma / j_normalized.sqrt()
+        };
+        let t = (alpha * t_factor).powf(1.0 / 0.9);
+        let p2 = viewing.aw * j_normalized.powf(1.0 / viewing.c / viewing.z) / viewing.nbb;
+
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * cos + 108.0 * t * sin);
+        let a = gamma * cos;
+        let b = gamma * sin;
+
+        let adapted = [
+            (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        ]
+        .map(|channel: f64| {
+            let base = (27.13 * channel.abs() / (400.0 - channel.abs())).max(0.0);
+            channel.signum() * base.powf(1.0 / 0.42)
+        });
+
+        let linear: [f64; 3] =
+            std::array::from_fn(|i| (0..3).map(|k| LINEAR_FROM_CAM16[i][k] * adapted[k]).sum());
+
+        if linear.iter().any(|&channel| channel < 0.0) {
+            return None;
+        }
+
+        let fnj: f64 = (0..3).map(|k| Y_FROM_LINEAR[k] * linear[k]).sum();
+
+        if fnj <= 0.0 {
+            return None;
+        }
+
+        if round == 4 || (fnj - y).abs() < 0.002 {
+            if linear.iter().any(|&channel| channel > 100.01) {
+                return None;
+            }
+
+            return Some(linear);
+        }
+
+        j -= (fnj - y) * j / (2.0 * fnj);
+    }
+
+    None
+}
+
+// Returns the CAM16 hue of linear sRGB channels, in radians.
+fn hue_of(linear: [f64; 3]) -> f64 {
+    let [r, g, b]: [f64; 3] = std::array::from_fn(|i| {
+        let scaled: f64 = (0..3).map(|k| CAM16_FROM_LINEAR[i][k] * linear[k]).sum();
+        let factor = scaled.abs().powf(0.42);
+
+        scaled.signum() * 400.0 * factor / (factor + 27.13)
+    });
+
+    let a = (11.0 * r - 12.0 * g + b) / 11.0;
+    let b = (r + g - 2.0 * b) / 9.0;
+
+    b.atan2(a)
+}
+
+// Returns whether `b` comes strictly between `a` and `c`, going around the
+// wheel from `a`.
+fn in_cyclic_order(a: f64, b: f64, c: f64) -> bool {
+    let delta = |from: f64, to: f64| (to - from).rem_euclid(2.0 * PI);
+
+    delta(a, b) < delta(a, c)
+}
+
+// Returns the `n`-th of the twelve edges of the RGB cube intersected with the
+// plane of the given luminance, if the intersection falls on the edge.
+#[allow(clippy::manual_is_multiple_of)]
+fn nth_vertex(y: f64, n: usize) -> Option<[f64; 3]> {
+    let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
+    let coord_b = if n % 2 == 0 { 0.0 } else { 100.0 };
+
+    // The index of the free channel, and those of the two fixed ones.
+    let (free, a, b) = match n {
+        0..=3 => (0, 1, 2),
+        4..=7 => (1, 2, 0),
+        _ => (2, 0, 1),
+    };
+
+    let mut vertex = [0.0; 3];
+    vertex[a] = coord_a;
+    vertex[b] = coord_b;
+    vertex[free] =
+        (y - coord_a * Y_FROM_LINEAR[a] - coord_b * Y_FROM_LINEAR[b]) / Y_FROM_LINEAR[free];
+
+    (0.0..=100.0).contains(&vertex[free]).then_some(vertex)
+}
+
+// Finds the two vertices of the gamut slice of the given luminance whose hues
+// surround `target`.
+fn bisect_to_segment(y: f64, target: f64) -> ([f64; 3], f64, [f64; 3]) {
+    let mut segment: Option<([f64; 3], f64, [f64; 3], f64)> = None;
+    let mut uncut = true;
+
+    for vertex in (0..12).filter_map(|n| nth_vertex(y, n)) {
+        let hue = hue_of(vertex);
+
+        let Some((left, left_hue, right, right_hue)) = segment.as_mut() else {
+            segment = Some((vertex, hue, vertex, hue));
+            continue;
+        };
+
+        if uncut || in_cyclic_order(*left_hue, hue, *right_hue) {
+            uncut = false;
+
+            if in_cyclic_order(*left_hue, target, hue) {
+                *right = vertex;
+                *right_hue = hue;
+            } else {
+                *left = vertex;
+                *left_hue = hue;
+            }
+        }
+    }
+
+    let (left, left_hue, right, _) = segment.unwrap_or(([0.0; 3], 0.0, [0.0; 3], 0.0));
+    (left, left_hue, right)
+}
+
+// Finds the color on the edge of the sRGB gamut with the given luminance and
+// hue, which is the most chromatic one available. The segment surrounding the
+// hue is bisected along the planes where channels change their 8-bit value.
+fn bisect_to_limit(y: f64, target: f64) -> [f64; 3] {
+    let (mut left, mut left_hue, mut right) = bisect_to_segment(y, target);
+
+    // The linear value, between 0.0 and 100.0, halfway between two 8-bit values.
+    let plane = |index: f64| to_linear_value((index + 0.5) / 255.0);
+    let delinearized = |value: f64| from_linear_value(value) * 255.0;
+
+    for axis in 0..3 {
+        if left[axis] == right[axis] {
+            continue;
+        }
+
+        let (mut left_plane, mut right_plane) = if left[axis] < right[axis] {
+            (
+                (delinearized(left[axis]) - 0.5).floor(),
+                (delinearized(right[axis]) - 0.5).ceil(),
+            )
+        } else {
+            (
+                (delinearized(left[axis]) - 0.5).ceil(),
+                (delinearized(right[axis]) - 0.5).floor(),
+            )
+        };
+
+        for _ in 0..8 {
+            if (right_plane - left_plane).abs() <= 1.0 {
+                break;
+            }
+
+            let mid_plane = ((left_plane + right_plane) / 2.0).floor();
+            let t = (plane(mid_plane) - left[axis]) / (right[axis] - left[axis]);
+            let mid: [f64; 3] = std::array::from_fn(|i| left[i] + (right[i] - left[i]) * t);
+            let mid_hue = hue_of(mid);
+
+            if in_cyclic_order(left_hue, target, mid_hue) {
+                right = mid;
+                right_plane = mid_plane;
+            } else {
+                left = mid;
+                left_hue = mid_hue;
+                left_plane = mid_plane;
+            }
+        }
+    }
+
+    std::array::from_fn(|i| (left[i] + right[i]) / 2.0)
+}
+
+impl fmt::Display for HCT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "hct({:.02} {:.02} {:.02})", self.h, self.c, self.t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hct, hsl, rgb, rgba, Color};
+
+    #[test]
+    fn can_convert_reference_colors() {
+        assert_eq!(
+            rgb(255, 0, 0).to_hct().to_string(),
+            "hct(27.41 113.36 53.23)"
+        );
+        assert_eq!(
+            rgb(0, 255, 0).to_hct().to_string(),
+            "hct(142.14 108.41 87.74)"
+        );
+        assert_eq!(
+            rgb(0, 0, 255).to_hct().to_string(),
+            "hct(282.79 87.23 32.30)"
+        );
+        assert_eq!(
+            rgb(255, 255, 255).to_hct().to_string(),
+            "hct(209.49 2.87 100.00)"
+        );
+
+        // CAM16 does not fully adapt to the white point, so greys keep a hint
+        // of chroma.
+        assert!(rgb(128, 128, 128).to_hct().c < 3.0);
+    }
+
+    #[test]
+    fn can_round_trip() {
+        for color in [
+            rgb(255, 99, 71),
+            rgb(23, 98, 119),
+            rgb(103, 80, 164),
+            rgb(255, 0, 0),
+            rgb(0, 0, 255),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ] {
+            assert_eq!(color.to_hct().to_rgb(), color);
+        }
+
+        assert_eq!(rgba(255, 99, 71, 0.5).to_hct(), rgb(255, 99, 71).to_hct());
+        assert_eq!(hsl(0, 0, 100).to_hct(), rgb(255, 255, 255).to_hct());
+    }
+
+    #[test]
+    fn keeps_hue_and_tone_out_of_gamut() {
+        let color = hct(27.41, 200.0, 53.24).to_rgb().to_hct();
+
+        assert!((color.t - 53.24).abs() < 0.5);
+        assert!((color.h - 27.41).abs() < 1.0);
+        assert!(color.c < 200.0);
+        assert_eq!(hct(0.0, 50.0, 0.0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(hct(0.0, 50.0, 100.0).to_rgb(), rgb(255, 255, 255));
+    }
+}
@@ -0,0 +1,418 @@
+use super::{
+    linear::{from_linear_channels, to_xyz},
+    Gamut, RGB, XYZ,
+};
+
+// The brightness of SDR white in HDR video in nits, as recommended by ITU-R
+// BT.2408, and the HLG scene light it is shown at, 75% of the HLG signal.
+const REFERENCE_WHITE: f32 = 203.0;
+const HLG_REFERENCE_WHITE: f32 = 0.26496;
+
+// The constants of the PQ transfer function, from SMPTE ST 2084.
+const PQ_M1: f32 = 2610.0 / 16384.0;
+const PQ_M2: f32 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f32 = 3424.0 / 4096.0;
+const PQ_C2: f32 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+// The constants of the HLG transfer function, from ITU-R BT.2100, kept at
+// their published precision.
+const HLG_A: f32 = 0.17883277;
+const HLG_B: f32 = 0.28466892;
+#[allow(clippy::excessive_precision)]
+const HLG_C: f32 = 0.55991073;
+
+/// Encodes a brightness in nits, between `0.0` and `10000.0`, into a signal of
+/// the SMPTE ST 2084 perceptual quantizer (PQ) used by HDR10 and Dolby Vision,
+/// between `0.0` and `1.0`. Brightnesses out of range are clamped.
+///
+/// # Example
+/// ```
+/// use farver::pq_encode;
+///
+/// assert_eq!(pq_encode(10000.0), 1.0);
+/// assert_eq!(format!("{:.3}", pq_encode(100.0)), "0.508");
+/// ```
+pub fn pq_encode(nits: f32) -> f32 {
+    let y = (nits / 10000.0).clamp(0.0, 1.0).powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+/// Decodes a PQ signal between `0.0` and `1.0` into its brightness in nits,
+/// the inverse of [`pq_encode`]. Signals out of range are clamped.
+pub fn pq_decode(signal: f32) -> f32 {
+    let e = signal.clamp(0.0, 1.0).powf(1.0 / PQ_M2);
+    ((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e)).powf(1.0 / PQ_M1) * 10000.0
+}
+
+/// Encodes scene light between `0.0` and `1.0` into a signal of the hybrid
+/// log-gamma (HLG) transfer function used by HDR broadcasts, between `0.0`
+/// and `1.0`. Light out of range is clamped.
+///
+/// # Example
+/// ```
+/// use farver::hlg_encode;
+///
+/// assert_eq!(hlg_encode(1.0 / 12.0), 0.5);
+/// assert_eq!(format!("{:.3}", hlg_encode(1.0)), "1.000");
+/// ```
+pub fn hlg_encode(light: f32) -> f32 {
+    let light = light.clamp(0.0, 1.0);
+
+    if light <= 1.0 / 12.0 {
+        (3.0 * light).sqrt()
+    } else {
+        HLG_A * (12.0 * light - HLG_B).ln() + HLG_C
+    }
+}
+
+/// Decodes an HLG signal between `0.0` and `1.0` into its scene light, the
+/// inverse of [`hlg_encode`]. Signals out of range are clamped.
+pub fn hlg_decode(signal: f32) -> f32 {
+    let signal = signal.clamp(0.0, 1.0);
+
+    if signal <= 0.5 {
+        signal * signal / 3.0
+    } else {
+        (((signal - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    }
+}
+
+/// The tonemapping operators available to [`LinearRGB::tonemap`], which
+/// compress HDR highlights into the range a display can show.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tonemap {
+    /// The operator of Reinhard et al., applied to the luminance so that hues
+    /// are kept. It is gentle, but leaves SDR white as a mid grey.
+    Reinhard,
+
+    /// Krzysztof Narkowicz's fit of the ACES filmic curve, applied channel by
+    /// channel. It adds contrast and desaturates the brightest highlights,
+    /// like film does.
+    AcesFilmic,
+}
+
+/// Constructs a color from linear light channels in the `gamut`, as used by
+/// renderers and HDR video.
+///
+/// Unlike [`RGB`], the channels are not limited to `0.0`-`1.0`: `1.0` is the
+/// brightness of SDR white, channels above it are brighter highlights, and
+/// negative channels hold colors outside of the gamut. They are kept as they
+/// are through conversions until [`LinearRGB::clamp`] or
+/// [`LinearRGB::to_rgb`] are used.
+///
+/// # Example
+/// ```
+/// use farver::{linear_rgb, Gamut};
+///
+/// // A highlight four times as bright as white.
+/// let highlight = linear_rgb(4.0, 4.0, 4.0, Gamut::Srgb);
+///
+/// assert_eq!(highlight.to_xyz().to_linear_rgb(Gamut::Srgb).r.round(), 4.0);
+/// ```
+pub fn linear_rgb(r: f32, g: f32, b: f32, gamut: Gamut) -> LinearRGB {
+    LinearRGB { r, g, b, gamut }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color as the extended-range linear light channels
+/// of an RGB gamut.
+pub struct LinearRGB {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+
+    // the gamut the channels are relative to
+    pub gamut: Gamut,
+}
+
+impl LinearRGB {
+    /// Converts `self` into CIE XYZ, keeping values out of range.
+    pub fn to_xyz(self) -> XYZ {
+        let [x, y, z] = to_xyz(self.srgb().map(|channel| channel as f64));
+
+        XYZ {
+            x: x as f32,
+            y: y as f32,
+            z: z as f32,
+        }
+    }
+
+    /// Converts `self` into the linear channels of another `gamut`, keeping
+    /// values out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{linear_rgb, Gamut};
+    ///
+    /// let red = linear_rgb(1.0, 0.0, 0.0, Gamut::DisplayP3).to_gamut(Gamut::Srgb);
+    ///
+    /// assert!(red.r > 1.0 && red.g < 0.0);
+    /// ```
+    pub fn to_gamut(self, gamut: Gamut) -> LinearRGB {
+        let [r, g, b] = gamut.channels(self.srgb());
+        linear_rgb(r, g, b, gamut)
+    }
+
+    /// Converts `self` into its RGB representation. Channels falling outside
+    /// of the sRGB gamut, or brighter than white, are clipped.
+    pub fn to_rgb(self) -> RGB {
+        from_linear_channels(self.srgb())
+    }
+
+    /// Clamps every channel of `self` into the `0.0`-`1.0` range of its
+    /// gamut, dropping highlights and colors outside of it.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{linear_rgb, Gamut};
+    ///
+    /// let color = linear_rgb(2.0, -0.5, 0.5, Gamut::Rec2020);
+    ///
+    /// assert_eq!(color.clamp(), linear_rgb(1.0, 0.0, 0.5, Gamut::Rec2020));
+    /// ```
+    pub fn clamp(self) -> LinearRGB {
+        let [r, g, b] = [self.r, self.g, self.b].map(|channel| channel.clamp(0.0, 1.0));
+        linear_rgb(r, g, b, self.gamut)
+    }
+
+    /// Returns whether every channel of `self` is finite, which conversions
+    /// of infinite or NaN values can't restore.
+    pub fn is_finite(self) -> bool {
+        self.r.is_finite() && self.g.is_finite() && self.b.is_finite()
+    }
+
+    /// Encodes `self` into the PQ signals of its Rec. 2020 channels, as in
+    /// HDR10 video, with SDR white shown at 203 nits as recommended by ITU-R
+    /// BT.2408.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{linear_rgb, Gamut};
+    ///
+    /// let white = linear_rgb(1.0, 1.0, 1.0, Gamut::Srgb).to_pq();
+    ///
+    /// assert_eq!(format!("{:.2}", white[0]), "0.58");
+    /// ```
+    pub fn to_pq(self) -> [f32; 3] {
+        self.rec2020()
+            .map(|channel| pq_encode(channel * REFERENCE_WHITE))
+    }
+
+    /// Decodes the PQ signals of Rec. 2020 channels, the inverse of
+    /// [`LinearRGB::to_pq`].
+    pub fn from_pq(signals: [f32; 3]) -> LinearRGB {
+        let [r, g, b] = signals.map(|signal| pq_decode(signal) / REFERENCE_WHITE);
+        linear_rgb(r, g, b, Gamut::Rec2020)
+    }
+
+    /// Encodes `self` into the HLG signals of its Rec. 2020 channels, with SDR
+    /// white at 75% of the signal as recommended by ITU-R BT.2408, leaving
+    /// room for highlights up to about 3.8 times as bright.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{linear_rgb, Gamut};
+    ///
+    /// let white = linear_rgb(1.0, 1.0, 1.0, Gamut::Srgb).to_hlg();
+    ///
+    /// assert_eq!(format!("{:.2}", white[0]), "0.75");
+    /// ```
+    pub fn to_hlg(self) -> [f32; 3] {
+        self.rec2020()
+            .map(|channel| hlg_encode(channel * HLG_REFERENCE_WHITE))
+    }
+
+    /// Decodes the HLG signals of Rec. 2020 channels, the inverse of
+    /// [`LinearRGB::to_hlg`].
+    pub fn from_hlg(signals: [f32; 3]) -> LinearRGB {
+        let [r, g, b] = signals.map(|signal| hlg_decode(signal) / HLG_REFERENCE_WHITE);
+        linear_rgb(r, g, b, Gamut::Rec2020)
+    }
+
+    /// Compresses `self` into sRGB with the tonemapping `operator`, so bright
+    /// highlights roll off towards white instead of being clipped. Colors
+    /// outside of the sRGB gamut are clipped first.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{linear_rgb, rgb, Gamut, Tonemap};
+    ///
+    /// let highlight = linear_rgb(2.0, 1.0, 0.5, Gamut::Srgb);
+    ///
+    /// assert_eq!(highlight.tonemap(Tonemap::Reinhard), rgb(246, 181, 132));
+    /// assert_eq!(highlight.tonemap(Tonemap::AcesFilmic), rgb(236, 214, 177));
+    /// ```
+    pub fn tonemap(self, operator: Tonemap) -> RGB {
+        let channels = self.srgb().map(|channel| channel.max(0.0));
+
+        let channels = match operator {
+            Tonemap::Reinhard => {
+                let [r, g, b] = channels;
+                let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+                let scale = 1.0 / (1.0 + luminance);
+
+                channels.map(|channel| channel * scale)
+            }
+            Tonemap::AcesFilmic => channels.map(|channel| {
+                // the fit expects the exposure of the original curve
+                let x = channel * 0.6;
+                (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)
+            }),
+        };
+
+        from_linear_channels(channels)
+    }
+
+    fn rec2020(self) -> [f32; 3] {
+        let LinearRGB { r, g, b, .. } = self.to_gamut(Gamut::Rec2020);
+        [r, g, b]
+    }
+
+    fn srgb(self) -> [f32; 3] {
+        self.gamut.to_srgb([self.r, self.g, self.b])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hlg_decode, hlg_encode, linear_rgb, oklab, pq_decode, pq_encode, rgb, xyz, Color, Gamut,
+        LinearRGB, Tonemap, RGB,
+    };
+
+    #[test]
+    fn keeps_extended_values() {
+        let color = linear_rgb(3.0, -0.25, 0.5, Gamut::Srgb);
+
+        for gamut in [Gamut::Srgb, Gamut::DisplayP3, Gamut::Rec2020] {
+            let back = color.to_gamut(gamut).to_xyz().to_linear_rgb(Gamut::Srgb);
+
+            assert!((back.r - 3.0).abs() < 1e-4, "{:?}", gamut);
+            assert!((back.g + 0.25).abs() < 1e-4, "{:?}", gamut);
+            assert!((back.b - 0.5).abs() < 1e-4, "{:?}", gamut);
+        }
+
+        let bright = xyz(2.0, 2.0, 2.0).to_oklab();
+        assert!(bright.l > 1.0);
+        assert!(bright.to_xyz().y > 1.99);
+        assert!(oklab(1.5, 0.0, 0.0).to_oklch().to_oklab().to_xyz().y > 1.0);
+    }
+
+    #[test]
+    fn clips_into_rgb() {
+        assert_eq!(
+            linear_rgb(4.0, 4.0, 4.0, Gamut::Srgb).to_rgb(),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            linear_rgb(1.0, -1.0, 0.0, Gamut::Srgb).to_rgb(),
+            rgb(255, 0, 0)
+        );
+        assert_eq!(
+            rgb(23, 98, 119)
+                .to_xyz()
+                .to_linear_rgb(Gamut::DisplayP3)
+                .to_rgb(),
+            rgb(23, 98, 119)
+        );
+    }
+
+    #[test]
+    fn encodes_pq() {
+        assert_eq!(pq_encode(0.0), pq_encode(-1.0));
+        assert_eq!(pq_encode(20000.0), 1.0);
+
+        for nits in [0.1, 1.0, 100.0, 203.0, 1000.0, 4000.0] {
+            let decoded = pq_decode(pq_encode(nits));
+            assert!((decoded - nits).abs() / nits < 1e-3, "{} {}", nits, decoded);
+        }
+
+        // Published code values of 10-bit PQ.
+        assert_eq!((pq_encode(100.0) * 1023.0).round(), 520.0);
+        assert_eq!((pq_encode(1000.0) * 1023.0).round(), 769.0);
+    }
+
+    #[test]
+    fn encodes_hlg() {
+        for light in [0.001, 0.05, 1.0 / 12.0, 0.2, 0.5, 1.0] {
+            let decoded = hlg_decode(hlg_encode(light));
+            assert!(
+                (decoded - light).abs() / light < 1e-3,
+                "{} {}",
+                light,
+                decoded
+            );
+        }
+
+        // Both halves of the curve meet.
+        assert!((hlg_decode(0.5 + 1e-4) - hlg_decode(0.5)).abs() < 1e-4);
+        assert_eq!(hlg_encode(2.0), hlg_encode(1.0));
+    }
+
+    #[test]
+    fn encodes_colors() {
+        let color = linear_rgb(2.0, 0.5, 0.1, Gamut::Srgb);
+
+        for back in [
+            LinearRGB::from_pq(color.to_pq()),
+            LinearRGB::from_hlg(color.to_hlg()),
+        ] {
+            let back = back.to_gamut(Gamut::Srgb);
+
+            assert_eq!(back.gamut, Gamut::Srgb);
+            assert!((back.r - 2.0).abs() < 1e-2, "{:?}", back);
+            assert!((back.g - 0.5).abs() < 1e-2, "{:?}", back);
+            assert!((back.b - 0.1).abs() < 1e-2, "{:?}", back);
+        }
+
+        // HLG runs out of room before PQ does.
+        let highlight = linear_rgb(10.0, 10.0, 10.0, Gamut::Rec2020);
+        assert!(LinearRGB::from_hlg(highlight.to_hlg()).r < 4.0);
+        assert!((LinearRGB::from_pq(highlight.to_pq()).r - 10.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn tonemaps_highlights() {
+        for operator in [Tonemap::Reinhard, Tonemap::AcesFilmic] {
+            let grey = |value: f32| {
+                linear_rgb(value, value, value, Gamut::Srgb)
+                    .tonemap(operator)
+                    .r
+                    .as_u8()
+            };
+
+            assert_eq!(grey(0.0), 0);
+            assert!(
+                grey(0.5) < grey(1.0) && grey(1.0) < grey(4.0),
+                "{:?}",
+                operator
+            );
+            assert!(grey(4.0) < grey(1000.0), "{:?}", operator);
+            assert!(grey(1e6) >= 254, "{:?}", operator);
+        }
+
+        // Reinhard keeps hues, where ACES saturates channels one by one.
+        let orange = linear_rgb(1.6, 0.4, 0.0, Gamut::Srgb);
+        let expected = linear_rgb(1.0, 0.25, 0.0, Gamut::Srgb)
+            .to_rgb()
+            .to_oklch()
+            .h;
+        let hue = |color: RGB| (color.to_oklch().h - expected).abs();
+        assert!(hue(orange.tonemap(Tonemap::Reinhard)) < 1.0);
+        assert!(hue(orange.tonemap(Tonemap::AcesFilmic)) > 1.0);
+        assert_eq!(
+            linear_rgb(-1.0, 0.0, 0.0, Gamut::Srgb).tonemap(Tonemap::AcesFilmic),
+            rgb(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn detects_infinite_values() {
+        assert!(linear_rgb(1e6, 0.0, -1e6, Gamut::Srgb).is_finite());
+        assert!(!linear_rgb(f32::INFINITY, 0.0, 0.0, Gamut::Srgb).is_finite());
+        assert!(!xyz(f32::NAN, 0.0, 0.0).is_finite());
+        assert!(!oklab(0.5, f32::NAN, 0.0).to_oklch().is_finite());
+        assert!(rgb(255, 0, 0).to_lab().is_finite());
+    }
+}
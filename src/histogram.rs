@@ -0,0 +1,269 @@
+use super::{oklab::normalize_hue, Color};
+
+// Colors with an OKLCH chroma below this are counted as greys, matching the
+// threshold `describe()` uses.
+const GREY_CHROMA: f32 = 0.02;
+
+/// Bins `colors` by their OKLCH hue and lightness, to find the hues that
+/// dominate a picture or check how much of it is taken by brand colors.
+///
+/// The color wheel is cut into `hue_bins` equal slices starting at 0°, and
+/// lightness into `lightness_bins` equal bands. Greys have no meaningful hue
+/// and are counted separately. Panics if either bin count is zero.
+///
+/// # Example
+/// ```
+/// use farver::{hue_histogram, rgb};
+///
+/// let mut pixels = vec![rgb(230, 25, 25); 6];
+/// pixels.extend([rgb(25, 25, 230); 3]);
+/// pixels.push(rgb(128, 128, 128));
+///
+/// let histogram = hue_histogram(&pixels, 12, 1);
+/// let dominant = histogram.dominant();
+///
+/// assert_eq!(dominant.len(), 2);
+/// assert_eq!(format!("{:.0}", dominant[0].hue), "28");
+/// assert_eq!(dominant[0].coverage, 0.6);
+/// assert_eq!(histogram.grey_coverage(), 0.1);
+/// ```
+pub fn hue_histogram<T: Color + Copy>(
+    colors: &[T],
+    hue_bins: usize,
+    lightness_bins: usize,
+) -> HueHistogram {
+    assert!(hue_bins > 0 && lightness_bins > 0, "Invalid bin count");
+
+    let mut histogram = HueHistogram {
+        hue_bins,
+        lightness_bins,
+        counts: vec![0; hue_bins * lightness_bins],
+        greys: vec![0; lightness_bins],
+        hues: vec![(0.0, 0.0); hue_bins],
+        len: colors.len(),
+    };
+
+    for &color in colors {
+        let oklch = color.to_oklch();
+        let lightness =
+            ((oklch.l.clamp(0.0, 1.0) * lightness_bins as f32) as usize).min(lightness_bins - 1);
+
+        if oklch.c < GREY_CHROMA {
+            histogram.greys[lightness] += 1;
+            continue;
+        }
+
+        let hue = ((oklch.h / 360.0 * hue_bins as f32) as usize).min(hue_bins - 1);
+        histogram.counts[hue * lightness_bins + lightness] += 1;
+
+        let (cos, sin) = &mut histogram.hues[hue];
+        *cos += oklch.h.to_radians().cos();
+        *sin += oklch.h.to_radians().sin();
+    }
+
+    histogram
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct that holds how many colors fall in each hue and lightness bin,
+/// as computed by `hue_histogram()`. Coverages are fractions of every color
+/// binned, between `0.0` and `1.0`.
+pub struct HueHistogram {
+    hue_bins: usize,
+    lightness_bins: usize,
+    counts: Vec<usize>,
+    greys: Vec<usize>,
+
+    // the sums of the hue vectors of every bin, to average hues around the wheel
+    hues: Vec<(f32, f32)>,
+
+    len: usize,
+}
+
+impl HueHistogram {
+    /// Returns the number of colors binned.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no color was binned.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of hue bins.
+    pub fn hue_bins(&self) -> usize {
+        self.hue_bins
+    }
+
+    /// Returns the number of lightness bins.
+    pub fn lightness_bins(&self) -> usize {
+        self.lightness_bins
+    }
+
+    /// Returns the range of hues of a bin, in degrees. The start is inclusive
+    /// and the end exclusive. Panics if `hue_bin` is out of bounds.
+    pub fn hue_range(&self, hue_bin: usize) -> (f32, f32) {
+        assert!(hue_bin < self.hue_bins, "Invalid bin index");
+
+        let width = 360.0 / self.hue_bins as f32;
+        (hue_bin as f32 * width, (hue_bin + 1) as f32 * width)
+    }
+
+    /// Returns the number of non-grey colors falling in both bins. Panics if
+    /// either bin is out of bounds.
+    pub fn count(&self, hue_bin: usize, lightness_bin: usize) -> usize {
+        assert!(
+            hue_bin < self.hue_bins && lightness_bin < self.lightness_bins,
+            "Invalid bin index"
+        );
+
+        self.counts[hue_bin * self.lightness_bins + lightness_bin]
+    }
+
+    /// Returns the fraction of colors falling in a hue bin, greys excluded.
+    /// Panics if `hue_bin` is out of bounds.
+    pub fn hue_coverage(&self, hue_bin: usize) -> f32 {
+        let count = (0..self.lightness_bins)
+            .map(|lightness_bin| self.count(hue_bin, lightness_bin))
+            .sum();
+
+        self.fraction(count)
+    }
+
+    /// Returns the fraction of colors falling in a lightness bin, greys
+    /// included. Panics if `lightness_bin` is out of bounds.
+    pub fn lightness_coverage(&self, lightness_bin: usize) -> f32 {
+        let count = (0..self.hue_bins)
+            .map(|hue_bin| self.count(hue_bin, lightness_bin))
+            .sum::<usize>()
+            + self.greys[lightness_bin];
+
+        self.fraction(count)
+    }
+
+    /// Returns the fraction of colors that are greys.
+    pub fn grey_coverage(&self) -> f32 {
+        self.fraction(self.greys.iter().sum())
+    }
+
+    /// Returns every hue bin holding colors, from the most covered to the
+    /// least covered. Bins with the same coverage are ordered by hue.
+    pub fn dominant(&self) -> Vec<DominantHue> {
+        let mut dominant: Vec<DominantHue> = (0..self.hue_bins)
+            .filter(|&hue_bin| self.hue_coverage(hue_bin) > 0.0)
+            .map(|hue_bin| {
+                let (cos, sin) = self.hues[hue_bin];
+
+                DominantHue {
+                    bin: hue_bin,
+                    hue: normalize_hue(sin.atan2(cos).to_degrees()),
+                    coverage: self.hue_coverage(hue_bin),
+                }
+            })
+            .collect();
+
+        dominant.sort_by(|lhs, rhs| rhs.coverage.total_cmp(&lhs.coverage));
+        dominant
+    }
+
+    fn fraction(&self, count: usize) -> f32 {
+        if self.len == 0 {
+            0.0
+        } else {
+            count as f32 / self.len as f32
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that represents a hue bin of a `HueHistogram`, as returned by
+/// `HueHistogram::dominant()`.
+pub struct DominantHue {
+    // index of the hue bin
+    pub bin: usize,
+
+    // the average OKLCH hue of the colors in the bin, in degrees
+    pub hue: f32,
+
+    // the fraction of every color binned falling in the bin
+    pub coverage: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, hue_histogram, oklch, rgb, RGB};
+
+    fn pixels() -> Vec<RGB> {
+        let mut pixels = vec![rgb(255, 0, 0); 4];
+        pixels.extend([rgb(128, 0, 0); 2]);
+        pixels.extend([rgb(0, 0, 255); 3]);
+        pixels.extend([rgb(0, 0, 0), rgb(255, 255, 255), rgb(128, 128, 128)]);
+        pixels
+    }
+
+    #[test]
+    fn bins_by_hue_and_lightness() {
+        let histogram = hue_histogram(&pixels(), 6, 2);
+
+        assert_eq!(histogram.len(), 12);
+        assert_eq!(histogram.hue_bins(), 6);
+        assert_eq!(histogram.lightness_bins(), 2);
+
+        // Red falls in 0-60°, and blue in 240-300°.
+        assert_eq!(histogram.count(0, 0), 2);
+        assert_eq!(histogram.count(0, 1), 4);
+        assert_eq!(histogram.count(4, 0), 3);
+        assert_eq!(histogram.hue_coverage(0), 0.5);
+        assert_eq!(histogram.hue_coverage(4), 0.25);
+        assert_eq!(histogram.hue_coverage(2), 0.0);
+        assert_eq!(histogram.hue_range(4), (240.0, 300.0));
+    }
+
+    #[test]
+    fn counts_greys_separately() {
+        let histogram = hue_histogram(&pixels(), 6, 2);
+
+        assert_eq!(histogram.grey_coverage(), 0.25);
+        assert_eq!(histogram.lightness_coverage(0), 0.5);
+        assert_eq!(histogram.lightness_coverage(1), 0.5);
+        assert!(hue_histogram(&[hsl(0, 0, 50)], 6, 1).dominant().is_empty());
+    }
+
+    #[test]
+    fn reports_dominant_hues() {
+        let dominant = hue_histogram(&pixels(), 6, 2).dominant();
+
+        assert_eq!(dominant.len(), 2);
+        assert_eq!((dominant[0].bin, dominant[0].coverage), (0, 0.5));
+        assert_eq!((dominant[1].bin, dominant[1].coverage), (4, 0.25));
+        assert!((dominant[1].hue - 264.05).abs() < 0.01);
+    }
+
+    #[test]
+    fn averages_hues_around_the_wheel() {
+        let colors = [
+            oklch(0.6, 0.15, 350.0).to_rgb(),
+            oklch(0.6, 0.15, 10.0).to_rgb(),
+        ];
+        let dominant = hue_histogram(&colors, 1, 1).dominant();
+
+        assert_eq!(dominant.len(), 1);
+        assert!(dominant[0].hue < 2.0 || dominant[0].hue > 358.0);
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        let histogram = hue_histogram::<RGB>(&[], 6, 2);
+
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.grey_coverage(), 0.0);
+        assert!(histogram.dominant().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_bins() {
+        hue_histogram(&pixels(), 0, 1);
+    }
+}
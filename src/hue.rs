@@ -0,0 +1,128 @@
+use super::{deg, oklab::normalize_hue, oklch, Color, RGB, RGBA};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The cylindrical color spaces a hue can be read from. The same color has a
+/// slightly different hue in each of them.
+pub enum HueSpace {
+    /// The hue of the HSL color model, in whole degrees.
+    HSL,
+
+    /// The hue of CIE LCH, derived from CIELAB.
+    LCH,
+
+    /// The hue of OKLCH, derived from OKLab.
+    OKLCH,
+}
+
+// Returns the hue of `color` in degrees, between 0° and 360°. Achromatic colors
+// have no meaningful hue, which is reported as 0° like `to_oklch()` does.
+pub(crate) fn hue<T: Color>(color: T, space: HueSpace) -> f32 {
+    match space {
+        HueSpace::HSL => color.to_hsl().h.degrees() as f32,
+        HueSpace::LCH => {
+            let lab = color.to_lab();
+
+            if lab.a.hypot(lab.b) < 1e-2 {
+                0.0
+            } else {
+                normalize_hue(lab.b.atan2(lab.a).to_degrees())
+            }
+        }
+        HueSpace::OKLCH => color.to_oklch().h,
+    }
+}
+
+// Returns the smallest angle between two hues, from 0° to 180°.
+pub(crate) fn hue_distance<T: Color, U: Color>(lhs: T, rhs: U, space: HueSpace) -> f32 {
+    let distance = (hue(lhs, space) - hue(rhs, space)).abs() % 360.0;
+
+    distance.min(360.0 - distance)
+}
+
+// Rotates the hue of `color` by `degrees` in the given space, keeping its
+// alpha value. HSL works in whole degrees, so the rotation is rounded there.
+// Colors falling outside of the sRGB gamut are clipped.
+pub(crate) fn rotate_hue<T: Color>(color: T, degrees: f32, space: HueSpace) -> RGBA {
+    let rgba = color.to_rgba();
+
+    let RGB { r, g, b } = match space {
+        HueSpace::HSL => return rgba.spin(deg(degrees.round() as i32)),
+        HueSpace::LCH => {
+            let (sin, cos) = degrees.to_radians().sin_cos();
+            let lab = rgba.to_lab();
+
+            super::lab(lab.l, lab.a * cos - lab.b * sin, lab.a * sin + lab.b * cos).to_rgb()
+        }
+        HueSpace::OKLCH => {
+            let lch = rgba.to_oklch();
+
+            oklch(lch.l, lch.c, lch.h + degrees).to_rgb()
+        }
+    };
+
+    RGBA { r, g, b, a: rgba.a }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, rgb, Color, HueSpace};
+
+    #[test]
+    fn can_compute_hue_distance() {
+        assert_eq!(
+            hsl(10, 100, 50).hue_distance(hsl(350, 100, 50), HueSpace::HSL),
+            20.0
+        );
+        assert_eq!(
+            hsl(0, 100, 50).hue_distance(hsl(180, 100, 50), HueSpace::HSL),
+            180.0
+        );
+        assert_eq!(
+            hsl(90, 100, 50).hue_distance(hsl(300, 100, 50), HueSpace::HSL),
+            150.0
+        );
+        assert_eq!(
+            hsl(90, 100, 50).hue_distance(hsl(90, 50, 20), HueSpace::HSL),
+            0.0
+        );
+    }
+
+    #[test]
+    fn depends_on_space() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(red.hue_distance(blue, HueSpace::HSL), 120.0);
+        assert_eq!(
+            format!("{:.2}", red.hue_distance(blue, HueSpace::OKLCH)),
+            "125.18"
+        );
+        assert_eq!(
+            format!("{:.0}", red.hue_distance(blue, HueSpace::LCH)),
+            "99"
+        );
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let tomato = rgb(255, 99, 71);
+        let teal = rgb(23, 98, 119);
+
+        for space in [HueSpace::HSL, HueSpace::LCH, HueSpace::OKLCH] {
+            assert_eq!(
+                tomato.hue_distance(teal, space),
+                teal.hue_distance(tomato, space)
+            );
+            assert!(tomato.hue_distance(teal, space) <= 180.0);
+        }
+    }
+
+    #[test]
+    fn greys_have_no_hue() {
+        let grey = rgb(128, 128, 128);
+
+        for space in [HueSpace::HSL, HueSpace::LCH, HueSpace::OKLCH] {
+            assert_eq!(grey.hue_distance(rgb(255, 255, 255), space), 0.0);
+        }
+    }
+}
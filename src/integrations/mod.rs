@@ -3,4 +3,4 @@ mod bevy;
 #[cfg(feature = "palette")]
 mod palette;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
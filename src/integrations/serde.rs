@@ -1,6 +1,6 @@
 use serde::de::Error;
 use serde::{de::Visitor, Deserialize, Serialize, Serializer};
-use std::num::ParseIntError;
+use std::{borrow::Cow, num::ParseIntError};
 
 use crate::Color;
 
@@ -160,6 +160,487 @@ impl<'de> Deserialize<'de> for crate::HSLA {
     }
 }
 
+// Builds the color types back from the RGBA colors the helper modules below
+// read, kept in a private module so that it can't be implemented elsewhere.
+mod private {
+    use crate::{Color, HSL, HSLA, RGB, RGBA};
+
+    pub trait FromRgba: Color + Copy {
+        fn from_rgba(rgba: RGBA) -> Self;
+    }
+
+    impl FromRgba for RGB {
+        fn from_rgba(rgba: RGBA) -> Self {
+            rgba.to_rgb()
+        }
+    }
+
+    impl FromRgba for RGBA {
+        fn from_rgba(rgba: RGBA) -> Self {
+            rgba
+        }
+    }
+
+    impl FromRgba for HSL {
+        fn from_rgba(rgba: RGBA) -> Self {
+            rgba.to_hsl()
+        }
+    }
+
+    impl FromRgba for HSLA {
+        fn from_rgba(rgba: RGBA) -> Self {
+            rgba.to_hsla()
+        }
+    }
+}
+
+// Rounds an alpha to 3 decimals, the fewest that still tell every one of its
+// 256 levels apart.
+fn alpha(rgba: crate::RGBA) -> f32 {
+    (rgba.a.as_f32() * 1000.0).round() / 1000.0
+}
+
+// Builds an RGBA color from its channels, rejecting alphas out of range
+// rather than panicking.
+fn from_channels<E: Error>(r: u8, g: u8, b: u8, a: f32) -> Result<crate::RGBA, E> {
+    if (0.0..=1.0).contains(&a) {
+        Ok(crate::rgba(r, g, b, a))
+    } else {
+        Err(E::invalid_value(
+            serde::de::Unexpected::Float(a as f64),
+            &"an alpha between 0 and 1",
+        ))
+    }
+}
+
+/// Serializes colors as hex strings, like `"#fa8072"`, or `"#fa807280"` for
+/// colors with alpha. Deserializing also accepts the short `"#f87"` and
+/// `"#f878"` forms.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde::hex")]
+///     accent: farver::RGB,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(r##"{"accent": "#f87"}"##).unwrap();
+///
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), r##"{"accent":"#ff8877"}"##);
+/// ```
+pub mod hex {
+    use super::private::FromRgba;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: FromRgba, S: Serializer>(
+        color: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, T: FromRgba, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        match value
+            .starts_with('#')
+            .then(|| crate::css::parse_color(&value))
+            .flatten()
+        {
+            Some(rgba) => Ok(T::from_rgba(rgba)),
+            None => Err(D::Error::invalid_value(
+                serde::de::Unexpected::Str(&value),
+                &"a hex color",
+            )),
+        }
+    }
+}
+
+/// Serializes colors as CSS strings, like `"rgb(250, 128, 114)"` or
+/// `"hsl(6, 93%, 71%)"`. Deserializing accepts any CSS color, such as hex
+/// colors, named colors or `oklch()`.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde::css")]
+///     accent: farver::RGBA,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(r#"{"accent": "salmon"}"#).unwrap();
+///
+/// assert_eq!(
+///     serde_json::to_string(&theme).unwrap(),
+///     r#"{"accent":"rgba(250, 128, 114, 1.00)"}"#
+/// );
+/// ```
+pub mod css {
+    use super::private::FromRgba;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: FromRgba, S: Serializer>(
+        color: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_css())
+    }
+
+    pub fn deserialize<'de, T: FromRgba, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        match crate::css::parse_color(&value) {
+            Some(rgba) => Ok(T::from_rgba(rgba)),
+            None => Err(D::Error::invalid_value(
+                serde::de::Unexpected::Str(&value),
+                &"a CSS color",
+            )),
+        }
+    }
+}
+
+/// Serializes colors as `[r, g, b, a]` arrays of their RGBA channels, the
+/// alpha being between `0.0` and `1.0`, rounded to 3 decimals. Deserializing also accepts `[r, g, b]`
+/// arrays of opaque colors.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde::tuple")]
+///     accent: farver::RGB,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(r#"{"accent": [250, 128, 114]}"#).unwrap();
+///
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), r#"{"accent":[250,128,114,1.0]}"#);
+/// ```
+pub mod tuple {
+    use super::{alpha, from_channels, private::FromRgba};
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserializer, Serializer,
+    };
+    use std::{fmt, marker::PhantomData};
+
+    pub fn serialize<T: FromRgba, S: Serializer>(
+        color: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let rgba = color.to_rgba();
+        let mut tuple = serializer.serialize_tuple(4)?;
+
+        tuple.serialize_element(&rgba.r.as_u8())?;
+        tuple.serialize_element(&rgba.g.as_u8())?;
+        tuple.serialize_element(&rgba.b.as_u8())?;
+        tuple.serialize_element(&alpha(rgba))?;
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, T: FromRgba, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_seq(TupleVisitor(PhantomData))
+    }
+
+    struct TupleVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromRgba> Visitor<'de> for TupleVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an array of 3 or 4 channels")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            let mut channel = |i: usize| {
+                seq.next_element::<u8>()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))
+            };
+            let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+            let a = seq.next_element::<f32>()?.unwrap_or(1.0);
+
+            if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                return Err(A::Error::invalid_length(5, &self));
+            }
+
+            from_channels(r, g, b, a).map(T::from_rgba)
+        }
+    }
+}
+
+/// Serializes colors as maps of their RGBA channels, like
+/// `{"r": 250, "g": 128, "b": 114, "a": 1.0}`, the alpha being between `0.0`
+/// and `1.0`, rounded to 3 decimals. Deserializing also accepts maps without an alpha, for opaque
+/// colors.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde::map")]
+///     accent: farver::RGBA,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(r#"{"accent": {"r": 250, "g": 128, "b": 114}}"#).unwrap();
+///
+/// assert_eq!(
+///     serde_json::to_string(&theme).unwrap(),
+///     r#"{"accent":{"r":250,"g":128,"b":114,"a":1.0}}"#
+/// );
+/// ```
+pub mod map {
+    use super::{alpha, from_channels, private::FromRgba};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Channels {
+        r: u8,
+        g: u8,
+        b: u8,
+        #[serde(default = "opaque")]
+        a: f32,
+    }
+
+    fn opaque() -> f32 {
+        1.0
+    }
+
+    pub fn serialize<T: FromRgba, S: Serializer>(
+        color: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let rgba = color.to_rgba();
+
+        Channels {
+            r: rgba.r.as_u8(),
+            g: rgba.g.as_u8(),
+            b: rgba.b.as_u8(),
+            a: alpha(rgba),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: FromRgba, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let Channels { r, g, b, a } = Channels::deserialize(deserializer)?;
+        from_channels(r, g, b, a).map(T::from_rgba)
+    }
+}
+
+// Serializes the colors of palettes and gradients as short hex strings, with
+// an alpha only when they are translucent, and deserializes any CSS color.
+mod color {
+    use crate::{Color, RGBA};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &RGBA, serializer: S) -> Result<S::Ok, S::Error> {
+        if color.a.as_u8() == 255 {
+            serializer.serialize_str(&color.to_rgb().to_hex())
+        } else {
+            serializer.serialize_str(&color.to_hex())
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RGBA, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        crate::css::parse_color(&value).ok_or_else(|| {
+            D::Error::invalid_value(serde::de::Unexpected::Str(&value), &"a CSS color")
+        })
+    }
+}
+
+// The variants mirror the ones of `ColorSpace`, under their CSS names.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::ColorSpace")]
+enum ColorSpaceDef {
+    #[serde(rename = "srgb")]
+    SRGB,
+    #[serde(rename = "srgb-linear")]
+    LinearRGB,
+    #[serde(rename = "hsl")]
+    HSL,
+    #[serde(rename = "lab")]
+    LAB,
+    #[serde(rename = "lch")]
+    LCH,
+    #[serde(rename = "oklab")]
+    OKLAB,
+    #[serde(rename = "oklch")]
+    OKLCH,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::HueInterpolation", rename_all = "kebab-case")]
+enum HueInterpolationDef {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::Easing", rename_all = "kebab-case")]
+enum EasingDef {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+    Steps(u32),
+}
+
+macro_rules! impl_remote {
+    ($x:ident, $def:ident) => {
+        impl Serialize for crate::$x {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $def::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for crate::$x {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $def::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+impl_remote!(ColorSpace, ColorSpaceDef);
+impl_remote!(HueInterpolation, HueInterpolationDef);
+impl_remote!(Easing, EasingDef);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::GradientStop")]
+struct GradientStopDef {
+    position: f32,
+    #[serde(with = "color")]
+    color: crate::RGBA,
+    #[serde(default = "linear", with = "EasingDef")]
+    easing: crate::Easing,
+}
+
+fn linear() -> crate::Easing {
+    crate::Easing::Linear
+}
+
+impl_remote!(GradientStop, GradientStopDef);
+
+// Palettes are stored as lists of their colors, each with its name and role
+// if it has them, like `[{"name": "salmon", "color": "#fa8072"}]`.
+#[derive(Serialize, Deserialize)]
+struct PaletteEntry<'a> {
+    #[serde(default, borrow, skip_serializing_if = "Option::is_none")]
+    name: Option<Cow<'a, str>>,
+    #[serde(with = "color")]
+    color: crate::RGBA,
+    #[serde(default, borrow, skip_serializing_if = "Option::is_none")]
+    role: Option<Cow<'a, str>>,
+}
+
+impl Serialize for crate::Palette {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_named().enumerate().map(|(i, (name, color))| {
+            PaletteEntry {
+                name: name.map(Cow::from),
+                color,
+                role: self.role(i).map(Cow::from),
+            }
+        }))
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Palette {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut palette = crate::Palette::new();
+
+        for entry in Vec::<PaletteEntry>::deserialize(deserializer)? {
+            match entry.name {
+                Some(name) => palette.push_named(name.into_owned(), entry.color),
+                None => palette.push(entry.color),
+            }
+
+            if let Some(role) = entry.role {
+                palette.set_role(palette.len() - 1, role.into_owned());
+            }
+        }
+
+        Ok(palette)
+    }
+}
+
+// Gradients are stored with their interpolation, and their stops in order,
+// like `{"space": "oklab", "hue": "shorter", "stops": [...]}`.
+#[derive(Serialize, Deserialize)]
+struct GradientDef {
+    #[serde(default = "oklab")]
+    space: crate::ColorSpace,
+    #[serde(default = "shorter")]
+    hue: crate::HueInterpolation,
+    stops: Vec<crate::GradientStop>,
+}
+
+fn oklab() -> crate::ColorSpace {
+    crate::ColorSpace::OKLAB
+}
+
+fn shorter() -> crate::HueInterpolation {
+    crate::HueInterpolation::Shorter
+}
+
+impl Serialize for crate::Gradient {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GradientDef {
+            space: self.color_space(),
+            hue: self.hue_arc(),
+            stops: self.stops().to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Gradient {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let definition = GradientDef::deserialize(deserializer)?;
+        let mut gradient = crate::Gradient::new()
+            .space(definition.space)
+            .hue_interpolation(definition.hue);
+
+        for stop in definition.stops {
+            if !stop.position.is_finite() {
+                return Err(D::Error::invalid_value(
+                    serde::de::Unexpected::Float(stop.position as f64),
+                    &"a finite stop position",
+                ));
+            }
+
+            let index = gradient.add_stop(stop.position, stop.color);
+            gradient.set_easing(index, stop.easing);
+        }
+
+        Ok(gradient)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Deserialize;
@@ -196,4 +677,107 @@ mod tests {
             }
         )
     }
+
+    #[derive(serde::Serialize, Deserialize, Debug, PartialEq)]
+    struct Formats {
+        #[serde(with = "crate::serde::hex")]
+        hex: crate::RGBA,
+        #[serde(with = "crate::serde::css")]
+        css: crate::HSL,
+        #[serde(with = "crate::serde::tuple")]
+        tuple: crate::RGBA,
+        #[serde(with = "crate::serde::map")]
+        map: crate::RGB,
+    }
+
+    #[test]
+    fn round_trips_formats() {
+        let formats = Formats {
+            hex: crate::rgba(250, 128, 114, 0.5),
+            css: crate::hsl(210, 50, 40),
+            tuple: crate::rgba(1, 2, 3, 0.2),
+            map: crate::rgb(4, 5, 6),
+        };
+        let json = serde_json::to_string(&formats).unwrap();
+
+        assert_eq!(
+            json,
+            r##"{"hex":"#fa807280","css":"hsl(210, 50%, 40%)","tuple":[1,2,3,0.2],"map":{"r":4,"g":5,"b":6,"a":1.0}}"##
+        );
+        assert_eq!(serde_json::from_str::<Formats>(&json).unwrap(), formats);
+    }
+
+    #[test]
+    fn rejects_invalid_formats() {
+        let parse = |json: &str| serde_json::from_str::<Formats>(json).is_err();
+        let valid = r##"{"hex":"#fff","css":"red","tuple":[1,2,3],"map":{"r":4,"g":5,"b":6}}"##;
+
+        assert!(!parse(valid));
+        assert!(parse(&valid.replace("#fff", "red")));
+        assert!(parse(&valid.replace("\"red\"", "\"reddish\"")));
+        assert!(parse(&valid.replace("[1,2,3]", "[1,2]")));
+        assert!(parse(&valid.replace("[1,2,3]", "[1,2,3,2.0]")));
+        assert!(parse(&valid.replace("[1,2,3]", "[1,2,3,1,0]")));
+        assert!(parse(&valid.replace("\"b\":6", "\"b\":600")));
+    }
+
+    #[test]
+    fn round_trips_palettes() {
+        let mut palette = crate::Palette::new();
+        palette.push_named("accent", crate::rgb(250, 128, 114));
+        palette.push(crate::rgba(0, 0, 0, 0.2));
+        palette.set_role(1, "shadow");
+
+        let json = serde_json::to_string(&palette).unwrap();
+
+        assert_eq!(
+            json,
+            r##"[{"name":"accent","color":"#fa8072"},{"color":"#00000033","role":"shadow"}]"##
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::Palette>(&json).unwrap(),
+            palette
+        );
+        assert!(serde_json::from_str::<crate::Palette>(r#"[{"color":"nope"}]"#).is_err());
+    }
+
+    #[test]
+    fn round_trips_gradients() {
+        let mut gradient =
+            crate::Gradient::from_colors(&[crate::rgb(255, 0, 0), crate::rgb(0, 0, 255)])
+                .space(crate::ColorSpace::OKLCH)
+                .hue_interpolation(crate::HueInterpolation::Longer);
+        gradient.add_stop(0.5, crate::rgb(255, 255, 255));
+        gradient.set_easing(0, crate::Easing::Steps(4));
+        gradient.set_easing(1, crate::Easing::CubicBezier(0.1, 0.2, 0.3, 0.4));
+
+        let json = serde_json::to_string(&gradient).unwrap();
+
+        assert_eq!(
+            json,
+            concat!(
+                r##"{"space":"oklch","hue":"longer","stops":["##,
+                r##"{"position":0.0,"color":"#ff0000","easing":{"steps":4}},"##,
+                r##"{"position":0.5,"color":"#ffffff","easing":{"cubic-bezier":[0.1,0.2,0.3,0.4]}},"##,
+                r##"{"position":1.0,"color":"#0000ff","easing":"linear"}]}"##
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::Gradient>(&json).unwrap(),
+            gradient
+        );
+    }
+
+    #[test]
+    fn fills_in_gradient_defaults() {
+        let gradient: crate::Gradient = serde_json::from_str(
+            r##"{"stops":[{"position":1.0,"color":"blue"},{"position":0.0,"color":"red"}]}"##,
+        )
+        .unwrap();
+
+        assert_eq!(gradient.color_space(), crate::ColorSpace::OKLAB);
+        assert_eq!(gradient.hue_arc(), crate::HueInterpolation::Shorter);
+        assert_eq!(gradient.stops()[0].color, crate::rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.stops()[1].easing, crate::Easing::Linear);
+    }
 }
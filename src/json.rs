@@ -0,0 +1,411 @@
+use std::fmt::{self, Write};
+
+// A JSON value, for the file formats colors are exchanged in. Objects keep
+// their keys in order, so files are written the way they were read.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // Parses a JSON document, or returns `None` if it is not valid JSON.
+    pub(crate) fn parse(input: &str) -> Option<Json> {
+        let mut parser = Parser {
+            input: input.as_bytes(),
+            index: 0,
+        };
+
+        let value = parser.value(0)?;
+        parser.whitespace();
+
+        (parser.index == input.len()).then_some(value)
+    }
+
+    // Returns the value of `key` if `self` is an object holding it.
+    pub(crate) fn get(&self, key: &str) -> Option<&Json> {
+        self.as_object()?
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_object(&self) -> Option<&[(String, Json)]> {
+        match self {
+            Json::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    // Builds an object from its entries, in order.
+    pub(crate) fn object<K: Into<String>>(entries: impl IntoIterator<Item = (K, Json)>) -> Json {
+        Json::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn string(value: impl Into<String>) -> Json {
+        Json::String(value.into())
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = |out: &mut String, depth: usize| {
+            out.push('\n');
+            out.push_str(&"  ".repeat(depth));
+        };
+
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Number(value) if value.is_finite() => {
+                let _ = write!(out, "{}", value);
+            }
+            Json::Number(_) => out.push_str("null"),
+            Json::String(value) => write_string(out, value),
+            Json::Array(values) if values.is_empty() => out.push_str("[]"),
+            Json::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    indent(out, depth + 1);
+                    value.write(out, depth + 1);
+                }
+                indent(out, depth);
+                out.push(']');
+            }
+            Json::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Json::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    indent(out, depth + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                }
+                indent(out, depth);
+                out.push('}');
+            }
+        }
+    }
+}
+
+// Writes the document indented by two spaces, with a trailing newline.
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+
+        writeln!(f, "{}", out)
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+// Deeply nested documents are rejected rather than overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    input: &'a [u8],
+    index: usize,
+}
+
+impl Parser<'_> {
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.input.get(self.index) {
+            self.index += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.whitespace();
+
+        if self.input.get(self.index) == Some(&byte) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Json) -> Option<Json> {
+        let end = self.index + keyword.len();
+
+        if self.input.get(self.index..end)? == keyword.as_bytes() {
+            self.index = end;
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Option<Json> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        self.whitespace();
+
+        match self.input.get(self.index)? {
+            b'n' => self.keyword("null", Json::Null),
+            b't' => self.keyword("true", Json::Bool(true)),
+            b'f' => self.keyword("false", Json::Bool(false)),
+            b'"' => self.string().map(Json::String),
+            b'[' => {
+                self.index += 1;
+                let mut values = Vec::new();
+
+                if self.eat(b']') {
+                    return Some(Json::Array(values));
+                }
+
+                loop {
+                    values.push(self.value(depth + 1)?);
+
+                    if self.eat(b']') {
+                        return Some(Json::Array(values));
+                    }
+                    if !self.eat(b',') {
+                        return None;
+                    }
+                }
+            }
+            b'{' => {
+                self.index += 1;
+                let mut entries = Vec::new();
+
+                if self.eat(b'}') {
+                    return Some(Json::Object(entries));
+                }
+
+                loop {
+                    self.whitespace();
+                    let key = self.string()?;
+
+                    if !self.eat(b':') {
+                        return None;
+                    }
+                    entries.push((key, self.value(depth + 1)?));
+
+                    if self.eat(b'}') {
+                        return Some(Json::Object(entries));
+                    }
+                    if !self.eat(b',') {
+                        return None;
+                    }
+                }
+            }
+            _ => self.number(),
+        }
+    }
+
+    fn number(&mut self) -> Option<Json> {
+        let start = self.index;
+
+        while let Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') = self.input.get(self.index)
+        {
+            self.index += 1;
+        }
+
+        let token = std::str::from_utf8(&self.input[start..self.index]).ok()?;
+
+        // JSON is stricter than Rust about where digits go
+        let digits = token.strip_prefix('-').unwrap_or(token);
+        if !digits.starts_with(|c: char| c.is_ascii_digit())
+            || (digits.starts_with('0') && digits[1..].starts_with(|c: char| c.is_ascii_digit()))
+            || digits.contains(".e")
+            || digits.contains(".E")
+            || digits.ends_with('.')
+        {
+            return None;
+        }
+
+        token.parse().ok().map(Json::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.input.get(self.index) != Some(&b'"') {
+            return None;
+        }
+        self.index += 1;
+
+        let mut value = String::new();
+
+        loop {
+            let start = self.index;
+            while !matches!(self.input.get(self.index)?, b'"' | b'\\') {
+                if self.input[self.index] < 0x20 {
+                    return None;
+                }
+                self.index += 1;
+            }
+
+            value.push_str(std::str::from_utf8(&self.input[start..self.index]).ok()?);
+
+            let byte = self.input[self.index];
+            self.index += 1;
+
+            if byte == b'"' {
+                return Some(value);
+            }
+
+            let escape = *self.input.get(self.index)?;
+            self.index += 1;
+
+            value.push(match escape {
+                b'"' => '"',
+                b'\\' => '\\',
+                b'/' => '/',
+                b'b' => '\u{8}',
+                b'f' => '\u{c}',
+                b'n' => '\n',
+                b'r' => '\r',
+                b't' => '\t',
+                b'u' => {
+                    let high = self.code_unit()?;
+
+                    if (0xd800..0xdc00).contains(&high) {
+                        if self.input.get(self.index..self.index + 2)? != b"\\u" {
+                            return None;
+                        }
+                        self.index += 2;
+
+                        let low = self.code_unit()?;
+                        if !(0xdc00..0xe000).contains(&low) {
+                            return None;
+                        }
+
+                        char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))?
+                    } else {
+                        char::from_u32(high)?
+                    }
+                }
+                _ => return None,
+            });
+        }
+    }
+
+    fn code_unit(&mut self) -> Option<u32> {
+        let digits = std::str::from_utf8(self.input.get(self.index..self.index + 4)?).ok()?;
+        self.index += 4;
+
+        u32::from_str_radix(digits, 16).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Json;
+
+    #[test]
+    fn can_parse_documents() {
+        let json =
+            Json::parse(r#" {"a": [1, -2.5e1, true, null], "b": {"c": "d\u00e9\n"}} "#).unwrap();
+
+        assert_eq!(
+            json.get("a").unwrap().as_array().unwrap(),
+            [
+                Json::Number(1.0),
+                Json::Number(-25.0),
+                Json::Bool(true),
+                Json::Null
+            ]
+        );
+        assert_eq!(
+            json.get("b").unwrap().get("c").unwrap().as_str(),
+            Some("dé\n")
+        );
+        assert_eq!(Json::parse(r#""\ud83c\udfa8""#), Some(Json::string("🎨")));
+        assert_eq!(json.get("c"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        for input in [
+            "",
+            "{",
+            "[1,]",
+            "{\"a\" 1}",
+            "01",
+            "1.",
+            ".5",
+            "tru",
+            "\"\\x\"",
+            "[1] 2",
+            "{1: 2}",
+            "\"\\ud83c\"",
+        ] {
+            assert_eq!(Json::parse(input), None, "{}", input);
+        }
+
+        assert_eq!(Json::parse(&"[".repeat(1000)), None);
+    }
+
+    #[test]
+    fn can_write_documents() {
+        let json = Json::object([
+            ("name", Json::string("a \"b\"")),
+            (
+                "values",
+                Json::Array(vec![Json::Number(1.0), Json::Number(0.5)]),
+            ),
+            ("empty", Json::Object(vec![])),
+        ]);
+
+        assert_eq!(
+            json.to_string(),
+            "{\n  \"name\": \"a \\\"b\\\"\",\n  \"values\": [\n    1,\n    0.5\n  ],\n  \"empty\": {}\n}\n"
+        );
+        assert_eq!(Json::parse(&json.to_string()), Some(json));
+    }
+}
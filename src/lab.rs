@@ -0,0 +1,236 @@
+use super::{
+    linear::{from_linear_channels, linear_channels},
+    xyz, Adaptation, Gamut, WhitePoint, RGB, XYZ,
+};
+use std::fmt;
+
+/// Constructs a CIELAB Color from numerical values, similar to the
+/// [`lab` function](css-lab) in CSS.
+///
+/// The lightness component ranges between `0.0` and `100.0`. The `a` and `b`
+/// components are unbounded, but fall roughly between `-125.0` and `125.0`.
+///
+/// # Example
+/// ```
+/// use farver::lab;
+///
+/// let salmon = lab(70.0, 40.0, 25.0);
+///
+/// assert_eq!(salmon.to_string(), "lab(70.00 40.00 25.00)");
+/// ```
+///
+/// [css-lab]: https://www.w3.org/TR/css-color-4/#specifying-lab-lch
+pub fn lab(l: f32, a: f32, b: f32) -> LAB {
+    LAB { l, a, b }
+}
+
+// The D50 reference white, as used by CSS.
+const WHITE: [f32; 3] = [0.96422, 1.0, 0.82521];
+
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in the CIELAB color space, relative to the D50
+/// white point like the `lab()` function of CSS.
+///
+/// The lightness `l` ranges between `0.0` (black) and `100.0` (white), while `a`
+/// (green to red) and `b` (blue to yellow) describe the hue and colorfulness.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#specifying-lab-lch).
+pub struct LAB {
+    // lightness
+    pub l: f32,
+
+    // green-red axis
+    pub a: f32,
+
+    // blue-yellow axis
+    pub b: f32,
+}
+
+// The matrices below convert between linear sRGB and Bradford-adapted D50
+// XYZ, kept at the precision published by Bruce Lindbloom.
+#[allow(clippy::excessive_precision)]
+impl LAB {
+    pub(crate) fn from_rgb(rgb: RGB) -> Self {
+        let [r, g, b] = linear_channels(rgb);
+
+        let x = 0.4360747 * r + 0.3850649 * g + 0.1430804 * b;
+        let y = 0.2225045 * r + 0.7168786 * g + 0.0606169 * b;
+        let z = 0.0139322 * r + 0.0971045 * g + 0.7141733 * b;
+
+        from_white_relative([x / WHITE[0], y / WHITE[1], z / WHITE[2]])
+    }
+
+    /// Converts `self` into its RGB representation. Colors falling outside of
+    /// the sRGB gamut are clipped.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_lab().to_rgb(), tomato);
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        from_linear_channels(self.to_linear())
+    }
+
+    // Converts `self` into linear sRGB channels, which fall outside of the
+    // 0.0-1.0 range for colors outside of the sRGB gamut.
+    pub(crate) fn to_linear(self) -> [f32; 3] {
+        let [x, y, z] = self.to_white_relative();
+        let (x, y, z) = (x * WHITE[0], y * WHITE[1], z * WHITE[2]);
+
+        [
+            3.1338561 * x - 1.6168667 * y - 0.4906146 * z,
+            -0.9787684 * x + 1.9161415 * y + 0.0334540 * z,
+            0.0719453 * x - 0.2289914 * y + 1.4052427 * z,
+        ]
+    }
+}
+
+impl LAB {
+    /// Returns whether `self` can be represented in the `gamut`, with every
+    /// linear channel allowed to stray up to `tolerance` outside of the
+    /// `0.0`-`1.0` range, to absorb rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{lab, Gamut};
+    ///
+    /// assert!(lab(50.0, 60.0, 40.0).in_gamut(Gamut::Srgb, 1e-4));
+    /// assert!(!lab(50.0, -90.0, 40.0).in_gamut(Gamut::Srgb, 1e-4));
+    /// ```
+    pub fn in_gamut(self, gamut: Gamut, tolerance: f32) -> bool {
+        gamut.contains(self.to_linear(), tolerance)
+    }
+
+    /// Converts `self` into CIE XYZ, relative to D65 like every [`XYZ`], taking
+    /// `self` to be relative to the `white` point. Colors relative to other
+    /// white points than D65 are adapted with the Bradford transform.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{lab, WhitePoint};
+    ///
+    /// // The white of a tungsten light bulb is plain white once adapted.
+    /// let white = lab(100.0, 0.0, 0.0).to_xyz(WhitePoint::A);
+    ///
+    /// assert_eq!(white.to_string(), "color(xyz-d65 0.9505 1.0000 1.0891)");
+    /// ```
+    pub fn to_xyz(self, white: WhitePoint) -> XYZ {
+        let reference = white.to_xyz();
+        let [x, y, z] = self.to_white_relative();
+
+        xyz(x * reference.x, y * reference.y, z * reference.z).adapt(
+            white,
+            WhitePoint::D65,
+            Adaptation::Bradford,
+        )
+    }
+
+    /// Returns whether every component of `self` is finite, which conversions
+    /// of infinite or NaN values can't restore.
+    pub fn is_finite(self) -> bool {
+        self.l.is_finite() && self.a.is_finite() && self.b.is_finite()
+    }
+
+    // Converts `self` into CIE XYZ divided by its reference white.
+    fn to_white_relative(self) -> [f32; 3] {
+        let LAB { l, a, b } = self;
+
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        let f = |t: f32| {
+            if t * t * t > EPSILON {
+                t * t * t
+            } else {
+                (116.0 * t - 16.0) / KAPPA
+            }
+        };
+
+        let y = if l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            l / KAPPA
+        };
+
+        [f(fx), y, f(fz)]
+    }
+}
+
+// Converts CIE XYZ divided by its reference white into CIELAB.
+pub(crate) fn from_white_relative([x, y, z]: [f32; 3]) -> LAB {
+    let f = |t: f32| {
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    };
+
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+
+    LAB {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+impl fmt::Display for LAB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "lab({:.02} {:.02} {:.02})", self.l, self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, lab, rgb, rgba, Color};
+
+    #[test]
+    fn can_convert_reference_colors() {
+        assert_eq!(
+            rgb(255, 255, 255).to_lab().to_string(),
+            "lab(100.00 0.00 0.00)"
+        );
+        assert_eq!(rgb(0, 0, 0).to_lab().to_string(), "lab(0.00 0.00 0.00)");
+        assert_eq!(
+            rgb(255, 0, 0).to_lab().to_string(),
+            "lab(54.29 80.81 69.89)"
+        );
+        assert_eq!(
+            rgb(0, 0, 255).to_lab().to_string(),
+            "lab(29.57 68.30 -112.03)"
+        );
+    }
+
+    #[test]
+    fn can_round_trip() {
+        for color in [
+            rgb(255, 99, 71),
+            rgb(23, 98, 119),
+            rgb(100, 149, 237),
+            rgb(1, 2, 3),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ] {
+            assert_eq!(color.to_lab().to_rgb(), color);
+        }
+
+        assert_eq!(rgba(255, 99, 71, 0.5).to_lab(), rgb(255, 99, 71).to_lab());
+        assert_eq!(hsl(0, 0, 100).to_lab(), rgb(255, 255, 255).to_lab());
+    }
+
+    #[test]
+    fn clips_out_of_gamut_colors() {
+        assert_eq!(lab(150.0, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(lab(-10.0, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(lab(50.0, 120.0, 0.0).to_rgb().r.as_u8(), 255);
+    }
+}
@@ -1,20 +1,114 @@
 #[cfg(feature = "ansi_term")]
 use std::{borrow::Cow, fmt::Debug};
 
+mod adaptation;
 mod angle;
+mod ansi;
+mod audit;
+mod blend;
+mod brewer;
+mod colormap;
+mod contrast;
+mod contrast_color;
+mod css;
+mod cvd;
+mod difference;
+mod easing;
+mod export;
+mod extract;
+mod gamut;
+mod generate;
+mod gradient;
+mod harmony;
+mod hct;
+mod hdr;
+mod histogram;
 mod hsl;
+mod hue;
 mod integrations;
+mod json;
+mod lab;
+mod linear;
+mod luminance;
+mod material;
+mod names;
+mod oklab;
+mod palette;
+mod presets;
+mod ramp;
 mod ratio;
 mod rgb;
-
+mod scale;
+mod space;
+mod spectrum;
+mod state;
+mod swatches;
+mod temperature;
+mod themes;
+mod tokens;
+#[cfg(feature = "x11")]
+mod x11;
+mod xyz;
+
+pub use adaptation::{Adaptation, WhitePoint};
 pub use angle::*;
+pub use ansi::{
+    from_ansi256, parse_ansi, print_palette, swatch, AnsiColor, ANSI16_VGA, ANSI16_XTERM, ANSI256,
+    ANSI_RESET,
+};
+pub use audit::{contrast_matrix, ContrastMatrix, ContrastPair};
+pub use blend::{average, average_in, blend_weighted, blend_weighted_in};
+pub use brewer::{brewer, brewer_scheme, BrewerKind, BrewerScheme, BREWER_SCHEMES};
+pub use colormap::{
+    Colormap, ColormapKind, Cubehelix, CIVIDIS, COLORMAPS, COOLWARM, INFERNO, MAGMA, PI_YG, PLASMA,
+    RD_BU, SPECTRAL, TURBO, VIRIDIS,
+};
+pub use contrast::{ContrastRatio, Level, TextSize};
+pub use contrast_color::{contrast_color, ContrastColor, ContrastTarget};
+pub use css::{derive_light_dark_css, to_color_mix_css, to_light_dark_css};
+pub use cvd::{check_cvd_safety, Cvd, CvdConflict};
+pub use difference::Application;
+pub use easing::Easing;
+pub use extract::{ExtractMethod, ExtractOptions};
+pub use gamut::{max_chroma, max_chroma_lch, Gamut};
+pub use generate::{
+    categorical, from_hash, golden_hues, hue_cycle, CategoricalConstraints, GoldenHues, HueCycle,
+};
+pub use gradient::{
+    bezier, Bezier, ColorSteps, Gradient, GradientStop, RadialExtent, RadialShape, RasterizeOptions,
+};
+pub use harmony::Harmony;
+pub use hct::{hct, HCT};
+pub use hdr::{hlg_decode, hlg_encode, linear_rgb, pq_decode, pq_encode, LinearRGB, Tonemap};
+pub use histogram::{hue_histogram, DominantHue, HueHistogram};
 pub use hsl::*;
+pub use hue::HueSpace;
+#[cfg(feature = "serde")]
+pub use integrations::serde;
+pub use lab::{lab, LAB};
+pub use luminance::LIGHT_THRESHOLD;
+pub use material::{
+    elevated_surface, elevation_overlay_alpha, CorePalettes, MaterialScheme, TonalPalette,
+};
+pub use oklab::{oklab, oklch, OKLAB, OKLCH};
+pub use palette::{Palette, PaletteIntoIter, PaletteIter, SortKey};
+pub use presets::{OKABE_ITO, TOL_BRIGHT, TOL_LIGHT, TOL_MUTED};
+pub use ramp::{to_tailwind_colors, to_tailwind_config, TailwindCurves, TAILWIND_STEPS};
 pub use ratio::*;
 pub use rgb::*;
+pub use scale::{Scale, ScaleClass, ScaleTransform};
+pub use space::{ColorSpace, HueInterpolation};
+pub use spectrum::{from_spectrum, from_wavelength};
+pub use state::{StateDeltas, StateVariants};
+pub use temperature::{from_daylight, from_temperature};
+pub use themes::TerminalTheme;
+#[cfg(feature = "x11")]
+pub use x11::{from_x11_name, nearest_x11_name};
+pub use xyz::{xyz, XYZ};
 
 /// A trait that can be used for converting between different color models
 /// and performing various transformations on them.
-pub trait Color {
+pub trait Color: Sized {
     type Alpha: Color;
 
     /// Converts `self` to its CSS string format.
@@ -108,6 +202,115 @@ pub trait Color {
     /// ```
     fn to_hsla(self) -> HSLA;
 
+    /// Converts `self` into its OKLab representation.
+    /// The alpha value, if any, will not be preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red.to_oklab().to_string(), "oklab(62.80% 0.2249 0.1258)");
+    /// ```
+    fn to_oklab(self) -> OKLAB {
+        OKLAB::from_rgb(self.to_rgb())
+    }
+
+    /// Converts `self` into its OKLCH representation.
+    /// The alpha value, if any, will not be preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red.to_oklch().to_string(), "oklch(62.80% 0.2577 29.23)");
+    /// ```
+    fn to_oklch(self) -> OKLCH {
+        self.to_oklab().to_oklch()
+    }
+
+    /// Converts `self` into its CIELAB representation, relative to the D50
+    /// white point. The alpha value, if any, will not be preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red.to_lab().to_string(), "lab(54.29 80.81 69.89)");
+    /// ```
+    fn to_lab(self) -> LAB {
+        LAB::from_rgb(self.to_rgb())
+    }
+
+    /// Converts `self` into its CIE XYZ representation, relative to the D65
+    /// white point. The alpha value, if any, will not be preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red.to_xyz().to_string(), "color(xyz-d65 0.4124 0.2126 0.0193)");
+    /// ```
+    fn to_xyz(self) -> XYZ {
+        XYZ::from_rgb(self.to_rgb())
+    }
+
+    /// Converts `self` into its HCT representation, the color space of
+    /// Material Design 3. The alpha value, if any, is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(blue.to_hct().to_string(), "hct(282.79 87.23 32.30)");
+    /// ```
+    fn to_hct(self) -> HCT {
+        HCT::from_rgb(self.to_rgb())
+    }
+
+    /// Returns the CSS keyword naming `self` exactly, if there is one. When
+    /// several keywords name the same color, the shortest is returned, such as
+    /// `"aqua"` rather than `"cyan"`. Fully transparent black is named
+    /// `"transparent"`, while other translucent colors have no name.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(102, 51, 153).to_name(), Some("rebeccapurple"));
+    /// assert_eq!(rgb(102, 51, 154).to_name(), None);
+    /// assert_eq!(rgba(102, 51, 153, 0.5).to_name(), None);
+    /// ```
+    fn to_name(self) -> Option<&'static str> {
+        names::to_name(self)
+    }
+
+    /// Describes `self` in plain words, such as `"dark desaturated red"` or
+    /// `"pale blue"`, for alternative texts and logs. The description is a
+    /// heuristic based on the OKLCH representation of the color, and the
+    /// alpha value, if any, is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).describe(), "red");
+    /// assert_eq!(rgb(23, 98, 119).describe(), "cyan");
+    /// assert_eq!(hsl(0, 0, 20).describe(), "dark grey");
+    /// ```
+    fn describe(self) -> String {
+        names::describe(self)
+    }
+
     /// Increases the saturation of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-saturate).
@@ -236,6 +439,27 @@ pub trait Color {
     /// ```
     fn spin(self, amount: Angle) -> Self;
 
+    /// Rotates the hue of `self` toward that of `toward`, by `amount` of the
+    /// difference between them and by at most 15°, so accent colors feel
+    /// cohesive with a brand color. Hues are taken in HCT, and the chroma and
+    /// tone of `self` are kept, along with any existing alpha channel, and the
+    /// result is returned as RGBA. With an amount of 50%, this matches
+    /// Material Design's `Blend.harmonize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.harmonize(blue, percent(50)), rgba(251, 0, 87, 1.0));
+    /// assert_eq!(red.harmonize(blue, percent(0)), red.to_rgba());
+    /// ```
+    fn harmonize<T: Color>(self, toward: T, amount: Ratio) -> RGBA {
+        material::harmonize(self.to_rgba(), toward, amount)
+    }
+
     /// Mixes two colors (`self` and any other `Color`) together in variable proportion.
     /// Takes opacity into account in the calculations.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-mix).
@@ -253,6 +477,92 @@ pub trait Color {
     /// ```
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha;
 
+    /// Mixes `self` with `other` in the given color space, like the CSS
+    /// `color-mix()` function, `weight` being the proportion of `self`.
+    /// Unlike `mix()`, colors are mixed with premultiplied alpha, and
+    /// cylindrical spaces mix hues around the shorter arc of the color wheel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, ColorSpace, hsl, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.mix_in(blue, percent(100), ColorSpace::SRGB), rgba(255, 0, 0, 1.0));
+    /// assert_eq!(red.mix_in(blue, percent(50), ColorSpace::HSL).to_hsl(), hsl(300, 100, 50));
+    /// assert_eq!(red.mix_in(blue, percent(50), ColorSpace::OKLAB).to_rgb(), rgb(141, 83, 162));
+    /// ```
+    fn mix_in<T: Color>(self, other: T, weight: Ratio, space: ColorSpace) -> RGBA {
+        space::interpolate(
+            self,
+            other,
+            1.0 - weight.as_f32(),
+            space,
+            HueInterpolation::Shorter,
+        )
+    }
+
+    /// Interpolates from `self` to `other` in the given color space, `t`
+    /// going from `0.0` for `self` to `1.0` for `other`, the way CSS
+    /// interpolates colors. See `mix_in()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, ColorSpace, rgb, rgba};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(black.lerp(white, 0.25, ColorSpace::SRGB), rgba(64, 64, 64, 1.0));
+    /// assert_eq!(black.lerp(white, 0.5, ColorSpace::LinearRGB), rgba(188, 188, 188, 1.0));
+    /// ```
+    fn lerp<T: Color>(self, other: T, t: f32, space: ColorSpace) -> RGBA {
+        space::interpolate(self, other, t, space, HueInterpolation::Shorter)
+    }
+
+    /// Interpolates from `self` to `other` like `lerp()`, with hues taking
+    /// the given arc around the color wheel in cylindrical color spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, ColorSpace, HueInterpolation, hsl};
+    ///
+    /// let red = hsl(0, 100, 50);
+    /// let green = hsl(120, 100, 50);
+    ///
+    /// let blue = red.lerp_with(green, 0.5, ColorSpace::HSL, HueInterpolation::Longer);
+    /// assert_eq!(blue.to_hsl(), hsl(240, 100, 50));
+    /// ```
+    fn lerp_with<T: Color>(
+        self,
+        other: T,
+        t: f32,
+        space: ColorSpace,
+        arc: HueInterpolation,
+    ) -> RGBA {
+        space::interpolate(self, other, t, space, arc)
+    }
+
+    /// Iterates over `n` evenly spaced colors from `self` to `other`, both
+    /// included, interpolated in the given color space like `lerp()`. This
+    /// builds ramps and animation frames without allocating a gradient.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, ColorSpace, rgb, rgba};
+    ///
+    /// let mut steps = rgb(0, 0, 0).steps_to(rgb(255, 255, 255), 3, ColorSpace::SRGB);
+    ///
+    /// assert_eq!(steps.next(), Some(rgba(0, 0, 0, 1.0)));
+    /// assert_eq!(steps.next(), Some(rgba(128, 128, 128, 1.0)));
+    /// assert_eq!(steps.next(), Some(rgba(255, 255, 255, 1.0)));
+    /// assert_eq!(steps.next(), None);
+    /// ```
+    fn steps_to<T: Color>(self, other: T, n: usize, space: ColorSpace) -> ColorSteps {
+        ColorSteps::new(self, other, n, space)
+    }
+
     /// Mixes `self` with white in variable proportion.
     /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`).
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
@@ -301,6 +611,794 @@ pub trait Color {
     /// ```
     fn greyscale(self) -> Self;
 
+    /// Simulates how `self` is perceived with a color vision deficiency, so palettes can be
+    /// previewed as colorblind users see them. The `severity` ranges from `0%` (normal vision)
+    /// to `100%` (dichromacy, or full achromatopsia). Returns RGBA, preserving any existing alpha
+    /// channel.
+    /// Uses the [Machado et al. (2009)](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+    /// model, interpolating between its published matrices, which come in steps of `10%`. Partial
+    /// achromatopsia, which the model does not cover, blends toward the luminance in linear light.
+    /// The Brettel et al. (1997) model is not implemented.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, Cvd, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let green = rgb(0, 128, 0);
+    ///
+    /// assert_eq!(red.simulate(Cvd::Protanopia, percent(0)), red.to_rgba());
+    /// assert_eq!(red.simulate(Cvd::Protanopia, percent(100)), rgba(109, 95, 0, 1.0));
+    /// assert_eq!(
+    ///     green.simulate(Cvd::Achromatopsia, percent(100)),
+    ///     rgba(109, 109, 109, 1.0)
+    /// );
+    /// ```
+    fn simulate(self, cvd: Cvd, severity: Ratio) -> RGBA {
+        cvd::simulate(self.to_rgba(), cvd, severity)
+    }
+
+    /// Shifts the colors of `self` to make it easier to tell apart from other colors with a
+    /// color vision deficiency, complementing `simulate()`. The information lost to the deficiency
+    /// is moved into channels that remain visible, using the
+    /// [Fidaner et al. (2005)](http://scien.stanford.edu/pages/labsite/2005/psych221/projects/05/ofidaner/colorblindness_project.htm)
+    /// error modification. Colors are left unchanged for achromatopsia.
+    /// Returns RGBA, preserving any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, Cvd, rgb, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert_ne!(red.daltonize(Cvd::Deuteranopia, percent(100)), red.to_rgba());
+    /// assert_eq!(grey.daltonize(Cvd::Deuteranopia, percent(100)), grey.to_rgba());
+    /// ```
+    fn daltonize(self, cvd: Cvd, severity: Ratio) -> RGBA {
+        cvd::daltonize(self.to_rgba(), cvd, severity)
+    }
+
+    /// Snaps `self` to the nearest color of the 216 colors web-safe palette,
+    /// whose channels are all multiples of 51 (`0x33`). Operates on the color
+    /// within its RGB representation and returns RGBA, preserving any existing
+    /// alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_web_safe(), rgba(255, 102, 51, 1.0));
+    /// assert_eq!(rgba(255, 99, 71, 0.5).to_web_safe(), rgba(255, 102, 51, 0.5));
+    /// ```
+    fn to_web_safe(self) -> RGBA {
+        let RGBA { r, g, b, a } = self.to_rgba();
+        let snap =
+            |channel: Ratio| Ratio::from_u8((channel.as_u8() as f32 / 51.0).round() as u8 * 51);
+
+        RGBA {
+            r: snap(r),
+            g: snap(g),
+            b: snap(b),
+            a,
+        }
+    }
+
+    /// Returns whether `self` is one of the 216 colors of the web-safe
+    /// palette. The alpha value, if any, is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert!(rgb(255, 102, 51).is_web_safe());
+    /// assert!(!rgb(255, 99, 71).is_web_safe());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_web_safe(self) -> bool {
+        let RGB { r, g, b } = self.to_rgb();
+
+        [r, g, b].iter().all(|channel| channel.as_u8() % 51 == 0)
+    }
+
+    /// Computes the relative luminance of `self`, ranging from `0.0` for black to `1.0` for white.
+    /// This is the physical amount of light emitted, computed from the linearized sRGB channels
+    /// with the Rec. 709 weights, and is what the WCAG 2.x contrast ratio is based on.
+    /// It is not the same as how bright the color looks; for that, see `perceived_brightness()`.
+    /// For more, see the [WCAG definition](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert_eq!(rgb(0, 0, 0).relative_luminance(), 0.0);
+    /// assert_eq!(format!("{:.2}", grey.relative_luminance()), "0.22");
+    /// ```
+    fn relative_luminance(self) -> f32 {
+        luminance::relative_luminance(self.to_rgb())
+    }
+
+    /// Computes the perceived brightness of `self`, ranging from `0.0` for black to `1.0` for white.
+    /// This uses the [HSP color model](http://alienryderflex.com/hsp.html) on the gamma-encoded
+    /// channels, and approximates how bright the color appears to a viewer. A mid grey is roughly
+    /// `0.5` here, while its `relative_luminance()` is closer to `0.2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert_eq!(rgb(0, 0, 0).perceived_brightness(), 0.0);
+    /// assert_eq!(format!("{:.2}", grey.perceived_brightness()), "0.50");
+    /// ```
+    fn perceived_brightness(self) -> f32 {
+        luminance::perceived_brightness(self.to_rgb())
+    }
+
+    /// Returns whether `self` is a light color, meaning that its relative luminance is above
+    /// `LIGHT_THRESHOLD`. Black text is more readable than white text on light colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let yellow = rgb(255, 255, 0);
+    /// let navy = rgb(0, 0, 128);
+    ///
+    /// assert!(yellow.is_light());
+    /// assert!(!navy.is_light());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_light(self) -> bool {
+        self.is_light_with(LIGHT_THRESHOLD)
+    }
+
+    /// Returns whether `self` is a dark color, meaning that its relative luminance is at or below
+    /// `LIGHT_THRESHOLD`. White text is more readable than black text on dark colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let yellow = rgb(255, 255, 0);
+    /// let navy = rgb(0, 0, 128);
+    ///
+    /// assert!(navy.is_dark());
+    /// assert!(!yellow.is_dark());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_dark(self) -> bool {
+        !self.is_light()
+    }
+
+    /// Returns whether the relative luminance of `self` is above `threshold`,
+    /// which should fall between `0.0` and `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert!(grey.is_light_with(0.2));
+    /// assert!(!grey.is_light_with(0.5));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_light_with(self, threshold: f32) -> bool {
+        self.relative_luminance() > threshold
+    }
+
+    /// Returns whether the relative luminance of `self` is at or below `threshold`,
+    /// which should fall between `0.0` and `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert!(grey.is_dark_with(0.5));
+    /// assert!(!grey.is_dark_with(0.2));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_dark_with(self, threshold: f32) -> bool {
+        !self.is_light_with(threshold)
+    }
+
+    /// Estimates the correlated color temperature of `self` in kelvin: the
+    /// temperature of the blackbody radiator whose color is closest to it, as
+    /// `from_temperature()` gives. Returns `None` for black.
+    ///
+    /// It is only meaningful for colors close to white, like the white balance
+    /// of a photo or the light of an LED, and falls between 1667K and 25000K.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let warm = rgb(255, 180, 107).cct().unwrap();
+    /// let white = rgb(255, 255, 255).cct().unwrap();
+    ///
+    /// assert_eq!(format!("{:.0}", warm), "2912");
+    /// assert_eq!(format!("{:.0}", white), "6502");
+    /// ```
+    fn cct(self) -> Option<f32> {
+        temperature::cct(self)
+    }
+
+    /// Computes the WCAG 2.x contrast ratio between `self` and any other `Color`.
+    /// The alpha channel of either color is not taken into account.
+    /// For more, see the [WCAG definition](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, Level, TextSize, rgb, hsl};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let black = hsl(0, 0, 0);
+    /// let teal = rgb(23, 98, 119);
+    ///
+    /// assert_eq!(white.contrast_ratio(black).to_string(), "21.00:1");
+    /// assert!(white.contrast_ratio(teal).meets(Level::AA, TextSize::Normal));
+    /// assert!(!white.contrast_ratio(teal).meets(Level::AAA, TextSize::Normal));
+    /// ```
+    fn contrast_ratio<T: Color>(self, other: T) -> ContrastRatio {
+        contrast::contrast_ratio(self, other)
+    }
+
+    /// Picks black or white, whichever has the highest WCAG 2.x contrast ratio, as the color for
+    /// text placed on a `self` background.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let yellow = rgb(255, 255, 0);
+    /// let navy = rgb(0, 0, 128);
+    ///
+    /// assert_eq!(yellow.readable_on(), rgb(0, 0, 0));
+    /// assert_eq!(navy.readable_on(), rgb(255, 255, 255));
+    /// ```
+    fn readable_on(self) -> RGB {
+        if self.is_light() {
+            rgb(0, 0, 0)
+        } else {
+            rgb(255, 255, 255)
+        }
+    }
+
+    /// Picks the color from `candidates` with the highest WCAG 2.x contrast ratio as the color for
+    /// text placed on a `self` background. If several candidates are tied, the first one is picked.
+    /// Returns `None` if there are no candidates.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let cream = rgb(255, 253, 208);
+    /// let candidates = [rgb(255, 99, 71), rgb(23, 98, 119), rgb(100, 149, 237)];
+    ///
+    /// assert_eq!(cream.best_text_color(&candidates), Some(rgb(23, 98, 119)));
+    /// assert_eq!(cream.best_text_color::<farver::RGB>(&[]), None);
+    /// ```
+    fn best_text_color<T: Color + Copy>(self, candidates: &[T]) -> Option<T> {
+        let background = self.to_rgba();
+        let mut best: Option<(T, ContrastRatio)> = None;
+
+        for &candidate in candidates {
+            let ratio = candidate.contrast_ratio(background);
+
+            match best {
+                Some((_, best_ratio)) if best_ratio >= ratio => {}
+                _ => best = Some((candidate, ratio)),
+            }
+        }
+
+        best.map(|(candidate, _)| candidate)
+    }
+
+    /// Adjusts the lightness of `self` until its WCAG 2.x contrast ratio against `background`
+    /// reaches `ratio`, or returns `None` if no lightness can achieve it. The adjustment happens
+    /// in OKLCH and goes in whichever direction (lighter or darker) requires the smallest change,
+    /// preserving the hue and any existing alpha channel. The chroma is eased off on the way to
+    /// black or white. Colors that already meet the target are returned unchanged, as RGBA.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, Level, TextSize, rgb};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let tomato = rgb(255, 99, 71);
+    /// let target = Level::AA.threshold(TextSize::Normal);
+    ///
+    /// let darker_tomato = tomato.ensure_contrast(white, target).unwrap();
+    ///
+    /// assert!(darker_tomato.contrast_ratio(white).meets(Level::AA, TextSize::Normal));
+    /// assert_eq!(tomato.ensure_contrast(rgb(128, 128, 128), 21.0), None);
+    /// ```
+    fn ensure_contrast<T: Color>(self, background: T, ratio: f32) -> Option<RGBA> {
+        contrast::ensure_contrast(self.to_rgba(), background, ratio)
+    }
+
+    /// Computes the APCA lightness contrast (Lc) of `self` as text placed on a `background`.
+    /// Unlike the WCAG 2.x ratio, APCA is not symmetric: the result is positive for dark text
+    /// on a light background, negative for light text on a dark background, and roughly
+    /// ranges between `-108` and `106`. Contrasts too low to be meaningful are reported as `0`.
+    /// For more, see the [APCA documentation](https://git.apcacontrast.com/documentation/APCA_in_a_Nutshell).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let grey = rgb(136, 136, 136);
+    ///
+    /// assert_eq!(grey.apca_contrast(white).round(), 63.0);
+    /// assert_eq!(white.apca_contrast(grey).round(), -69.0);
+    /// assert_eq!(grey.apca_contrast(grey), 0.0);
+    /// ```
+    fn apca_contrast<T: Color>(self, background: T) -> f32 {
+        contrast::apca_contrast(self, background)
+    }
+
+    /// Computes the CIE76 color difference between `self` and `other`: their
+    /// euclidean distance in CIELAB. A difference of about 2.3 is just
+    /// noticeable. The alpha values, if any, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let black = rgb(0, 0, 0);
+    ///
+    /// assert_eq!(white.delta_e76(black).round(), 100.0);
+    /// assert_eq!(white.delta_e76(hsl(0, 0, 100)), 0.0);
+    /// ```
+    fn delta_e76<T: Color>(self, other: T) -> f32 {
+        difference::delta_e76(self.to_lab(), other.to_lab())
+    }
+
+    /// Computes the CIEDE2000 color difference between `self` and `other`,
+    /// which corrects the perceptual non-uniformities of CIE76, notably in the
+    /// blue hues. A difference of about 1.0 is just noticeable, making it the
+    /// metric of choice to tell whether two colors look identical.
+    /// The alpha values, if any, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert!(tomato.delta_e2000(rgb(255, 100, 71)) < 1.0);
+    /// assert!(tomato.delta_e2000(rgb(23, 98, 119)) > 50.0);
+    /// ```
+    fn delta_e2000<T: Color>(self, other: T) -> f32 {
+        difference::delta_e2000(self.to_lab(), other.to_lab())
+    }
+
+    /// Computes the CIE94 color difference between `self`, as the reference
+    /// color, and `other`, with the weighting factors of the given industry.
+    /// The metric is not symmetric, as the weights depend on the reference.
+    /// The alpha values, if any, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Application, Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    /// let coral = rgb(255, 127, 80);
+    ///
+    /// assert_eq!(tomato.delta_e94(coral, Application::GraphicArts).round(), 7.0);
+    /// assert_eq!(tomato.delta_e94(coral, Application::Textiles).round(), 5.0);
+    /// ```
+    fn delta_e94<T: Color>(self, other: T, application: Application) -> f32 {
+        difference::delta_e94(self.to_lab(), other.to_lab(), application)
+    }
+
+    /// Computes the CMC l:c color difference between `self`, as the reference
+    /// color, and `other`. The `lightness` and `chroma` weights are usually
+    /// `2.0` and `1.0` to judge acceptability, or both `1.0` to judge
+    /// perceptibility. The metric is not symmetric, as the weights depend on
+    /// the reference. The alpha values, if any, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    /// let coral = rgb(255, 127, 80);
+    ///
+    /// assert_eq!(tomato.delta_e_cmc(coral, 2.0, 1.0).round(), 7.0);
+    /// assert_eq!(tomato.delta_e_cmc(coral, 1.0, 1.0).round(), 8.0);
+    /// ```
+    fn delta_e_cmc<T: Color>(self, other: T, lightness: f32, chroma: f32) -> f32 {
+        difference::delta_e_cmc(self.to_lab(), other.to_lab(), lightness, chroma)
+    }
+
+    /// Computes the "redmean" distance between `self` and `other`, a weighted
+    /// euclidean distance in RGB ranging from `0.0` to about `765.0`. It is a
+    /// rough but cheap approximation of the perceptual difference, suited to
+    /// hot paths such as finding the nearest palette color for every pixel
+    /// of an image. The alpha values, if any, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let crimson = rgb(220, 20, 60);
+    /// let palette = [rgb(255, 0, 0), rgb(255, 165, 0), rgb(0, 0, 255)];
+    ///
+    /// let nearest = palette.iter().min_by(|lhs, rhs| {
+    ///     let lhs = crimson.redmean_distance(**lhs);
+    ///     let rhs = crimson.redmean_distance(**rhs);
+    ///
+    ///     lhs.partial_cmp(&rhs).unwrap()
+    /// });
+    ///
+    /// assert_eq!(nearest, Some(&rgb(255, 0, 0)));
+    /// ```
+    fn redmean_distance<T: Color>(self, other: T) -> f32 {
+        difference::redmean(self.to_rgb(), other.to_rgb())
+    }
+
+    /// Computes the smallest angle between the hues of `self` and `other`,
+    /// in degrees between `0.0` and `180.0`, as read from the given color
+    /// space. Achromatic colors count as having a hue of 0°.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, HueSpace};
+    ///
+    /// let red = hsl(10, 100, 50);
+    /// let pink = hsl(340, 100, 50);
+    ///
+    /// assert_eq!(red.hue_distance(pink, HueSpace::HSL), 30.0);
+    /// assert!(red.hue_distance(pink, HueSpace::OKLCH) < 90.0);
+    /// ```
+    fn hue_distance<T: Color>(self, other: T, space: HueSpace) -> f32 {
+        hue::hue_distance(self, other, space)
+    }
+
+    /// Builds a classic color scheme from `self`, rotating its hue in the
+    /// given space: HSL matches most color pickers, while LCH and OKLCH keep
+    /// the colors at a similar lightness. The scheme starts with `self`, and
+    /// keeps its alpha value. Colors falling outside of the sRGB gamut are
+    /// clipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, Harmony, HueSpace};
+    ///
+    /// let red = hsl(0, 100, 50);
+    /// let scheme = red.harmony(Harmony::Triadic, HueSpace::HSL);
+    ///
+    /// assert_eq!(scheme.to_hsl(), vec![hsl(0, 100, 50), hsl(120, 100, 50), hsl(240, 100, 50)]);
+    /// assert_eq!(red.harmony(Harmony::Complementary, HueSpace::OKLCH).len(), 2);
+    /// ```
+    fn harmony(self, harmony: Harmony, space: HueSpace) -> Palette {
+        harmony::harmony_at(self, harmony.angles(), space)
+    }
+
+    /// Builds a custom color scheme from `self`, rotating its hue by each of
+    /// `angles` (in degrees) in the given space. Unlike `harmony()`, `self` is
+    /// only included when one of the angles is `0.0`. The alpha value, if any,
+    /// is kept. Colors falling outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, HueSpace};
+    ///
+    /// let red = hsl(0, 100, 50);
+    /// let scheme = red.harmony_at(&[30.0, 180.0, 210.0], HueSpace::HSL);
+    ///
+    /// assert_eq!(scheme.to_hsl(), vec![hsl(30, 100, 50), hsl(180, 100, 50), hsl(210, 100, 50)]);
+    /// ```
+    fn harmony_at(self, angles: &[f32], space: HueSpace) -> Palette {
+        harmony::harmony_at(self, angles, space)
+    }
+
+    /// Suggests accent colors for `self`, such as to theme an interface from
+    /// a user's avatar or wallpaper. The suggestions are the OKLCH complement
+    /// of `self` followed by its split complements, keeping its lightness and
+    /// chroma as far as the sRGB gamut allows, then made lighter or darker
+    /// until they reach a WCAG 2.x contrast ratio of 3:1 against `self`.
+    /// Suggestions that cannot reach it are left out. Any alpha is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, HueSpace, rgb};
+    ///
+    /// let teal = rgb(23, 98, 119);
+    /// let accent = teal.suggest_accent().get(0).unwrap();
+    ///
+    /// assert!(accent.contrast_ratio(teal).as_f32() >= 3.0);
+    /// assert!(teal.hue_distance(accent, HueSpace::OKLCH) > 170.0);
+    /// ```
+    fn suggest_accent(self) -> Palette {
+        harmony::suggest_accent(self)
+    }
+
+    /// Builds `n` variants of `self` evenly spaced in lightness, from the
+    /// lightest to the darkest, as a UI shade ramp. The variants are spaced in
+    /// OKLCH so the steps look even, and keep the hue and alpha value of
+    /// `self`. Their chroma is reduced where needed to stay in the sRGB gamut.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let ramp = rgb(23, 98, 119).monochromatic(5);
+    ///
+    /// assert_eq!(ramp.len(), 5);
+    /// assert!(ramp.get(0).unwrap().is_light());
+    /// assert!(ramp.get(4).unwrap().is_dark());
+    /// ```
+    fn monochromatic(self, n: usize) -> Palette {
+        ramp::monochromatic(self, n)
+    }
+
+    /// Builds a Tailwind CSS color scale around `self`, with one color per
+    /// step of `TAILWIND_STEPS` from 50 to 950, each named after its step.
+    /// The colors keep the hue and alpha value of `self`, and the step closest
+    /// to it in lightness is `self` itself. See `tailwind_scale_with()` to tune
+    /// the lightness and chroma of the steps.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let teal = rgb(23, 98, 119);
+    /// let scale = teal.tailwind_scale();
+    ///
+    /// assert_eq!(scale.len(), 11);
+    /// assert_eq!(scale.get_named("700"), Some(teal.to_rgba()));
+    /// assert!(scale.get_named("50").unwrap().is_light());
+    /// ```
+    fn tailwind_scale(self) -> Palette {
+        ramp::tailwind_scale(self, TailwindCurves::default())
+    }
+
+    /// Builds a Tailwind CSS color scale around `self` like `tailwind_scale()`,
+    /// following the given lightness and chroma curves.
+    fn tailwind_scale_with(self, curves: TailwindCurves) -> Palette {
+        ramp::tailwind_scale(self, curves)
+    }
+
+    /// Builds the colors of an interactive element in its hover, focus,
+    /// active, and disabled states, from `self` as its base color. Light
+    /// colors get darker as they are interacted with and dark colors lighter,
+    /// by consistent steps of OKLCH lightness, while the disabled state fades
+    /// `self` to 38% of its opacity. See `state_variants_with()` to change
+    /// the steps.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let teal = rgb(23, 98, 119);
+    /// let states = teal.state_variants();
+    ///
+    /// assert!(states.hover.to_oklch().l > teal.to_oklch().l);
+    /// assert_eq!(states.disabled, rgba(23, 98, 119, 0.38));
+    /// ```
+    fn state_variants(self) -> StateVariants {
+        state::state_variants(self, StateDeltas::default())
+    }
+
+    /// Builds the colors of an interactive element in each of its states like
+    /// `state_variants()`, with the given steps.
+    fn state_variants_with(self, deltas: StateDeltas) -> StateVariants {
+        state::state_variants(self, deltas)
+    }
+
+    /// Builds `n` steps mixing `self` with black (`rgb(0, 0, 0)`) in growing
+    /// proportion, as `shade()` does. The ladder starts with `self` and stops one
+    /// step short of black.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     red.shades(4).to_rgb(),
+    ///     vec![rgb(255, 0, 0), rgb(191, 0, 0), rgb(128, 0, 0), rgb(64, 0, 0)]
+    /// );
+    /// ```
+    fn shades(self, n: usize) -> Palette {
+        ramp::shades(self, n)
+    }
+
+    /// Builds `n` steps mixing `self` with white (`rgb(255, 255, 255)`) in growing
+    /// proportion, as `tint()` does. The ladder starts with `self` and stops one
+    /// step short of white.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     red.tints(4).to_rgb(),
+    ///     vec![rgb(255, 0, 0), rgb(255, 64, 64), rgb(255, 127, 127), rgb(255, 191, 191)]
+    /// );
+    /// ```
+    fn tints(self, n: usize) -> Palette {
+        ramp::tints(self, n)
+    }
+
+    /// Builds `n` steps mixing `self` with grey (`rgb(128, 128, 128)`) in growing
+    /// proportion, as `mix()` does. The ladder starts with `self` and stops one
+    /// step short of grey.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     red.tones(4).to_rgb(),
+    ///     vec![rgb(255, 0, 0), rgb(223, 32, 32), rgb(192, 64, 64), rgb(160, 96, 96)]
+    /// );
+    /// ```
+    fn tones(self, n: usize) -> Palette {
+        ramp::tones(self, n)
+    }
+
+    /// Returns the index of the xterm 256 colors palette nearest to `self`,
+    /// for terminals without truecolor support. The index is picked from the
+    /// 6x6x6 color cube or the grayscale ramp, leaving out the first 16 colors
+    /// as terminal themes usually redefine them. The alpha value, if any, is
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_ansi256(), 203);
+    /// assert_eq!(rgb(128, 128, 128).to_ansi256(), 244);
+    /// assert_eq!(rgb(0, 0, 0).to_ansi256(), 16);
+    /// ```
+    fn to_ansi256(self) -> u8 {
+        ansi::to_ansi256(self)
+    }
+
+    /// Returns the index of the basic ANSI color nearest to `self`, as defined
+    /// by xterm, for terminals supporting only 16 colors. Indexes 0 to 7 map
+    /// to the SGR codes 30 to 37, and 8 to 15 to the bright codes 90 to 97.
+    /// The alpha value, if any, is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_ansi16(), 9);
+    /// assert_eq!(rgb(139, 0, 0).to_ansi16(), 1);
+    /// ```
+    fn to_ansi16(self) -> u8 {
+        ansi::nearest(self, &ANSI16_XTERM)
+    }
+
+    /// Returns the index of the color of `palette` nearest to `self`, such as
+    /// one of the basic ANSI palettes, or the first 8 colors of one for
+    /// terminals supporting only 8 colors. Panics if the palette is empty, or
+    /// holds more than 256 colors. The alpha value, if any, is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, ANSI16_VGA};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_ansi16_with(&ANSI16_VGA), 9);
+    ///
+    /// // Yellow, which VGA renders as brown.
+    /// assert_eq!(tomato.to_ansi16_with(&ANSI16_VGA[..8]), 3);
+    /// ```
+    fn to_ansi16_with(self, palette: &[RGB]) -> u8 {
+        ansi::nearest(self, palette)
+    }
+
+    /// Returns the escape sequence setting `self` as the foreground color of
+    /// terminal output, for terminals with truecolor support. The alpha value,
+    /// if any, is ignored. See `ANSI_RESET` to reset the style afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_ansi_fg(), "\x1b[38;2;255;99;71m");
+    /// ```
+    fn to_ansi_fg(self) -> String {
+        ansi::truecolor(self, 38)
+    }
+
+    /// Returns the escape sequence setting `self` as the background color of
+    /// terminal output, for terminals with truecolor support. The alpha value,
+    /// if any, is ignored. See `ANSI_RESET` to reset the style afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_ansi_bg(), "\x1b[48;2;255;99;71m");
+    /// ```
+    fn to_ansi_bg(self) -> String {
+        ansi::truecolor(self, 48)
+    }
+
+    /// Returns the SwiftUI expression of `self`, a `Color` built from its sRGB
+    /// channels rounded to 3 decimals, with its opacity if it is translucent.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_swiftui(), "Color(red: 0.980, green: 0.502, blue: 0.447)");
+    /// assert_eq!(
+    ///     rgba(0, 0, 0, 0.5).to_swiftui(),
+    ///     "Color(red: 0.000, green: 0.000, blue: 0.000, opacity: 0.502)"
+    /// );
+    /// ```
+    fn to_swiftui(self) -> String {
+        export::swiftui(self)
+    }
+
+    /// Returns the Jetpack Compose expression of `self`, a `Color` built from
+    /// its hex ARGB value.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_compose(), "Color(0xFFFA8072)");
+    /// assert_eq!(rgba(0, 0, 0, 0.5).to_compose(), "Color(0x80000000)");
+    /// ```
+    fn to_compose(self) -> String {
+        export::compose(self)
+    }
+
+    /// Returns the GLSL `vec4` literal of `self`, holding its linear light
+    /// sRGB channels and its alpha value, as shaders blend colors, with
+    /// `precision` decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_glsl_vec4(4), "vec4(0.9560, 0.2159, 0.1683, 1.0000)");
+    /// assert_eq!(rgba(255, 255, 255, 0.5).to_glsl_vec4(2), "vec4(1.00, 1.00, 1.00, 0.50)");
+    /// ```
+    fn to_glsl_vec4(self, precision: usize) -> String {
+        export::vec4(self, "vec4", precision)
+    }
+
+    /// Returns the WGSL `vec4<f32>` literal of `self`, holding its linear
+    /// light sRGB channels and its alpha value, with `precision` decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(
+    ///     rgb(250, 128, 114).to_wgsl_vec4(4),
+    ///     "vec4<f32>(0.9560, 0.2159, 0.1683, 1.0000)"
+    /// );
+    /// ```
+    fn to_wgsl_vec4(self, precision: usize) -> String {
+        export::vec4(self, "vec4<f32>", precision)
+    }
+
     #[cfg(feature = "ansi_term")]
     fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
         &self,
@@ -818,6 +1916,26 @@ mod tests {
         assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
     }
 
+    #[test]
+    fn can_snap_to_web_safe() {
+        assert_eq!(rgb(0, 25, 26).to_web_safe(), rgba(0, 0, 51, 1.0));
+        assert_eq!(rgb(100, 149, 237).to_web_safe(), rgba(102, 153, 255, 1.0));
+        assert_eq!(
+            rgba(100, 149, 237, 0.5).to_web_safe(),
+            rgba(102, 153, 255, 0.5)
+        );
+        assert_eq!(hsl(0, 100, 50).to_web_safe(), rgba(255, 0, 0, 1.0));
+        assert_eq!(
+            hsla(219, 79, 66, 0.5).to_web_safe(),
+            rgba(102, 153, 255, 0.5)
+        );
+
+        for color in [rgb(255, 99, 71), rgb(23, 98, 119), rgb(1, 2, 3)] {
+            assert!(color.to_web_safe().is_web_safe());
+            assert_eq!(color.to_web_safe().to_web_safe(), color.to_web_safe());
+        }
+    }
+
     #[test]
     fn can_clone() {
         let rgb_color = rgb(5, 10, 15);
@@ -0,0 +1,111 @@
+use super::{Ratio, RGB};
+
+// Converts a gamma-encoded sRGB channel into linear light.
+pub(crate) fn to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Converts a linear light channel back into its gamma-encoded sRGB value.
+pub(crate) fn from_linear(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Splits a color into its linear light channels.
+pub(crate) fn linear_channels(rgb: RGB) -> [f32; 3] {
+    [
+        to_linear(rgb.r.as_f32()),
+        to_linear(rgb.g.as_f32()),
+        to_linear(rgb.b.as_f32()),
+    ]
+}
+
+// Builds a color from linear light channels. Channels falling outside of the
+// sRGB gamut are clipped.
+pub(crate) fn from_linear_channels([r, g, b]: [f32; 3]) -> RGB {
+    let channel = |value: f32| {
+        let value = from_linear(value);
+
+        // NaN can only come from invalid input, so treat it as no light at all.
+        Ratio::from_f32(if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        })
+    };
+
+    RGB {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+// Converts linear sRGB channels into D65 CIE XYZ, Y being the relative
+// luminance.
+#[allow(clippy::excessive_precision)]
+pub(crate) fn to_xyz([r, g, b]: [f64; 3]) -> [f64; 3] {
+    [
+        0.4123907993 * r + 0.3575843394 * g + 0.1804807884 * b,
+        0.2126390059 * r + 0.7151686788 * g + 0.0721923154 * b,
+        0.0193308187 * r + 0.1191947798 * g + 0.9505321522 * b,
+    ]
+}
+
+// Converts D65 CIE XYZ into linear sRGB channels, which fall outside of the
+// 0.0-1.0 range for colors outside of the sRGB gamut.
+#[allow(clippy::excessive_precision)]
+pub(crate) fn from_xyz([x, y, z]: [f64; 3]) -> [f64; 3] {
+    [
+        3.2409699419 * x - 1.5373831776 * y - 0.4986107603 * z,
+        -0.9692436363 * x + 1.8759675015 * y + 0.0415550574 * z,
+        0.0556300797 * x - 0.2039769589 * y + 1.0569715142 * z,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_linear, from_linear_channels, from_xyz, linear_channels, to_linear, to_xyz};
+    use crate::rgb;
+
+    #[test]
+    fn can_round_trip_channels() {
+        for value in 0..=255 {
+            let color = rgb(value, value, value);
+
+            assert_eq!(from_linear_channels(linear_channels(color)), color);
+        }
+    }
+
+    #[test]
+    fn can_convert_channels() {
+        assert_eq!(to_linear(0.0), 0.0);
+        assert_eq!(to_linear(1.0), 1.0);
+        assert!((to_linear(0.5) - 0.2140).abs() < 0.0001);
+        assert!((from_linear(0.2140) - 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn can_round_trip_xyz() {
+        let white = to_xyz([1.0, 1.0, 1.0]);
+        assert!((white[0] - 0.95046).abs() < 1e-5 && (white[1] - 1.0).abs() < 1e-9);
+
+        let linear = from_xyz(to_xyz([0.2, 0.5, 0.9]));
+        assert!(linear
+            .iter()
+            .zip([0.2, 0.5, 0.9])
+            .all(|(lhs, rhs)| (lhs - rhs).abs() < 1e-6));
+    }
+
+    #[test]
+    fn clips_out_of_gamut_channels() {
+        assert_eq!(from_linear_channels([1.5, -0.5, f32::NAN]), rgb(255, 0, 0));
+    }
+}
@@ -0,0 +1,85 @@
+use super::{linear::linear_channels, RGB};
+
+/// The relative luminance above which a color is considered light by `is_light()`.
+///
+/// This is the point at which black and white text have the same WCAG 2.x
+/// contrast ratio against the color, so light colors are the ones where black
+/// text is the more readable choice.
+pub const LIGHT_THRESHOLD: f32 = 0.179;
+
+// The relative luminance of a color, as defined by WCAG 2.x and Rec. 709.
+// The channels are linearized first, as the weights only apply to linear light.
+pub(crate) fn relative_luminance(rgb: RGB) -> f32 {
+    let [r, g, b] = linear_channels(rgb);
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+// The HSP perceived brightness of a color (http://alienryderflex.com/hsp.html).
+// This operates on the gamma-encoded channels directly, and approximates how
+// bright a color appears rather than how much light it emits.
+pub(crate) fn perceived_brightness(rgb: RGB) -> f32 {
+    let r = rgb.r.as_f32();
+    let g = rgb.g.as_f32();
+    let b = rgb.b.as_f32();
+
+    (0.299 * r * r + 0.587 * g * g + 0.114 * b * b).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, rgb, rgba, Color};
+
+    #[test]
+    fn can_compute_relative_luminance() {
+        assert_eq!(rgb(0, 0, 0).relative_luminance(), 0.0);
+        assert!((rgb(255, 255, 255).relative_luminance() - 1.0).abs() < 0.0001);
+        assert!((rgb(255, 0, 0).relative_luminance() - 0.2126).abs() < 0.0001);
+        assert!((rgb(0, 255, 0).relative_luminance() - 0.7152).abs() < 0.0001);
+        assert!((rgb(0, 0, 255).relative_luminance() - 0.0722).abs() < 0.0001);
+        assert!((rgb(128, 128, 128).relative_luminance() - 0.2158).abs() < 0.0001);
+    }
+
+    #[test]
+    fn can_compute_perceived_brightness() {
+        assert_eq!(rgb(0, 0, 0).perceived_brightness(), 0.0);
+        assert!((rgb(255, 255, 255).perceived_brightness() - 1.0).abs() < 0.0001);
+        assert!((rgb(128, 128, 128).perceived_brightness() - 0.5020).abs() < 0.0001);
+        assert!((rgb(0, 0, 255).perceived_brightness() - 0.3376).abs() < 0.0001);
+    }
+
+    #[test]
+    fn can_check_lightness() {
+        assert!(rgb(255, 255, 255).is_light());
+        assert!(rgb(255, 255, 0).is_light());
+        assert!(rgb(0, 0, 0).is_dark());
+        assert!(rgb(0, 0, 255).is_dark());
+        assert!(hsl(0, 0, 50).is_light());
+        assert!(hsl(0, 0, 45).is_dark());
+    }
+
+    #[test]
+    fn can_check_lightness_with_threshold() {
+        let grey = rgb(128, 128, 128);
+
+        assert!(grey.is_light_with(0.2));
+        assert!(!grey.is_dark_with(0.2));
+        assert!(!grey.is_light_with(0.5));
+        assert!(grey.is_dark_with(0.5));
+        assert!(rgb(0, 0, 0).is_dark_with(0.0));
+    }
+
+    #[test]
+    fn ignores_alpha_and_color_model() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(
+            rgba(255, 99, 71, 0.5).relative_luminance(),
+            tomato.relative_luminance()
+        );
+        assert_eq!(
+            hsl(0, 0, 50).perceived_brightness(),
+            rgb(128, 128, 128).perceived_brightness()
+        );
+    }
+}
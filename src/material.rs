@@ -0,0 +1,550 @@
+use super::{hct, Color, Palette, Ratio, RGB, RGBA};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A Material Design 3 tonal palette: every tone of a single hue and chroma
+/// in HCT, from black (tone `0.0`) to white (tone `100.0`).
+///
+/// For more, see the [Material Design documentation](https://m3.material.io/styles/color/the-color-system/key-colors-tones).
+///
+/// # Example
+/// ```
+/// use farver::{rgb, TonalPalette};
+///
+/// let primary = TonalPalette::from_color(rgb(103, 80, 164));
+///
+/// assert_eq!(primary.tone(100.0), rgb(255, 255, 255));
+/// assert_eq!(primary.to_palette().len(), 13);
+/// ```
+pub struct TonalPalette {
+    // hue, in degrees
+    pub hue: f32,
+
+    // chroma
+    pub chroma: f32,
+}
+
+impl TonalPalette {
+    /// The tones Material Design uses in its color schemes.
+    pub const TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+    /// Constructs a tonal palette from a hue and chroma in HCT.
+    pub fn new(hue: f32, chroma: f32) -> Self {
+        TonalPalette {
+            hue: hct(hue, chroma, 50.0).h,
+            chroma,
+        }
+    }
+
+    /// Constructs a tonal palette with the hue and chroma of `seed`.
+    pub fn from_color<T: Color>(seed: T) -> Self {
+        let seed = seed.to_hct();
+
+        TonalPalette::new(seed.h, seed.c)
+    }
+
+    /// Returns the color of the palette with the given tone, between `0.0`
+    /// and `100.0`. Tones that cannot be shown with the chroma of the palette
+    /// are given the highest chroma available.
+    pub fn tone(self, tone: f32) -> RGB {
+        hct(self.hue, self.chroma, tone).to_rgb()
+    }
+
+    /// Collects the standard `TONES` of the palette, from black to white,
+    /// each named after its tone.
+    pub fn to_palette(self) -> Palette {
+        let mut palette = Palette::new();
+
+        for tone in TonalPalette::TONES {
+            palette.push_named(tone.to_string(), self.tone(tone as f32));
+        }
+
+        palette
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The key tonal palettes of a Material Design 3 color scheme, all derived
+/// from a single seed color as dynamic color does.
+pub struct CorePalettes {
+    /// The palette of the seed color, keeping its hue.
+    pub primary: TonalPalette,
+
+    /// A toned down palette of the seed hue.
+    pub secondary: TonalPalette,
+
+    /// A palette of the seed hue rotated by 60°.
+    pub tertiary: TonalPalette,
+
+    /// A nearly grey palette of the seed hue, for surfaces and backgrounds.
+    pub neutral: TonalPalette,
+
+    /// A slightly more colorful neutral palette, for outlines and variants.
+    pub neutral_variant: TonalPalette,
+
+    /// The palette of errors, a fixed red.
+    pub error: TonalPalette,
+}
+
+impl CorePalettes {
+    /// Derives the key tonal palettes from `seed`, following the classic
+    /// Material Design 3 recipe: the primary palette keeps the seed hue with
+    /// a chroma of at least 48, the secondary and neutral palettes tone it
+    /// down, the tertiary palette is rotated 60° away, and the error palette
+    /// is a fixed red.
+    pub fn from_color<T: Color>(seed: T) -> Self {
+        let seed = seed.to_hct();
+
+        CorePalettes {
+            primary: TonalPalette::new(seed.h, seed.c.max(48.0)),
+            secondary: TonalPalette::new(seed.h, 16.0),
+            tertiary: TonalPalette::new(seed.h + 60.0, 24.0),
+            neutral: TonalPalette::new(seed.h, 4.0),
+            neutral_variant: TonalPalette::new(seed.h, 8.0),
+            error: TonalPalette::new(25.0, 84.0),
+        }
+    }
+
+    /// Maps the palettes onto the roles of a light color scheme.
+    pub fn light_scheme(&self) -> MaterialScheme {
+        MaterialScheme::new(self, false)
+    }
+
+    /// Maps the palettes onto the roles of a dark color scheme.
+    pub fn dark_scheme(&self) -> MaterialScheme {
+        MaterialScheme::new(self, true)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that holds the colors of every role of a Material Design 3 color
+/// scheme, as built by `CorePalettes::light_scheme()` and `dark_scheme()`.
+///
+/// For more, see the [Material Design documentation](https://m3.material.io/styles/color/roles).
+///
+/// # Example
+/// ```
+/// use farver::{rgb, MaterialScheme};
+///
+/// let light = MaterialScheme::light(rgb(0, 0, 255));
+/// let dark = MaterialScheme::dark(rgb(0, 0, 255));
+///
+/// assert_eq!(light.primary, rgb(52, 61, 255));
+/// assert_eq!(light.on_primary, rgb(255, 255, 255));
+/// assert_eq!(dark.primary, rgb(190, 194, 255));
+/// ```
+pub struct MaterialScheme {
+    /// The main color of the scheme, for prominent components like filled buttons.
+    pub primary: RGB,
+
+    /// The color of text and icons on `primary`.
+    pub on_primary: RGB,
+
+    /// A less prominent fill than `primary`, for components standing out less.
+    pub primary_container: RGB,
+
+    /// The color of text and icons on `primary_container`.
+    pub on_primary_container: RGB,
+
+    /// The color of less prominent components, like filter chips.
+    pub secondary: RGB,
+
+    /// The color of text and icons on `secondary`.
+    pub on_secondary: RGB,
+
+    /// A less prominent fill than `secondary`.
+    pub secondary_container: RGB,
+
+    /// The color of text and icons on `secondary_container`.
+    pub on_secondary_container: RGB,
+
+    /// A contrasting accent, to balance `primary` and `secondary`.
+    pub tertiary: RGB,
+
+    /// The color of text and icons on `tertiary`.
+    pub on_tertiary: RGB,
+
+    /// A less prominent fill than `tertiary`.
+    pub tertiary_container: RGB,
+
+    /// The color of text and icons on `tertiary_container`.
+    pub on_tertiary_container: RGB,
+
+    /// The color of errors, like invalid text fields.
+    pub error: RGB,
+
+    /// The color of text and icons on `error`.
+    pub on_error: RGB,
+
+    /// A less prominent fill than `error`.
+    pub error_container: RGB,
+
+    /// The color of text and icons on `error_container`.
+    pub on_error_container: RGB,
+
+    /// The color behind scrollable content.
+    pub background: RGB,
+
+    /// The color of text and icons on `background`.
+    pub on_background: RGB,
+
+    /// The color of surfaces, like cards, sheets and menus.
+    pub surface: RGB,
+
+    /// The color of text and icons on `surface`.
+    pub on_surface: RGB,
+
+    /// A surface color set apart from `surface`.
+    pub surface_variant: RGB,
+
+    /// The color of less prominent text and icons on `surface`, or of those on `surface_variant`.
+    pub on_surface_variant: RGB,
+
+    /// The color of important boundaries, like the outline of text fields.
+    pub outline: RGB,
+
+    /// The color of decorative boundaries, like dividers.
+    pub outline_variant: RGB,
+
+    /// The color of the shadows of elevated components.
+    pub shadow: RGB,
+
+    /// The color of the scrims covering content behind modal components.
+    pub scrim: RGB,
+
+    /// The surface color of components contrasting with the rest of the scheme, like snackbars.
+    pub inverse_surface: RGB,
+
+    /// The color of text and icons on `inverse_surface`.
+    pub inverse_on_surface: RGB,
+
+    /// The color of actionable elements on `inverse_surface`, like the button of a snackbar.
+    pub inverse_primary: RGB,
+}
+
+impl MaterialScheme {
+    /// Builds the light color scheme of `seed`.
+    pub fn light<T: Color>(seed: T) -> Self {
+        CorePalettes::from_color(seed).light_scheme()
+    }
+
+    /// Builds the dark color scheme of `seed`.
+    pub fn dark<T: Color>(seed: T) -> Self {
+        CorePalettes::from_color(seed).dark_scheme()
+    }
+
+    fn new(palettes: &CorePalettes, dark: bool) -> Self {
+        let tone = |palette: TonalPalette, light_tone: u8, dark_tone: u8| {
+            palette.tone(if dark { dark_tone } else { light_tone } as f32)
+        };
+
+        MaterialScheme {
+            primary: tone(palettes.primary, 40, 80),
+            on_primary: tone(palettes.primary, 100, 20),
+            primary_container: tone(palettes.primary, 90, 30),
+            on_primary_container: tone(palettes.primary, 10, 90),
+            secondary: tone(palettes.secondary, 40, 80),
+            on_secondary: tone(palettes.secondary, 100, 20),
+            secondary_container: tone(palettes.secondary, 90, 30),
+            on_secondary_container: tone(palettes.secondary, 10, 90),
+            tertiary: tone(palettes.tertiary, 40, 80),
+            on_tertiary: tone(palettes.tertiary, 100, 20),
+            tertiary_container: tone(palettes.tertiary, 90, 30),
+            on_tertiary_container: tone(palettes.tertiary, 10, 90),
+            error: tone(palettes.error, 40, 80),
+            on_error: tone(palettes.error, 100, 20),
+            error_container: tone(palettes.error, 90, 30),
+            on_error_container: tone(palettes.error, 10, 90),
+            background: tone(palettes.neutral, 99, 10),
+            on_background: tone(palettes.neutral, 10, 90),
+            surface: tone(palettes.neutral, 99, 10),
+            on_surface: tone(palettes.neutral, 10, 90),
+            surface_variant: tone(palettes.neutral_variant, 90, 30),
+            on_surface_variant: tone(palettes.neutral_variant, 30, 80),
+            outline: tone(palettes.neutral_variant, 50, 60),
+            outline_variant: tone(palettes.neutral_variant, 80, 30),
+            shadow: tone(palettes.neutral, 0, 0),
+            scrim: tone(palettes.neutral, 0, 0),
+            inverse_surface: tone(palettes.neutral, 20, 90),
+            inverse_on_surface: tone(palettes.neutral, 95, 20),
+            inverse_primary: tone(palettes.primary, 80, 40),
+        }
+    }
+
+    /// Collects the colors of every role, each named after its role in
+    /// kebab case like the Material Design tokens (`on-primary`).
+    pub fn to_palette(&self) -> Palette {
+        let mut palette = Palette::new();
+
+        for (name, color) in [
+            ("primary", self.primary),
+            ("on-primary", self.on_primary),
+            ("primary-container", self.primary_container),
+            ("on-primary-container", self.on_primary_container),
+            ("secondary", self.secondary),
+            ("on-secondary", self.on_secondary),
+            ("secondary-container", self.secondary_container),
+            ("on-secondary-container", self.on_secondary_container),
+            ("tertiary", self.tertiary),
+            ("on-tertiary", self.on_tertiary),
+            ("tertiary-container", self.tertiary_container),
+            ("on-tertiary-container", self.on_tertiary_container),
+            ("error", self.error),
+            ("on-error", self.on_error),
+            ("error-container", self.error_container),
+            ("on-error-container", self.on_error_container),
+            ("background", self.background),
+            ("on-background", self.on_background),
+            ("surface", self.surface),
+            ("on-surface", self.on_surface),
+            ("surface-variant", self.surface_variant),
+            ("on-surface-variant", self.on_surface_variant),
+            ("outline", self.outline),
+            ("outline-variant", self.outline_variant),
+            ("shadow", self.shadow),
+            ("scrim", self.scrim),
+            ("inverse-surface", self.inverse_surface),
+            ("inverse-on-surface", self.inverse_on_surface),
+            ("inverse-primary", self.inverse_primary),
+        ] {
+            palette.push_named(name, color);
+        }
+
+        palette
+    }
+}
+
+/// Returns the opacity of the white overlay Material Design lays over dark
+/// theme surfaces at the given elevation, in dp. It grows logarithmically,
+/// from `0.0` at rest to about `0.16` at 24dp, matching the Android Material
+/// Components.
+///
+/// # Example
+/// ```
+/// use farver::elevation_overlay_alpha;
+///
+/// assert_eq!(elevation_overlay_alpha(0.0), 0.0);
+/// assert_eq!(format!("{:.2}", elevation_overlay_alpha(1.0)), "0.05");
+/// assert_eq!(format!("{:.2}", elevation_overlay_alpha(24.0)), "0.16");
+/// ```
+pub fn elevation_overlay_alpha(elevation: f32) -> f32 {
+    if elevation <= 0.0 {
+        return 0.0;
+    }
+
+    (4.5 * (elevation + 1.0).ln() + 2.0) / 100.0
+}
+
+/// Computes the color of a dark theme `surface` at the given elevation, in
+/// dp, by compositing the white overlay of `elevation_overlay_alpha()` over
+/// it. The alpha value of the surface, if any, is kept.
+///
+/// For more, see the [Material Design documentation](https://m2.material.io/design/color/dark-theme.html#properties).
+///
+/// # Example
+/// ```
+/// use farver::{elevated_surface, rgb, rgba};
+///
+/// let surface = rgb(18, 18, 18);
+///
+/// assert_eq!(elevated_surface(surface, 0.0), rgba(18, 18, 18, 1.0));
+/// assert_eq!(elevated_surface(surface, 1.0), rgba(30, 30, 30, 1.0));
+/// assert_eq!(elevated_surface(surface, 2.0), rgba(34, 34, 34, 1.0));
+/// ```
+pub fn elevated_surface<T: Color>(surface: T, elevation: f32) -> RGBA {
+    let RGBA { r, g, b, a } = surface.to_rgba();
+    let alpha = elevation_overlay_alpha(elevation);
+
+    let overlay =
+        |channel: Ratio| Ratio::from_f32(channel.as_f32() + (1.0 - channel.as_f32()) * alpha);
+
+    RGBA {
+        r: overlay(r),
+        g: overlay(g),
+        b: overlay(b),
+        a,
+    }
+}
+
+// Rotates the HCT hue of `color` toward that of `toward`, by `amount` of the
+// difference between them and by at most 15°, keeping its chroma, tone, and
+// alpha. With an amount of 50%, this matches `Blend.harmonize` of Google's
+// material-color-utilities.
+pub(crate) fn harmonize<T: Color>(color: RGBA, toward: T, amount: Ratio) -> RGBA {
+    let from = color.to_hct();
+    let to = toward.to_hct();
+
+    let difference = 180.0 - ((from.h - to.h).abs() - 180.0).abs();
+    let rotation = (difference * amount.as_f32()).min(15.0);
+    let direction = if (to.h - from.h).rem_euclid(360.0) <= 180.0 {
+        1.0
+    } else {
+        -1.0
+    };
+
+    let RGB { r, g, b } = hct(from.h + rotation * direction, from.c, from.t).to_rgb();
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{hsl, percent, rgb, rgba, Color, CorePalettes, MaterialScheme, TonalPalette};
+
+    #[test]
+    fn can_build_tonal_palettes() {
+        // These match the tones of Google's material-color-utilities.
+        let blue = TonalPalette::from_color(rgb(0, 0, 255));
+        let tones: Vec<String> = [100, 95, 90, 80, 70, 60, 50, 40, 30, 20, 10, 0]
+            .iter()
+            .map(|&tone| blue.tone(tone as f32).to_hex())
+            .collect();
+
+        assert_eq!(
+            tones,
+            vec![
+                "#ffffff", "#f1efff", "#e0e0ff", "#bec2ff", "#9da3ff", "#7c84ff", "#5a64ff",
+                "#343dff", "#0000ef", "#0001ac", "#00006e", "#000000"
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_seed_hue_and_chroma() {
+        let seed = rgb(103, 80, 164);
+        let palette = TonalPalette::from_color(seed);
+
+        assert_eq!(palette.hue, seed.to_hct().h);
+        assert_eq!(palette.chroma, seed.to_hct().c);
+        assert_eq!(palette.tone(seed.to_hct().t), seed);
+        assert_eq!(TonalPalette::new(-90.0, 10.0).hue, 270.0);
+    }
+
+    #[test]
+    fn can_collect_tones() {
+        let palette = TonalPalette::from_color(rgb(0, 0, 255)).to_palette();
+
+        assert_eq!(palette.len(), TonalPalette::TONES.len());
+        assert_eq!(palette.name(0), Some("0"));
+        assert_eq!(palette.get_named("40"), Some(rgba(52, 61, 255, 1.0)));
+        assert_eq!(palette.get_named("100"), Some(rgba(255, 255, 255, 1.0)));
+    }
+
+    #[test]
+    fn can_build_light_schemes() {
+        let scheme = MaterialScheme::light(rgb(0, 0, 255));
+
+        assert_eq!(scheme.primary.to_hex(), "#343dff");
+        assert_eq!(scheme.on_primary.to_hex(), "#ffffff");
+        assert_eq!(scheme.primary_container.to_hex(), "#e0e0ff");
+        assert_eq!(scheme.on_primary_container.to_hex(), "#00006e");
+        assert_eq!(scheme.inverse_primary.to_hex(), "#bec2ff");
+        assert_eq!(scheme.shadow, rgb(0, 0, 0));
+        assert!(scheme.surface.is_light() && scheme.on_surface.is_dark());
+    }
+
+    #[test]
+    fn can_build_dark_schemes() {
+        let scheme = MaterialScheme::dark(rgb(0, 0, 255));
+
+        assert_eq!(scheme.primary.to_hex(), "#bec2ff");
+        assert_eq!(scheme.on_primary.to_hex(), "#0001ac");
+        assert_eq!(scheme.primary_container.to_hex(), "#0000ef");
+        assert_eq!(scheme.on_primary_container.to_hex(), "#e0e0ff");
+        assert!(scheme.surface.is_dark() && scheme.on_surface.is_light());
+    }
+
+    #[test]
+    fn derives_core_palettes() {
+        let palettes = CorePalettes::from_color(rgb(103, 80, 164));
+        let seed = rgb(103, 80, 164).to_hct();
+
+        assert_eq!(palettes.primary.chroma, 48.0);
+        assert_eq!(palettes.secondary, TonalPalette::new(seed.h, 16.0));
+        assert!((palettes.tertiary.hue - (seed.h + 60.0) % 360.0).abs() < 1e-3);
+        assert_eq!(palettes.error, TonalPalette::new(25.0, 84.0));
+        assert_eq!(
+            palettes.light_scheme(),
+            MaterialScheme::light(rgb(103, 80, 164))
+        );
+    }
+
+    #[test]
+    fn can_collect_roles() {
+        let scheme = MaterialScheme::light(rgb(0, 0, 255));
+        let palette = scheme.to_palette();
+
+        assert_eq!(palette.len(), 29);
+        assert_eq!(palette.name(1), Some("on-primary"));
+        assert_eq!(
+            palette.get_named("primary-container"),
+            Some(scheme.primary_container.to_rgba())
+        );
+    }
+
+    #[test]
+    fn can_harmonize() {
+        // These match the blends of Google's material-color-utilities.
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 255, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(
+            red.harmonize(blue, percent(50)).to_rgb().to_hex(),
+            "#fb0057"
+        );
+        assert_eq!(
+            red.harmonize(green, percent(50)).to_rgb().to_hex(),
+            "#d85600"
+        );
+        assert_eq!(
+            blue.harmonize(red, percent(50)).to_rgb().to_hex(),
+            "#5700dc"
+        );
+    }
+
+    #[test]
+    fn harmonize_keeps_chroma_tone_and_alpha() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(
+            tomato.harmonize(rgb(0, 0, 255), percent(0)),
+            tomato.to_rgba()
+        );
+        assert_eq!(tomato.harmonize(tomato, percent(100)), tomato.to_rgba());
+
+        let harmonized = tomato.harmonize(rgb(0, 0, 255), percent(100)).to_hct();
+        assert!((harmonized.t - tomato.to_hct().t).abs() < 0.5);
+
+        let translucent = rgba(255, 99, 71, 0.5).harmonize(rgb(0, 0, 255), percent(50));
+        assert_eq!(translucent.a, rgba(255, 99, 71, 0.5).a);
+        assert_eq!(
+            hsl(9, 100, 64)
+                .harmonize(rgb(0, 0, 255), percent(50))
+                .to_hsl(),
+            rgb(255, 99, 71)
+                .harmonize(rgb(0, 0, 255), percent(50))
+                .to_hsl()
+        );
+    }
+
+    #[test]
+    fn can_elevate_surfaces() {
+        let surface = rgb(18, 18, 18);
+        let levels: Vec<u8> = [0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 24.0]
+            .iter()
+            .map(|&elevation| crate::elevated_surface(surface, elevation).r.as_u8())
+            .collect();
+
+        assert_eq!(levels, vec![18, 30, 34, 38, 40, 43, 46, 50, 53, 57]);
+        assert_eq!(
+            crate::elevated_surface(rgba(18, 18, 18, 0.5), 8.0).a,
+            rgba(18, 18, 18, 0.5).a
+        );
+        assert_eq!(crate::elevated_surface(surface, -4.0), surface.to_rgba());
+    }
+}
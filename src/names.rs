@@ -0,0 +1,266 @@
+use super::{rgb, Color, OKLCH, RGB};
+
+// The named colors of CSS, sorted by name. Some colors have several names,
+// such as the "gray" and "grey" spellings.
+//
+// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#named-colors).
+pub(crate) const KEYWORDS: [(&str, RGB); 148] = [
+    ("aliceblue", rgb(0xF0, 0xF8, 0xFF)),
+    ("antiquewhite", rgb(0xFA, 0xEB, 0xD7)),
+    ("aqua", rgb(0x00, 0xFF, 0xFF)),
+    ("aquamarine", rgb(0x7F, 0xFF, 0xD4)),
+    ("azure", rgb(0xF0, 0xFF, 0xFF)),
+    ("beige", rgb(0xF5, 0xF5, 0xDC)),
+    ("bisque", rgb(0xFF, 0xE4, 0xC4)),
+    ("black", rgb(0x00, 0x00, 0x00)),
+    ("blanchedalmond", rgb(0xFF, 0xEB, 0xCD)),
+    ("blue", rgb(0x00, 0x00, 0xFF)),
+    ("blueviolet", rgb(0x8A, 0x2B, 0xE2)),
+    ("brown", rgb(0xA5, 0x2A, 0x2A)),
+    ("burlywood", rgb(0xDE, 0xB8, 0x87)),
+    ("cadetblue", rgb(0x5F, 0x9E, 0xA0)),
+    ("chartreuse", rgb(0x7F, 0xFF, 0x00)),
+    ("chocolate", rgb(0xD2, 0x69, 0x1E)),
+    ("coral", rgb(0xFF, 0x7F, 0x50)),
+    ("cornflowerblue", rgb(0x64, 0x95, 0xED)),
+    ("cornsilk", rgb(0xFF, 0xF8, 0xDC)),
+    ("crimson", rgb(0xDC, 0x14, 0x3C)),
+    ("cyan", rgb(0x00, 0xFF, 0xFF)),
+    ("darkblue", rgb(0x00, 0x00, 0x8B)),
+    ("darkcyan", rgb(0x00, 0x8B, 0x8B)),
+    ("darkgoldenrod", rgb(0xB8, 0x86, 0x0B)),
+    ("darkgray", rgb(0xA9, 0xA9, 0xA9)),
+    ("darkgreen", rgb(0x00, 0x64, 0x00)),
+    ("darkgrey", rgb(0xA9, 0xA9, 0xA9)),
+    ("darkkhaki", rgb(0xBD, 0xB7, 0x6B)),
+    ("darkmagenta", rgb(0x8B, 0x00, 0x8B)),
+    ("darkolivegreen", rgb(0x55, 0x6B, 0x2F)),
+    ("darkorange", rgb(0xFF, 0x8C, 0x00)),
+    ("darkorchid", rgb(0x99, 0x32, 0xCC)),
+    ("darkred", rgb(0x8B, 0x00, 0x00)),
+    ("darksalmon", rgb(0xE9, 0x96, 0x7A)),
+    ("darkseagreen", rgb(0x8F, 0xBC, 0x8F)),
+    ("darkslateblue", rgb(0x48, 0x3D, 0x8B)),
+    ("darkslategray", rgb(0x2F, 0x4F, 0x4F)),
+    ("darkslategrey", rgb(0x2F, 0x4F, 0x4F)),
+    ("darkturquoise", rgb(0x00, 0xCE, 0xD1)),
+    ("darkviolet", rgb(0x94, 0x00, 0xD3)),
+    ("deeppink", rgb(0xFF, 0x14, 0x93)),
+    ("deepskyblue", rgb(0x00, 0xBF, 0xFF)),
+    ("dimgray", rgb(0x69, 0x69, 0x69)),
+    ("dimgrey", rgb(0x69, 0x69, 0x69)),
+    ("dodgerblue", rgb(0x1E, 0x90, 0xFF)),
+    ("firebrick", rgb(0xB2, 0x22, 0x22)),
+    ("floralwhite", rgb(0xFF, 0xFA, 0xF0)),
+    ("forestgreen", rgb(0x22, 0x8B, 0x22)),
+    ("fuchsia", rgb(0xFF, 0x00, 0xFF)),
+    ("gainsboro", rgb(0xDC, 0xDC, 0xDC)),
+    ("ghostwhite", rgb(0xF8, 0xF8, 0xFF)),
+    ("gold", rgb(0xFF, 0xD7, 0x00)),
+    ("goldenrod", rgb(0xDA, 0xA5, 0x20)),
+    ("gray", rgb(0x80, 0x80, 0x80)),
+    ("green", rgb(0x00, 0x80, 0x00)),
+    ("greenyellow", rgb(0xAD, 0xFF, 0x2F)),
+    ("grey", rgb(0x80, 0x80, 0x80)),
+    ("honeydew", rgb(0xF0, 0xFF, 0xF0)),
+    ("hotpink", rgb(0xFF, 0x69, 0xB4)),
+    ("indianred", rgb(0xCD, 0x5C, 0x5C)),
+    ("indigo", rgb(0x4B, 0x00, 0x82)),
+    ("ivory", rgb(0xFF, 0xFF, 0xF0)),
+    ("khaki", rgb(0xF0, 0xE6, 0x8C)),
+    ("lavender", rgb(0xE6, 0xE6, 0xFA)),
+    ("lavenderblush", rgb(0xFF, 0xF0, 0xF5)),
+    ("lawngreen", rgb(0x7C, 0xFC, 0x00)),
+    ("lemonchiffon", rgb(0xFF, 0xFA, 0xCD)),
+    ("lightblue", rgb(0xAD, 0xD8, 0xE6)),
+    ("lightcoral", rgb(0xF0, 0x80, 0x80)),
+    ("lightcyan", rgb(0xE0, 0xFF, 0xFF)),
+    ("lightgoldenrodyellow", rgb(0xFA, 0xFA, 0xD2)),
+    ("lightgray", rgb(0xD3, 0xD3, 0xD3)),
+    ("lightgreen", rgb(0x90, 0xEE, 0x90)),
+    ("lightgrey", rgb(0xD3, 0xD3, 0xD3)),
+    ("lightpink", rgb(0xFF, 0xB6, 0xC1)),
+    ("lightsalmon", rgb(0xFF, 0xA0, 0x7A)),
+    ("lightseagreen", rgb(0x20, 0xB2, 0xAA)),
+    ("lightskyblue", rgb(0x87, 0xCE, 0xFA)),
+    ("lightslategray", rgb(0x77, 0x88, 0x99)),
+    ("lightslategrey", rgb(0x77, 0x88, 0x99)),
+    ("lightsteelblue", rgb(0xB0, 0xC4, 0xDE)),
+    ("lightyellow", rgb(0xFF, 0xFF, 0xE0)),
+    ("lime", rgb(0x00, 0xFF, 0x00)),
+    ("limegreen", rgb(0x32, 0xCD, 0x32)),
+    ("linen", rgb(0xFA, 0xF0, 0xE6)),
+    ("magenta", rgb(0xFF, 0x00, 0xFF)),
+    ("maroon", rgb(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", rgb(0x66, 0xCD, 0xAA)),
+    ("mediumblue", rgb(0x00, 0x00, 0xCD)),
+    ("mediumorchid", rgb(0xBA, 0x55, 0xD3)),
+    ("mediumpurple", rgb(0x93, 0x70, 0xDB)),
+    ("mediumseagreen", rgb(0x3C, 0xB3, 0x71)),
+    ("mediumslateblue", rgb(0x7B, 0x68, 0xEE)),
+    ("mediumspringgreen", rgb(0x00, 0xFA, 0x9A)),
+    ("mediumturquoise", rgb(0x48, 0xD1, 0xCC)),
+    ("mediumvioletred", rgb(0xC7, 0x15, 0x85)),
+    ("midnightblue", rgb(0x19, 0x19, 0x70)),
+    ("mintcream", rgb(0xF5, 0xFF, 0xFA)),
+    ("mistyrose", rgb(0xFF, 0xE4, 0xE1)),
+    ("moccasin", rgb(0xFF, 0xE4, 0xB5)),
+    ("navajowhite", rgb(0xFF, 0xDE, 0xAD)),
+    ("navy", rgb(0x00, 0x00, 0x80)),
+    ("oldlace", rgb(0xFD, 0xF5, 0xE6)),
+    ("olive", rgb(0x80, 0x80, 0x00)),
+    ("olivedrab", rgb(0x6B, 0x8E, 0x23)),
+    ("orange", rgb(0xFF, 0xA5, 0x00)),
+    ("orangered", rgb(0xFF, 0x45, 0x00)),
+    ("orchid", rgb(0xDA, 0x70, 0xD6)),
+    ("palegoldenrod", rgb(0xEE, 0xE8, 0xAA)),
+    ("palegreen", rgb(0x98, 0xFB, 0x98)),
+    ("paleturquoise", rgb(0xAF, 0xEE, 0xEE)),
+    ("palevioletred", rgb(0xDB, 0x70, 0x93)),
+    ("papayawhip", rgb(0xFF, 0xEF, 0xD5)),
+    ("peachpuff", rgb(0xFF, 0xDA, 0xB9)),
+    ("peru", rgb(0xCD, 0x85, 0x3F)),
+    ("pink", rgb(0xFF, 0xC0, 0xCB)),
+    ("plum", rgb(0xDD, 0xA0, 0xDD)),
+    ("powderblue", rgb(0xB0, 0xE0, 0xE6)),
+    ("purple", rgb(0x80, 0x00, 0x80)),
+    ("rebeccapurple", rgb(0x66, 0x33, 0x99)),
+    ("red", rgb(0xFF, 0x00, 0x00)),
+    ("rosybrown", rgb(0xBC, 0x8F, 0x8F)),
+    ("royalblue", rgb(0x41, 0x69, 0xE1)),
+    ("saddlebrown", rgb(0x8B, 0x45, 0x13)),
+    ("salmon", rgb(0xFA, 0x80, 0x72)),
+    ("sandybrown", rgb(0xF4, 0xA4, 0x60)),
+    ("seagreen", rgb(0x2E, 0x8B, 0x57)),
+    ("seashell", rgb(0xFF, 0xF5, 0xEE)),
+    ("sienna", rgb(0xA0, 0x52, 0x2D)),
+    ("silver", rgb(0xC0, 0xC0, 0xC0)),
+    ("skyblue", rgb(0x87, 0xCE, 0xEB)),
+    ("slateblue", rgb(0x6A, 0x5A, 0xCD)),
+    ("slategray", rgb(0x70, 0x80, 0x90)),
+    ("slategrey", rgb(0x70, 0x80, 0x90)),
+    ("snow", rgb(0xFF, 0xFA, 0xFA)),
+    ("springgreen", rgb(0x00, 0xFF, 0x7F)),
+    ("steelblue", rgb(0x46, 0x82, 0xB4)),
+    ("tan", rgb(0xD2, 0xB4, 0x8C)),
+    ("teal", rgb(0x00, 0x80, 0x80)),
+    ("thistle", rgb(0xD8, 0xBF, 0xD8)),
+    ("tomato", rgb(0xFF, 0x63, 0x47)),
+    ("turquoise", rgb(0x40, 0xE0, 0xD0)),
+    ("violet", rgb(0xEE, 0x82, 0xEE)),
+    ("wheat", rgb(0xF5, 0xDE, 0xB3)),
+    ("white", rgb(0xFF, 0xFF, 0xFF)),
+    ("whitesmoke", rgb(0xF5, 0xF5, 0xF5)),
+    ("yellow", rgb(0xFF, 0xFF, 0x00)),
+    ("yellowgreen", rgb(0x9A, 0xCD, 0x32)),
+];
+
+// Returns the CSS keyword for `color`, if it has one. When several keywords
+// name the same color, the shortest is picked, then the first alphabetically.
+pub(crate) fn to_name<T: Color>(color: T) -> Option<&'static str> {
+    let rgba = color.to_rgba();
+
+    match rgba.a.as_u8() {
+        0 if rgba.to_rgb() == rgb(0, 0, 0) => Some("transparent"),
+        255 => KEYWORDS
+            .iter()
+            .filter(|(_, keyword)| *keyword == rgba.to_rgb())
+            .map(|(name, _)| *name)
+            .min_by_key(|name| name.len()),
+        _ => None,
+    }
+}
+
+// Describes `color` in plain words from its OKLCH coordinates, as an optional
+// lightness, an optional colorfulness, and a hue or shade of grey.
+pub(crate) fn describe<T: Color>(color: T) -> String {
+    let OKLCH { l, c, h } = color.to_oklch();
+
+    if c < 0.02 {
+        let grey = match l {
+            l if l < 0.15 => "black",
+            l if l < 0.45 => "dark grey",
+            l if l < 0.8 => "grey",
+            l if l < 0.97 => "light grey",
+            _ => "white",
+        };
+
+        return grey.to_string();
+    }
+
+    let hue = match h {
+        h if h < 15.0 => "pink",
+        h if h < 45.0 => "red",
+        h if h < 75.0 => "orange",
+        h if h < 115.0 => "yellow",
+        h if h < 165.0 => "green",
+        h if h < 230.0 => "cyan",
+        h if h < 285.0 => "blue",
+        h if h < 330.0 => "purple",
+        _ => "pink",
+    };
+
+    let modifiers = match (l, c) {
+        (l, c) if l >= 0.8 && c < 0.1 => vec!["pale"],
+        (l, c) if l < 0.4 && c < 0.07 => vec!["dark", "desaturated"],
+        (l, c) if l < 0.4 && c >= 0.2 => vec!["dark", "vivid"],
+        (l, _) if l < 0.4 => vec!["dark"],
+        (l, _) if l >= 0.8 => vec!["light"],
+        (_, c) if c < 0.07 => vec!["desaturated"],
+        (_, c) if c >= 0.2 => vec!["vivid"],
+        _ => vec![],
+    };
+
+    let mut words = modifiers;
+    words.push(hue);
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KEYWORDS;
+    use crate::{hsl, rgb, rgba, Color};
+
+    #[test]
+    fn is_sorted() {
+        assert!(KEYWORDS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn can_find_names() {
+        assert_eq!(rgb(102, 51, 153).to_name(), Some("rebeccapurple"));
+        assert_eq!(rgb(255, 99, 71).to_name(), Some("tomato"));
+        assert_eq!(hsl(0, 0, 100).to_name(), Some("white"));
+        assert_eq!(rgb(255, 99, 72).to_name(), None);
+    }
+
+    #[test]
+    fn picks_the_shortest_name() {
+        assert_eq!(rgb(0, 255, 255).to_name(), Some("aqua"));
+        assert_eq!(rgb(255, 0, 255).to_name(), Some("fuchsia"));
+        assert_eq!(rgb(128, 128, 128).to_name(), Some("gray"));
+        assert_eq!(rgb(47, 79, 79).to_name(), Some("darkslategray"));
+    }
+
+    #[test]
+    fn can_describe() {
+        assert_eq!(rgb(255, 0, 0).describe(), "vivid red");
+        assert_eq!(rgb(128, 0, 0).describe(), "dark red");
+        assert_eq!(rgb(90, 60, 60).describe(), "dark desaturated red");
+        assert_eq!(rgb(176, 196, 222).describe(), "pale blue");
+        assert_eq!(rgb(255, 182, 193).describe(), "pale pink");
+        assert_eq!(rgb(144, 238, 144).describe(), "light green");
+        assert_eq!(rgb(0, 128, 0).describe(), "green");
+        assert_eq!(rgb(128, 128, 128).describe(), "grey");
+        assert_eq!(rgb(0, 0, 0).describe(), "black");
+        assert_eq!(rgb(255, 255, 255).describe(), "white");
+    }
+
+    #[test]
+    fn handles_alpha() {
+        assert_eq!(rgba(255, 99, 71, 1.0).to_name(), Some("tomato"));
+        assert_eq!(rgba(255, 99, 71, 0.5).to_name(), None);
+        assert_eq!(rgba(0, 0, 0, 0.0).to_name(), Some("transparent"));
+        assert_eq!(rgba(255, 255, 255, 0.0).to_name(), None);
+    }
+}
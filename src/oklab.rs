@@ -0,0 +1,449 @@
+use super::{
+    gamut::fit_in_gamut,
+    linear::{from_linear_channels, linear_channels, to_xyz},
+    xyz, Gamut, RGB, XYZ,
+};
+use std::fmt;
+
+/// Constructs an OKLab Color from numerical values, similar to the
+/// [`oklab` function](css-oklab) in CSS.
+///
+/// The lightness component ranges between `0.0` and `1.0`. The `a` and `b`
+/// components are unbounded, but fall roughly between `-0.4` and `0.4`.
+///
+/// # Example
+/// ```
+/// use farver::oklab;
+///
+/// let salmon = oklab(0.7, 0.1, 0.05);
+///
+/// assert_eq!(salmon.to_string(), "oklab(70.00% 0.1000 0.0500)");
+/// ```
+///
+/// [css-oklab]: https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+pub fn oklab(l: f32, a: f32, b: f32) -> OKLAB {
+    OKLAB { l, a, b }
+}
+
+/// Constructs an OKLCH Color from numerical values, similar to the
+/// [`oklch` function](css-oklch) in CSS.
+///
+/// The lightness component ranges between `0.0` and `1.0`. The chroma
+/// component is unbounded, but falls roughly between `0.0` and `0.4`. The
+/// hue component is expressed in degrees. Values outside of the 0-360°
+/// range will be normalized accordingly.
+///
+/// # Example
+/// ```
+/// use farver::oklch;
+///
+/// let salmon = oklch(0.7, 0.15, 390.0);
+///
+/// assert_eq!(salmon.to_string(), "oklch(70.00% 0.1500 30.00)");
+/// ```
+///
+/// [css-oklch]: https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+pub fn oklch(l: f32, c: f32, h: f32) -> OKLCH {
+    OKLCH {
+        l,
+        c,
+        h: normalize_hue(h),
+    }
+}
+
+// Wraps a hue in degrees into the 0-360° range.
+pub(crate) fn normalize_hue(hue: f32) -> f32 {
+    let hue = hue.rem_euclid(360.0);
+
+    // rem_euclid can round up to exactly 360.0 for tiny negative inputs.
+    if hue >= 360.0 {
+        0.0
+    } else {
+        hue
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in the perceptually uniform OKLab color space.
+///
+/// The lightness `l` ranges between `0.0` (black) and `1.0` (white), while `a`
+/// (green to red) and `b` (blue to yellow) describe the hue and colorfulness.
+///
+/// For more, see [Björn Ottosson's introduction](https://bottosson.github.io/posts/oklab/).
+pub struct OKLAB {
+    // lightness
+    pub l: f32,
+
+    // green-red axis
+    pub a: f32,
+
+    // blue-yellow axis
+    pub b: f32,
+}
+
+// The matrices below are kept at the precision published by Björn Ottosson.
+#[allow(clippy::excessive_precision)]
+impl OKLAB {
+    pub(crate) fn from_rgb(rgb: RGB) -> Self {
+        Self::from_linear(linear_channels(rgb))
+    }
+
+    // Converts linear sRGB channels into OKLab, including channels outside of
+    // the 0.0-1.0 range.
+    pub(crate) fn from_linear([r, g, b]: [f32; 3]) -> Self {
+        let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
+        let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
+        let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
+
+        let l = l.cbrt();
+        let m = m.cbrt();
+        let s = s.cbrt();
+
+        OKLAB {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Converts `self` into its RGB representation. Colors falling outside of
+    /// the sRGB gamut are clipped.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_oklab().to_rgb(), tomato);
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        from_linear_channels(self.to_linear())
+    }
+
+    // Converts `self` into linear sRGB channels, which fall outside of the
+    // 0.0-1.0 range for colors outside of the sRGB gamut.
+    pub(crate) fn to_linear(self) -> [f32; 3] {
+        let OKLAB { l, a, b } = self;
+
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    }
+
+    /// Computes the perceptual difference between `self` and `other` as their
+    /// euclidean distance in OKLab, also known as ΔEOK. A difference of about
+    /// `0.02` is just noticeable.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let white = rgb(255, 255, 255).to_oklab();
+    /// let black = rgb(0, 0, 0).to_oklab();
+    ///
+    /// assert_eq!(format!("{:.2}", white.distance(black)), "1.00");
+    /// assert_eq!(white.distance(white), 0.0);
+    /// ```
+    pub fn distance(self, other: OKLAB) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// Returns whether `self` can be represented in the `gamut`, with every
+    /// linear channel allowed to stray up to `tolerance` outside of the
+    /// `0.0`-`1.0` range, to absorb rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklab, Gamut};
+    ///
+    /// // A vivid green, which only wide gamut displays can show.
+    /// let green = oklab(0.8, -0.22, 0.16);
+    ///
+    /// assert!(!green.in_gamut(Gamut::Srgb, 1e-4));
+    /// assert!(green.in_gamut(Gamut::DisplayP3, 1e-4));
+    /// ```
+    pub fn in_gamut(self, gamut: Gamut, tolerance: f32) -> bool {
+        gamut.contains(self.to_linear(), tolerance)
+    }
+
+    /// Maps `self` into the `gamut` the way the CSS Color spec has browsers
+    /// do it, lowering its OKLCH chroma until clipping it leaves an
+    /// unnoticeable difference. Its lightness and hue are kept up to that
+    /// final clip, which can still shift them slightly.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklch, Gamut};
+    ///
+    /// let green = oklch(0.8, 0.35, 145.0).to_oklab().fit_in_gamut(Gamut::Srgb);
+    ///
+    /// assert!(green.in_gamut(Gamut::Srgb, 1e-4));
+    /// ```
+    pub fn fit_in_gamut(self, gamut: Gamut) -> OKLAB {
+        fit_in_gamut(self.to_oklch(), gamut).to_oklab()
+    }
+
+    /// Clips `self` into the `gamut` channel by channel. This is cheaper than
+    /// [`OKLAB::fit_in_gamut`], but shifts the hue and lightness of colors
+    /// far outside of the gamut, such as vivid blues turning purple.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklch, Gamut};
+    ///
+    /// let green = oklch(0.8, 0.35, 145.0).to_oklab().clip_to_gamut(Gamut::Srgb);
+    ///
+    /// assert!(green.in_gamut(Gamut::Srgb, 1e-4));
+    /// ```
+    pub fn clip_to_gamut(self, gamut: Gamut) -> OKLAB {
+        OKLAB::from_linear(gamut.clip(self.to_linear()))
+    }
+
+    /// Converts `self` into CIE XYZ, keeping values out of range.
+    pub fn to_xyz(self) -> XYZ {
+        let [x, y, z] = to_xyz(self.to_linear().map(|channel| channel as f64));
+        xyz(x as f32, y as f32, z as f32)
+    }
+
+    /// Returns whether every component of `self` is finite, which conversions
+    /// of infinite or NaN values can't restore.
+    pub fn is_finite(self) -> bool {
+        self.l.is_finite() && self.a.is_finite() && self.b.is_finite()
+    }
+
+    /// Converts `self` into its cylindrical OKLCH representation.
+    pub fn to_oklch(self) -> OKLCH {
+        let OKLAB { l, a, b } = self;
+
+        let c = (a * a + b * b).sqrt();
+
+        // Greys have no meaningful hue, so pick 0 rather than amplifying noise.
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            normalize_hue(b.atan2(a).to_degrees())
+        };
+
+        OKLCH { l, c, h }
+    }
+}
+
+impl fmt::Display for OKLAB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "oklab({:.02}% {:.04} {:.04})",
+            self.l * 100.0,
+            self.a,
+            self.b
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in OKLCH, the cylindrical form of OKLab.
+///
+/// The lightness `l` ranges between `0.0` (black) and `1.0` (white), the chroma
+/// `c` starts at `0.0` for greys, and the hue `h` is a degree on the color wheel
+/// between `0.0` and `360.0`.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch).
+pub struct OKLCH {
+    // lightness
+    pub l: f32,
+
+    // chroma
+    pub c: f32,
+
+    // hue
+    pub h: f32,
+}
+
+impl OKLCH {
+    /// Converts `self` into its RGB representation. Colors falling outside of
+    /// the sRGB gamut are clipped.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_oklch().to_rgb(), tomato);
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.to_oklab().to_rgb()
+    }
+
+    /// Returns whether `self` can be represented in the `gamut`, with every
+    /// linear channel allowed to stray up to `tolerance` outside of the
+    /// `0.0`-`1.0` range, to absorb rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklch, Gamut};
+    ///
+    /// // The red primary of Display P3.
+    /// let red = oklch(0.65, 0.29, 29.0);
+    ///
+    /// assert!(!red.in_gamut(Gamut::Srgb, 1e-4));
+    /// assert!(red.in_gamut(Gamut::DisplayP3, 1e-4));
+    /// ```
+    pub fn in_gamut(self, gamut: Gamut, tolerance: f32) -> bool {
+        self.to_oklab().in_gamut(gamut, tolerance)
+    }
+
+    /// Maps `self` into the `gamut` the way the CSS Color spec has browsers
+    /// do it, lowering its chroma until clipping it leaves an unnoticeable
+    /// difference. Its lightness and hue are kept up to that final clip,
+    /// which can still shift them slightly, as with the red below. Colors as
+    /// light as white or as dark as black become white or black.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklch, Gamut};
+    ///
+    /// // The red primary of Display P3, on an sRGB display.
+    /// let red = oklch(0.65, 0.3, 29.0).fit_in_gamut(Gamut::Srgb);
+    ///
+    /// assert_eq!(red.to_string(), "oklch(63.17% 0.2539 28.98)");
+    /// ```
+    pub fn fit_in_gamut(self, gamut: Gamut) -> OKLCH {
+        fit_in_gamut(self, gamut)
+    }
+
+    /// Clips `self` into the `gamut` channel by channel, like converting it
+    /// to RGB does. This is cheaper than [`OKLCH::fit_in_gamut`], but shifts
+    /// the hue and lightness of colors far outside of the gamut.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{oklch, Gamut};
+    ///
+    /// let red = oklch(0.65, 0.3, 29.0);
+    ///
+    /// // Clipping keeps more chroma, but lets the hue and lightness drift.
+    /// assert_eq!(red.clip_to_gamut(Gamut::Srgb).to_string(), "oklch(62.80% 0.2577 29.23)");
+    /// assert_eq!(red.fit_in_gamut(Gamut::Srgb).to_string(), "oklch(63.17% 0.2539 28.98)");
+    /// ```
+    pub fn clip_to_gamut(self, gamut: Gamut) -> OKLCH {
+        self.to_oklab().clip_to_gamut(gamut).to_oklch()
+    }
+
+    /// Returns whether every component of `self` is finite, which conversions
+    /// of infinite or NaN values can't restore.
+    pub fn is_finite(self) -> bool {
+        self.l.is_finite() && self.c.is_finite() && self.h.is_finite()
+    }
+
+    /// Converts `self` into its rectangular OKLab representation.
+    pub fn to_oklab(self) -> OKLAB {
+        let OKLCH { l, c, h } = self;
+        let h = h.to_radians();
+
+        OKLAB {
+            l,
+            a: c * h.cos(),
+            b: c * h.sin(),
+        }
+    }
+}
+
+impl fmt::Display for OKLCH {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "oklch({:.02}% {:.04} {:.02})",
+            self.l * 100.0,
+            self.c,
+            self.h
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, oklab, oklch, rgb, rgba, Color};
+
+    #[test]
+    fn can_convert_reference_colors() {
+        assert_eq!(
+            rgb(255, 255, 255).to_oklab().to_string(),
+            "oklab(100.00% 0.0000 0.0000)"
+        );
+        assert_eq!(
+            rgb(0, 0, 0).to_oklab().to_string(),
+            "oklab(0.00% 0.0000 0.0000)"
+        );
+        assert_eq!(
+            rgb(255, 0, 0).to_oklab().to_string(),
+            "oklab(62.80% 0.2249 0.1258)"
+        );
+        assert_eq!(
+            rgb(255, 0, 0).to_oklch().to_string(),
+            "oklch(62.80% 0.2577 29.23)"
+        );
+        assert_eq!(
+            rgb(0, 0, 255).to_oklch().to_string(),
+            "oklch(45.20% 0.3132 264.05)"
+        );
+    }
+
+    #[test]
+    fn greys_have_no_hue() {
+        let grey = rgb(128, 128, 128).to_oklch();
+
+        assert!(grey.c < 1e-4);
+        assert_eq!(grey.h, 0.0);
+    }
+
+    #[test]
+    fn can_round_trip() {
+        for color in [
+            rgb(255, 99, 71),
+            rgb(23, 98, 119),
+            rgb(100, 149, 237),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ] {
+            assert_eq!(color.to_oklab().to_rgb(), color);
+            assert_eq!(color.to_oklch().to_rgb(), color);
+            assert_eq!(color.to_oklch().to_oklab().to_oklch().to_rgb(), color);
+        }
+
+        assert_eq!(
+            rgba(255, 99, 71, 0.5).to_oklab(),
+            rgb(255, 99, 71).to_oklab()
+        );
+        assert_eq!(hsl(0, 0, 100).to_oklab(), rgb(255, 255, 255).to_oklab());
+    }
+
+    #[test]
+    fn normalizes_hue() {
+        assert_eq!(oklch(0.5, 0.1, -90.0).h, 270.0);
+        assert_eq!(oklch(0.5, 0.1, 720.0).h, 0.0);
+        assert_eq!(oklch(0.5, 0.1, -1e-6).h, 0.0);
+    }
+
+    #[test]
+    fn clips_out_of_gamut_colors() {
+        assert_eq!(oklab(1.5, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(oklab(-0.5, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(oklch(0.6, 0.4, 30.0).to_rgb().r.as_u8(), 255);
+    }
+}
@@ -0,0 +1,846 @@
+use super::{
+    contrast_color::css, css::parse_color, difference, json::Json, ramp::fit_chroma, Color, HSL,
+    HSLA, LAB, RGB, RGBA,
+};
+use std::{slice, vec};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The orders a `Palette` can be sorted in, all ascending.
+pub enum SortKey {
+    /// By OKLCH hue, starting from the pinks and reds. Greys come first.
+    Hue,
+
+    /// By WCAG relative luminance, from the darkest colors to the lightest.
+    Luminance,
+
+    /// By HSL saturation, from the greys to the most saturated colors.
+    Saturation,
+
+    /// By OKLCH chroma, from the greys to the most colorful colors.
+    Chroma,
+
+    /// By hue in the given number of steps, with the colors of every other
+    /// step sorted from light to dark and the rest from dark to light, so
+    /// neighbouring colors stay similar. Eight steps is a good default.
+    ///
+    /// For more, see [Alan Zucconi's article](https://www.alanzucconi.com/2015/09/30/colour-sorting/).
+    Step(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// An ordered collection of colors, each of which can be given a name.
+///
+/// Colors are stored in their RGBA representation, so any alpha channel is
+/// preserved.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, hsl, rgba, Palette};
+///
+/// let mut palette = Palette::new();
+/// palette.push(rgb(255, 255, 255));
+/// palette.push_named("tomato", hsl(9, 100, 64));
+///
+/// assert_eq!(palette.len(), 2);
+/// assert_eq!(palette.get_named("tomato"), Some(rgba(255, 99, 71, 1.0)));
+/// assert_eq!(palette.to_hex(), vec!["#ffffff", "#ff6347"]);
+/// ```
+pub struct Palette {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    name: Option<String>,
+    color: RGBA,
+
+    // what the color is used for in a theme, like "background" or "accent"
+    role: Option<String>,
+}
+
+impl Palette {
+    /// Constructs an empty palette.
+    pub fn new() -> Self {
+        Palette::default()
+    }
+
+    /// Returns the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends an unnamed color to the end of the palette.
+    pub fn push<T: Color>(&mut self, color: T) {
+        self.entries.push(Entry {
+            name: None,
+            color: color.to_rgba(),
+            role: None,
+        });
+    }
+
+    /// Appends a named color to the end of the palette. Names need not be
+    /// unique, but only the first color with a given name can be looked up.
+    pub fn push_named<T: Color>(&mut self, name: impl Into<String>, color: T) {
+        self.entries.push(Entry {
+            name: Some(name.into()),
+            color: color.to_rgba(),
+            role: None,
+        });
+    }
+
+    /// Inserts an unnamed color at `index`, shifting the following colors.
+    /// Panics if `index` is greater than the length of the palette.
+    pub fn insert<T: Color>(&mut self, index: usize, color: T) {
+        self.entries.insert(
+            index,
+            Entry {
+                name: None,
+                color: color.to_rgba(),
+                role: None,
+            },
+        );
+    }
+
+    /// Removes and returns the color at `index`, shifting the following
+    /// colors. Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> RGBA {
+        self.entries.remove(index).color
+    }
+
+    /// Returns the color at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<RGBA> {
+        self.entries.get(index).map(|entry| entry.color)
+    }
+
+    /// Returns the first color named `name`, if any.
+    pub fn get_named(&self, name: &str) -> Option<RGBA> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.as_deref() == Some(name))
+            .map(|entry| entry.color)
+    }
+
+    /// Returns the name of the color at `index`, if it has one.
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.entries.get(index)?.name.as_deref()
+    }
+
+    /// Sets the role of the color at `index`, what it is used for in a theme,
+    /// like `"background"` or `"accent"`. Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("salmon", rgb(250, 128, 114));
+    /// palette.set_role(0, "accent");
+    ///
+    /// assert_eq!(palette.role(0), Some("accent"));
+    /// assert_eq!(palette.get_role("accent"), Some(rgba(250, 128, 114, 1.0)));
+    /// ```
+    pub fn set_role(&mut self, index: usize, role: impl Into<String>) {
+        self.entries[index].role = Some(role.into());
+    }
+
+    /// Returns the role of the color at `index`, if it has one.
+    pub fn role(&self, index: usize) -> Option<&str> {
+        self.entries.get(index)?.role.as_deref()
+    }
+
+    /// Returns the first color with the role `role`, if any.
+    pub fn get_role(&self, role: &str) -> Option<RGBA> {
+        self.entries
+            .iter()
+            .find(|entry| entry.role.as_deref() == Some(role))
+            .map(|entry| entry.color)
+    }
+
+    /// Iterates over the colors of the palette, in order.
+    pub fn iter(&self) -> PaletteIter<'_> {
+        PaletteIter(self.entries.iter())
+    }
+
+    /// Iterates over the colors of the palette along with their names, in
+    /// order.
+    pub fn iter_named(&self) -> impl Iterator<Item = (Option<&str>, RGBA)> + '_ {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_deref(), entry.color))
+    }
+
+    /// Sorts the palette in the order of `key`, keeping the names with their
+    /// colors. The sort is stable, so equal colors keep their order.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette, SortKey};
+    ///
+    /// let mut palette: Palette = [rgb(255, 255, 255), rgb(0, 0, 0), rgb(255, 99, 71)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// palette.sort_by(SortKey::Luminance);
+    ///
+    /// assert_eq!(palette.to_hex(), vec!["#000000", "#ff6347", "#ffffff"]);
+    /// ```
+    pub fn sort_by(&mut self, key: SortKey) {
+        match key {
+            SortKey::Hue => self.sort_by_f32(|color| color.to_oklch().h),
+            SortKey::Luminance => self.sort_by_f32(|color| color.relative_luminance()),
+            SortKey::Saturation => self.sort_by_f32(|color| color.to_hsl().s.as_f32()),
+            SortKey::Chroma => self.sort_by_f32(|color| color.to_oklch().c),
+            SortKey::Step(steps) => {
+                let steps = steps.max(1) as u16;
+
+                self.entries.sort_by_cached_key(|entry| {
+                    let RGB { r, g, b } = entry.color.to_rgb();
+                    let step = |value: f32| ((value * steps as f32) as u16).min(steps - 1);
+
+                    let hue = step(entry.color.to_hsl().h.degrees() as f32 / 360.0);
+                    let brightness = step(entry.color.perceived_brightness());
+                    let value = step(r.max(g).max(b).as_f32());
+
+                    if hue % 2 == 0 {
+                        (hue, brightness, value)
+                    } else {
+                        (hue, steps - 1 - brightness, steps - 1 - value)
+                    }
+                });
+            }
+        }
+    }
+
+    fn sort_by_f32(&mut self, key: impl Fn(RGBA) -> f32) {
+        self.entries
+            .sort_by_cached_key(|entry| OrderedF32(key(entry.color)));
+    }
+
+    /// Removes the colors whose CIEDE2000 difference to an earlier color of
+    /// the palette is below `delta_e`, keeping the earliest color of every
+    /// group of similar colors along with its name. A threshold of about
+    /// `1.0` removes the colors that look identical. Alpha values are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette: Palette = [rgb(255, 99, 71), rgb(254, 100, 70), rgb(23, 98, 119)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// palette.dedup_within(1.0);
+    ///
+    /// assert_eq!(palette.to_hex(), vec!["#ff6347", "#176277"]);
+    /// ```
+    pub fn dedup_within(&mut self, delta_e: f32) {
+        let mut kept: Vec<LAB> = Vec::with_capacity(self.entries.len());
+
+        self.entries.retain(|entry| {
+            let lab = entry.color.to_lab();
+
+            if kept
+                .iter()
+                .any(|&other| difference::delta_e2000(lab, other) < delta_e)
+            {
+                false
+            } else {
+                kept.push(lab);
+                true
+            }
+        });
+    }
+
+    /// Derives a dark variant of a light palette. The OKLCH lightness of every
+    /// color is inverted, so white backgrounds turn dark and dark text turns
+    /// light, keeping the contrast between them. Colorful colors lose some of
+    /// their chroma, as saturated colors look glaring on dark backgrounds.
+    /// Hues, names, and alpha values are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Color, Palette};
+    ///
+    /// let mut light = Palette::new();
+    /// light.push_named("background", rgb(255, 255, 255));
+    /// light.push_named("text", rgb(17, 17, 17));
+    /// light.push_named("accent", rgb(23, 98, 119));
+    ///
+    /// let dark = light.to_dark_mode();
+    ///
+    /// assert!(dark.get_named("background").unwrap().is_dark());
+    /// assert!(dark.get_named("text").unwrap().is_light());
+    /// assert!(dark.get_named("accent").unwrap().is_light());
+    /// ```
+    pub fn to_dark_mode(&self) -> Palette {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| Entry {
+                name: entry.name.clone(),
+                color: dark_variant(entry.color),
+                role: entry.role.clone(),
+            })
+            .collect();
+
+        Palette { entries }
+    }
+
+    /// Converts every color of the palette into its RGB representation.
+    pub fn to_rgb(&self) -> Vec<RGB> {
+        self.iter().map(|color| color.to_rgb()).collect()
+    }
+
+    /// Converts every color of the palette into its RGBA representation.
+    pub fn to_rgba(&self) -> Vec<RGBA> {
+        self.iter().collect()
+    }
+
+    /// Converts every color of the palette into its HSL representation.
+    pub fn to_hsl(&self) -> Vec<HSL> {
+        self.iter().map(|color| color.to_hsl()).collect()
+    }
+
+    /// Converts every color of the palette into its HSLA representation.
+    pub fn to_hsla(&self) -> Vec<HSLA> {
+        self.iter().map(|color| color.to_hsla()).collect()
+    }
+
+    /// Converts every color of the palette to its CSS string format. Opaque
+    /// colors are written without an alpha channel.
+    pub fn to_css(&self) -> Vec<String> {
+        self.iter().map(css).collect()
+    }
+
+    /// Converts every color of the palette to its hexadecimal string format.
+    /// Opaque colors are written without an alpha channel.
+    pub fn to_hex(&self) -> Vec<String> {
+        self.iter()
+            .map(|color| {
+                if color.a.as_u8() == 255 {
+                    color.to_rgb().to_hex()
+                } else {
+                    color.to_hex()
+                }
+            })
+            .collect()
+    }
+}
+
+impl Palette {
+    /// Writes the palette in the JSON palette format of farver, an object
+    /// holding the list of its `colors`, each with its `color` as a hex
+    /// string and, if it has them, its `name` and `role`:
+    ///
+    /// ```json
+    /// {
+    ///   "colors": [
+    ///     {
+    ///       "name": "salmon",
+    ///       "color": "#fa8072",
+    ///       "role": "accent"
+    ///     },
+    ///     {
+    ///       "color": "#00000080"
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("salmon", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(Palette::from_json(&palette.to_json()), Some(palette));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let colors = self
+            .entries
+            .iter()
+            .zip(self.to_hex())
+            .map(|(entry, hex)| {
+                let mut fields = Vec::new();
+
+                if let Some(name) = &entry.name {
+                    fields.push(("name", Json::string(name)));
+                }
+                fields.push(("color", Json::String(hex)));
+                if let Some(role) = &entry.role {
+                    fields.push(("role", Json::string(role)));
+                }
+
+                Json::object(fields)
+            })
+            .collect();
+
+        Json::object([("colors", Json::Array(colors))]).to_string()
+    }
+
+    /// Reads a palette written in the JSON palette format of farver, as
+    /// described in `to_json()`. Colors may be given as any CSS color, and
+    /// unknown fields are ignored. Returns `None` if the input does not
+    /// follow the format.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Palette};
+    ///
+    /// let palette = Palette::from_json(r#"{"colors": [{"color": "salmon", "role": "accent"}]}"#).unwrap();
+    ///
+    /// assert_eq!(palette.get_role("accent"), Some(rgba(250, 128, 114, 1.0)));
+    /// assert_eq!(Palette::from_json(r#"{"colors": [{"name": "salmon"}]}"#), None);
+    /// ```
+    pub fn from_json(input: &str) -> Option<Palette> {
+        let json = Json::parse(input)?;
+        let mut palette = Palette::new();
+
+        for entry in json.get("colors")?.as_array()? {
+            let text = |key: &str| match entry.get(key) {
+                None | Some(Json::Null) => Some(None),
+                Some(value) => value.as_str().map(|text| Some(text.to_string())),
+            };
+
+            palette.entries.push(Entry {
+                name: text("name")?,
+                color: parse_color(entry.get("color")?.as_str()?)?,
+                role: text("role")?,
+            });
+        }
+
+        Some(palette)
+    }
+}
+
+// Orders floats totally, so they can be used as sort keys.
+#[derive(PartialEq)]
+struct OrderedF32(f32);
+
+impl Eq for OrderedF32 {}
+
+impl PartialOrd for OrderedF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF32 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+// Collects colors into a palette, leaving them unnamed.
+impl<T: Color> FromIterator<T> for Palette {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut palette = Palette::new();
+        palette.extend(iter);
+        palette
+    }
+}
+
+// Appends colors to a palette, leaving them unnamed.
+impl<T: Color> Extend<T> for Palette {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for color in iter {
+            self.push(color);
+        }
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = RGBA;
+    type IntoIter = PaletteIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PaletteIntoIter(self.entries.into_iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = RGBA;
+    type IntoIter = PaletteIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over the colors of a `Palette`, returned by `Palette::iter()`.
+pub struct PaletteIter<'a>(slice::Iter<'a, Entry>);
+
+impl Iterator for PaletteIter<'_> {
+    type Item = RGBA;
+
+    fn next(&mut self) -> Option<RGBA> {
+        self.0.next().map(|entry| entry.color)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for PaletteIter<'_> {
+    fn next_back(&mut self) -> Option<RGBA> {
+        self.0.next_back().map(|entry| entry.color)
+    }
+}
+
+impl ExactSizeIterator for PaletteIter<'_> {}
+
+#[derive(Debug)]
+/// An iterator moving the colors out of a `Palette`.
+pub struct PaletteIntoIter(vec::IntoIter<Entry>);
+
+impl Iterator for PaletteIntoIter {
+    type Item = RGBA;
+
+    fn next(&mut self) -> Option<RGBA> {
+        self.0.next().map(|entry| entry.color)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for PaletteIntoIter {
+    fn next_back(&mut self) -> Option<RGBA> {
+        self.0.next_back().map(|entry| entry.color)
+    }
+}
+
+impl ExactSizeIterator for PaletteIntoIter {}
+
+// Derives the dark mode variant of a color, as `Palette::to_dark_mode()`
+// does.
+pub(crate) fn dark_variant(color: RGBA) -> RGBA {
+    // White turns into a dark grey rather than black, and black into an
+    // off-white, as pure extremes are harsh in dark themes.
+    const DARKEST: f32 = 0.15;
+    const LIGHTEST: f32 = 0.95;
+
+    let lch = color.to_oklch();
+    let l = DARKEST + (LIGHTEST - DARKEST) * (1.0 - lch.l.clamp(0.0, 1.0));
+
+    // Chroma is reduced by up to 30%, the most for the most colorful.
+    let c = lch.c * (1.0 - 0.3 * (lch.c / 0.25).min(1.0));
+
+    let RGB { r, g, b } = fit_chroma(l, c, lch.h);
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, hsla, percent, rgb, rgba, Color, Palette, SortKey, RGBA};
+
+    #[test]
+    fn can_write_json() {
+        let mut palette = Palette::new();
+        palette.push_named("salmon", rgb(250, 128, 114));
+        palette.push(rgba(0, 0, 0, 0.5));
+        palette.set_role(0, "accent");
+
+        assert_eq!(
+            palette.to_json(),
+            r##"{
+  "colors": [
+    {
+      "name": "salmon",
+      "color": "#fa8072",
+      "role": "accent"
+    },
+    {
+      "color": "#00000080"
+    }
+  ]
+}
+"##
+        );
+        assert_eq!(Palette::new().to_json(), "{\n  \"colors\": []\n}\n");
+    }
+
+    #[test]
+    fn can_read_json() {
+        let mut palette = Palette::new();
+        palette.push_named("salmon \"pink\"", rgb(250, 128, 114));
+        palette.push(rgba(0, 0, 0, 0.5));
+        palette.set_role(1, "shadow");
+
+        let read = Palette::from_json(&palette.to_json()).unwrap();
+        assert_eq!(read, palette);
+        assert_eq!(read.role(1), Some("shadow"));
+
+        let read = Palette::from_json(
+            r#"{"version": 1, "colors": [{"color": "hsl(0, 100%, 50%)", "name": null, "extra": [1]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(read.get(0), Some(rgba(255, 0, 0, 1.0)));
+        assert_eq!(read.name(0), None);
+
+        for input in [
+            "",
+            "[]",
+            r#"{"colors": {}}"#,
+            r#"{"colors": [{"color": "nope"}]}"#,
+            r#"{"colors": [{"color": "red", "name": 1}]}"#,
+        ] {
+            assert_eq!(Palette::from_json(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn keeps_roles() {
+        let mut palette: Palette = [rgb(255, 255, 255), rgb(0, 0, 0)].into_iter().collect();
+        palette.set_role(0, "background");
+        palette.set_role(1, "text");
+
+        let dark = palette.to_dark_mode();
+        assert!(dark.get_role("background").unwrap().is_dark());
+        assert_eq!(dark.role(1), Some("text"));
+        assert_eq!(palette.get_role("accent"), None);
+        assert_eq!(palette.role(5), None);
+    }
+
+    fn palette() -> Palette {
+        let mut palette = Palette::new();
+        palette.push_named("white", rgb(255, 255, 255));
+        palette.push(rgba(23, 98, 119, 0.5));
+        palette.push_named("tomato", hsl(9, 100, 64));
+
+        palette
+    }
+
+    #[test]
+    fn can_insert_and_remove() {
+        let mut palette = palette();
+        palette.insert(0, rgb(0, 0, 0));
+
+        assert_eq!(palette.len(), 4);
+        assert_eq!(palette.get(0), Some(rgba(0, 0, 0, 1.0)));
+        assert_eq!(palette.name(1), Some("white"));
+
+        assert_eq!(palette.remove(1), rgba(255, 255, 255, 1.0));
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette.get_named("white"), None);
+        assert!(Palette::new().is_empty());
+    }
+
+    #[test]
+    fn can_look_up_colors() {
+        let mut palette = palette();
+        palette.push_named("tomato", rgb(0, 0, 0));
+
+        assert_eq!(palette.get(1), Some(rgba(23, 98, 119, 0.5)));
+        assert_eq!(palette.get(4), None);
+        assert_eq!(palette.get_named("tomato"), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(palette.get_named("teal"), None);
+        assert_eq!(palette.name(1), None);
+        assert_eq!(palette.name(2), Some("tomato"));
+        assert_eq!(palette.name(4), None);
+    }
+
+    #[test]
+    fn can_iterate() {
+        let palette = palette();
+
+        assert_eq!(palette.iter().count(), 3);
+        assert_eq!(
+            palette
+                .iter_named()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            vec![Some("white"), None, Some("tomato")]
+        );
+    }
+
+    #[test]
+    fn can_collect() {
+        let colors = [hsl(9, 100, 64), hsl(194, 67, 28)];
+        let palette: Palette = colors.iter().map(|c| c.lighten(percent(10))).collect();
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.to_hsl(), vec![hsl(9, 100, 74), hsl(194, 67, 38)]);
+        assert_eq!(palette.name(0), None);
+
+        let mut palette = palette;
+        palette.extend([rgb(0, 0, 0)]);
+        assert_eq!(palette.len(), 3);
+    }
+
+    #[test]
+    fn can_loop_over() {
+        let palette = palette();
+        let mut colors = Vec::new();
+
+        for color in &palette {
+            colors.push(color);
+        }
+
+        assert_eq!(colors, palette.to_rgba());
+        assert_eq!(palette.iter().len(), 3);
+        assert_eq!(palette.iter().next_back(), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(palette.into_iter().collect::<Vec<RGBA>>(), colors);
+    }
+
+    #[test]
+    fn can_sort() {
+        let colors = [
+            rgb(255, 255, 255),
+            rgb(23, 98, 119),
+            rgb(255, 99, 71),
+            rgb(0, 0, 0),
+            rgb(255, 255, 0),
+        ];
+        let palette: Palette = colors.into_iter().collect();
+        let sorted = |key| {
+            let mut palette = palette.clone();
+            palette.sort_by(key);
+            palette.to_hex()
+        };
+
+        assert_eq!(
+            sorted(SortKey::Hue),
+            vec!["#ffffff", "#000000", "#ff6347", "#ffff00", "#176277"]
+        );
+        assert_eq!(
+            sorted(SortKey::Luminance),
+            vec!["#000000", "#176277", "#ff6347", "#ffff00", "#ffffff"]
+        );
+        assert_eq!(
+            sorted(SortKey::Saturation),
+            vec!["#ffffff", "#000000", "#176277", "#ff6347", "#ffff00"]
+        );
+        // Black and white both have a chroma of about 0.
+        assert_eq!(
+            sorted(SortKey::Chroma)[2..],
+            ["#176277", "#ff6347", "#ffff00"]
+        );
+    }
+
+    #[test]
+    fn can_step_sort() {
+        let mut palette: Palette = [
+            hsl(0, 100, 30),
+            hsl(0, 100, 70),
+            hsl(60, 100, 30),
+            hsl(60, 100, 70),
+        ]
+        .into_iter()
+        .collect();
+        palette.sort_by(SortKey::Step(6));
+
+        // The reds go from dark to light, then the yellows from light to dark.
+        assert_eq!(
+            palette.to_hsl(),
+            vec![
+                hsl(0, 100, 30),
+                hsl(0, 100, 70),
+                hsl(60, 100, 70),
+                hsl(60, 100, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_names_when_sorting() {
+        let mut palette = palette();
+        palette.sort_by(SortKey::Luminance);
+
+        assert_eq!(palette.name(0), None);
+        assert_eq!(palette.name(1), Some("tomato"));
+        assert_eq!(palette.get_named("white"), palette.get(2));
+    }
+
+    #[test]
+    fn can_dedup() {
+        let mut palette = palette();
+        palette.push_named("tomato2", rgb(255, 100, 72));
+        palette.push(rgb(240, 240, 240));
+        palette.push(rgba(255, 255, 255, 0.2));
+        palette.dedup_within(1.0);
+
+        assert_eq!(palette.len(), 4);
+        assert_eq!(palette.name(0), Some("white"));
+        assert_eq!(palette.get_named("tomato2"), None);
+        assert_eq!(palette.get(3), Some(rgba(240, 240, 240, 1.0)));
+
+        palette.dedup_within(5.0);
+        assert_eq!(palette.len(), 3);
+
+        palette.dedup_within(0.0);
+        assert_eq!(palette.len(), 3);
+    }
+
+    #[test]
+    fn can_convert() {
+        let palette = palette();
+
+        assert_eq!(
+            palette.to_rgb(),
+            vec![rgb(255, 255, 255), rgb(23, 98, 119), rgb(255, 99, 71)]
+        );
+        assert_eq!(palette.to_hsla()[2], hsla(9, 100, 64, 1.0));
+        assert_eq!(
+            palette.to_css(),
+            vec![
+                "rgb(255, 255, 255)",
+                "rgba(23, 98, 119, 0.50)",
+                "rgb(255, 99, 71)"
+            ]
+        );
+        assert_eq!(palette.to_hex(), vec!["#ffffff", "#17627780", "#ff6347"]);
+    }
+
+    #[test]
+    fn can_derive_dark_mode() {
+        let dark = palette().to_dark_mode();
+
+        assert_eq!(dark.len(), palette().len());
+        assert_eq!(dark.name(1), palette().name(1));
+
+        // The lightness order of the colors is reversed.
+        for (lhs, rhs) in [(0, 1), (0, 2), (1, 2)] {
+            let light = palette();
+            assert_eq!(
+                light.get(lhs).unwrap().to_oklch().l < light.get(rhs).unwrap().to_oklch().l,
+                dark.get(lhs).unwrap().to_oklch().l > dark.get(rhs).unwrap().to_oklch().l
+            );
+        }
+
+        for (light, dark) in palette().iter().zip(dark.iter()) {
+            assert_eq!(light.a, dark.a);
+            assert!(dark.to_oklch().c <= light.to_oklch().c + 0.01);
+
+            if light.to_oklch().c > 0.05 {
+                assert!(light.hue_distance(dark, crate::HueSpace::OKLCH) < 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn dark_mode_keeps_contrast() {
+        let light: Palette = [rgb(255, 255, 255), rgb(0, 0, 0)].into_iter().collect();
+        let dark = light.to_dark_mode();
+
+        let background = dark.get(0).unwrap();
+        let text = dark.get(1).unwrap();
+
+        assert!(background.is_dark() && text.is_light());
+        assert!(text.contrast_ratio(background).as_f32() > 12.0);
+        assert!(Palette::new().to_dark_mode().is_empty());
+    }
+}
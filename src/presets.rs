@@ -0,0 +1,93 @@
+use super::{rgb, RGB};
+
+/// The eight colors of the Okabe–Ito palette, designed by Masataka Okabe and
+/// Kei Ito to stay distinguishable with every common color vision deficiency.
+/// In order: black, orange, sky blue, bluish green, yellow, blue, vermillion
+/// and reddish purple.
+///
+/// For more, see [Color Universal Design](https://jfly.uni-koeln.de/color/).
+pub const OKABE_ITO: [RGB; 8] = [
+    rgb(0x00, 0x00, 0x00),
+    rgb(0xE6, 0x9F, 0x00),
+    rgb(0x56, 0xB4, 0xE9),
+    rgb(0x00, 0x9E, 0x73),
+    rgb(0xF0, 0xE4, 0x42),
+    rgb(0x00, 0x72, 0xB2),
+    rgb(0xD5, 0x5E, 0x00),
+    rgb(0xCC, 0x79, 0xA7),
+];
+
+/// Paul Tol's bright qualitative palette. In order: blue, cyan, green,
+/// yellow, red, purple and grey.
+///
+/// For more, see [Paul Tol's notes](https://personal.sron.nl/~pault/).
+pub const TOL_BRIGHT: [RGB; 7] = [
+    rgb(0x44, 0x77, 0xAA),
+    rgb(0x66, 0xCC, 0xEE),
+    rgb(0x22, 0x88, 0x33),
+    rgb(0xCC, 0xBB, 0x44),
+    rgb(0xEE, 0x66, 0x77),
+    rgb(0xAA, 0x33, 0x77),
+    rgb(0xBB, 0xBB, 0xBB),
+];
+
+/// Paul Tol's muted qualitative palette. In order: rose, indigo, sand, green,
+/// cyan, wine, teal, olive, purple, and the pale grey meant for missing data.
+///
+/// For more, see [Paul Tol's notes](https://personal.sron.nl/~pault/).
+pub const TOL_MUTED: [RGB; 10] = [
+    rgb(0xCC, 0x66, 0x77),
+    rgb(0x33, 0x22, 0x88),
+    rgb(0xDD, 0xCC, 0x77),
+    rgb(0x11, 0x77, 0x33),
+    rgb(0x88, 0xCC, 0xEE),
+    rgb(0x88, 0x22, 0x55),
+    rgb(0x44, 0xAA, 0x99),
+    rgb(0x99, 0x99, 0x33),
+    rgb(0xAA, 0x44, 0x99),
+    rgb(0xDD, 0xDD, 0xDD),
+];
+
+/// Paul Tol's light qualitative palette, meant for filling cells behind dark
+/// text. In order: light blue, orange, light yellow, pink, light cyan, mint,
+/// pear, olive and pale grey.
+///
+/// For more, see [Paul Tol's notes](https://personal.sron.nl/~pault/).
+pub const TOL_LIGHT: [RGB; 9] = [
+    rgb(0x77, 0xAA, 0xDD),
+    rgb(0xEE, 0x88, 0x66),
+    rgb(0xEE, 0xDD, 0x88),
+    rgb(0xFF, 0xAA, 0xBB),
+    rgb(0x99, 0xDD, 0xFF),
+    rgb(0x44, 0xBB, 0x99),
+    rgb(0xBB, 0xCC, 0x33),
+    rgb(0xAA, 0xAA, 0x00),
+    rgb(0xDD, 0xDD, 0xDD),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{OKABE_ITO, TOL_BRIGHT, TOL_LIGHT, TOL_MUTED};
+    use crate::{check_cvd_safety, rgb, Cvd};
+
+    #[test]
+    fn has_reference_values() {
+        assert_eq!(OKABE_ITO[1], rgb(230, 159, 0));
+        assert_eq!(TOL_BRIGHT[0], rgb(68, 119, 170));
+        assert_eq!(TOL_MUTED[9], rgb(221, 221, 221));
+        assert_eq!(TOL_LIGHT[7], rgb(170, 170, 0));
+    }
+
+    #[test]
+    fn is_safe_for_red_green_deficiencies() {
+        for palette in [&OKABE_ITO[..], &TOL_BRIGHT, &TOL_MUTED, &TOL_LIGHT] {
+            for conflict in check_cvd_safety(palette, 0.03) {
+                assert!(
+                    !matches!(conflict.cvd, Cvd::Protanopia | Cvd::Deuteranopia),
+                    "{:?}",
+                    conflict
+                );
+            }
+        }
+    }
+}
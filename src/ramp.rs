@@ -0,0 +1,357 @@
+use super::{json::Json, max_chroma, oklch, rgb, Color, Gamut, Palette, Ratio, RGB, RGBA};
+
+// Returns whether the OKLCH color fits in the sRGB gamut, give or take
+// rounding errors.
+pub(crate) fn in_gamut(l: f32, c: f32, h: f32) -> bool {
+    oklch(l, c, h).in_gamut(Gamut::Srgb, 1e-4)
+}
+
+// Returns the OKLCH color with the given lightness and hue, and the highest
+// chroma up to `chroma` that fits in the sRGB gamut, so ramps keep their hue
+// instead of drifting when clipped.
+pub(crate) fn fit_chroma(l: f32, chroma: f32, h: f32) -> RGB {
+    if in_gamut(l, chroma, h) {
+        return oklch(l, chroma, h).to_rgb();
+    }
+
+    oklch(l, chroma.min(max_chroma(l, h, Gamut::Srgb)), h).to_rgb()
+}
+
+// Builds `n` variants of `color` evenly spaced in OKLCH lightness, from the
+// lightest to the darkest, keeping its hue, chroma where possible, and alpha.
+pub(crate) fn monochromatic<T: Color>(color: T, n: usize) -> Palette {
+    let color = color.to_rgba();
+    let a = color.a;
+    let lch = color.to_oklch();
+
+    (0..n)
+        .map(|i| {
+            let l = 1.0 - (i + 1) as f32 / (n + 1) as f32;
+            let RGB { r, g, b } = fit_chroma(l, lch.c, lch.h);
+
+            RGBA { r, g, b, a }
+        })
+        .collect()
+}
+
+/// The steps of a Tailwind CSS color scale, from the lightest to the darkest.
+pub const TAILWIND_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to tune the scales built by `tailwind_scale_with()`, one value per
+/// step of `TAILWIND_STEPS`.
+///
+/// The defaults follow the curves of the Tailwind CSS v4 palette: lightness
+/// falls steadily, while chroma peaks around the 500 and 600 steps and fades
+/// toward both ends.
+pub struct TailwindCurves {
+    // the OKLCH lightness of every step, between 0.0 and 1.0
+    pub lightness: [f32; 11],
+
+    // the chroma of every step, relative to the chroma of the brand color
+    pub chroma: [f32; 11],
+}
+
+impl Default for TailwindCurves {
+    fn default() -> Self {
+        TailwindCurves {
+            lightness: [
+                0.971, 0.936, 0.885, 0.81, 0.71, 0.63, 0.55, 0.48, 0.42, 0.37, 0.28,
+            ],
+            chroma: [0.1, 0.2, 0.35, 0.6, 0.85, 1.0, 1.0, 0.95, 0.8, 0.65, 0.45],
+        }
+    }
+}
+
+// Builds a Tailwind CSS scale around `color`, keeping its hue and alpha. The
+// step closest to it in lightness is `color` itself, so the brand color shows
+// up unchanged.
+pub(crate) fn tailwind_scale<T: Color>(color: T, curves: TailwindCurves) -> Palette {
+    let rgba = color.to_rgba();
+    let lch = rgba.to_oklch();
+
+    let anchor = (0..TAILWIND_STEPS.len())
+        .min_by(|&lhs, &rhs| {
+            let distance = |i: usize| (curves.lightness[i] - lch.l).abs();
+            distance(lhs).total_cmp(&distance(rhs))
+        })
+        .unwrap_or_default();
+
+    let mut palette = Palette::new();
+
+    for (i, step) in TAILWIND_STEPS.iter().enumerate() {
+        if i == anchor {
+            palette.push_named(step.to_string(), rgba);
+            continue;
+        }
+
+        let RGB { r, g, b } = fit_chroma(curves.lightness[i], lch.c * curves.chroma[i], lch.h);
+        palette.push_named(step.to_string(), RGBA { r, g, b, a: rgba.a });
+    }
+
+    palette
+}
+
+/// Writes color scales as the `theme.colors` object of a Tailwind CSS
+/// config, in JSON, with one nested object per scale keyed by the names of
+/// its colors, so scales from `tailwind_scale()` get their 50 to 950 steps.
+/// Unnamed colors are keyed by their position in the scale, from 1. Colors
+/// are written as hex strings.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, to_tailwind_colors, Color};
+///
+/// let brand = rgb(23, 98, 119).tailwind_scale();
+/// let colors = to_tailwind_colors(&[("brand", &brand)]);
+///
+/// assert!(colors.starts_with("{\n  \"brand\": {\n    \"50\": \"#"));
+/// assert!(colors.contains(r##""700": "#176277""##));
+/// ```
+pub fn to_tailwind_colors(scales: &[(&str, &Palette)]) -> String {
+    tailwind_colors(scales).to_string()
+}
+
+/// Writes color scales as a Tailwind CSS config module extending the theme
+/// with their colors, as `to_tailwind_colors()` writes them, to be saved as
+/// `tailwind.config.js`.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, to_tailwind_config, Palette};
+///
+/// let mut gray = Palette::new();
+/// gray.push_named("100", rgb(243, 244, 246));
+///
+/// assert_eq!(
+///     to_tailwind_config(&[("gray", &gray)]),
+///     r##"module.exports = {
+///   "theme": {
+///     "extend": {
+///       "colors": {
+///         "gray": {
+///           "100": "#f3f4f6"
+///         }
+///       }
+///     }
+///   }
+/// };
+/// "##
+/// );
+/// ```
+pub fn to_tailwind_config(scales: &[(&str, &Palette)]) -> String {
+    let extend = Json::object([("colors", tailwind_colors(scales))]);
+    let config = Json::object([("theme", Json::object([("extend", extend)]))]);
+
+    format!("module.exports = {};\n", config.to_string().trim_end())
+}
+
+fn tailwind_colors(scales: &[(&str, &Palette)]) -> Json {
+    Json::object(scales.iter().map(|(name, scale)| {
+        let steps =
+            scale
+                .iter_named()
+                .zip(scale.to_hex())
+                .enumerate()
+                .map(|(i, ((step, _), hex))| {
+                    let step = step.map_or_else(|| (i + 1).to_string(), str::to_string);
+                    (step, Json::String(hex))
+                });
+
+        (*name, Json::object(steps))
+    }))
+}
+
+// Builds `n` steps from `color` toward `target`, starting with `color` itself
+// and stopping one step short of `target`, mixing them like `mix()` does.
+fn ladder<T: Color>(color: T, n: usize, target: RGB) -> Palette {
+    let color = color.to_rgba();
+
+    (0..n)
+        .map(|i| {
+            let weight = Ratio::from_f32(1.0 - i as f32 / n as f32);
+            color.mix(target, weight)
+        })
+        .collect()
+}
+
+pub(crate) fn shades<T: Color>(color: T, n: usize) -> Palette {
+    ladder(color, n, rgb(0, 0, 0))
+}
+
+pub(crate) fn tints<T: Color>(color: T, n: usize) -> Palette {
+    ladder(color, n, rgb(255, 255, 255))
+}
+
+pub(crate) fn tones<T: Color>(color: T, n: usize) -> Palette {
+    ladder(color, n, rgb(128, 128, 128))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hsla, rgb, rgba, to_tailwind_colors, Color, Palette, TailwindCurves, TAILWIND_STEPS,
+    };
+
+    #[test]
+    fn can_write_tailwind_colors() {
+        let mut accent = Palette::new();
+        accent.push(rgb(255, 0, 0));
+        accent.push(rgba(0, 0, 0, 0.5));
+
+        let brand = rgb(23, 98, 119).tailwind_scale();
+        let colors = to_tailwind_colors(&[("brand", &brand), ("accent", &accent)]);
+
+        let keys: Vec<_> = colors
+            .lines()
+            .filter(|line| line.starts_with("    \""))
+            .map(|line| line.trim().split(':').next().unwrap())
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "\"50\"", "\"100\"", "\"200\"", "\"300\"", "\"400\"", "\"500\"", "\"600\"",
+                "\"700\"", "\"800\"", "\"900\"", "\"950\"", "\"1\"", "\"2\""
+            ]
+        );
+        assert!(colors.ends_with(
+            "  \"accent\": {\n    \"1\": \"#ff0000\",\n    \"2\": \"#00000080\"\n  }\n}\n"
+        ));
+        assert_eq!(to_tailwind_colors(&[]), "{}\n");
+    }
+
+    #[test]
+    fn can_build_tailwind_scales() {
+        let teal = rgb(23, 98, 119);
+        let scale = teal.tailwind_scale();
+
+        assert_eq!(scale.len(), 11);
+        assert_eq!(scale.name(0), Some("50"));
+        assert_eq!(scale.name(10), Some("950"));
+
+        // Teal sits closest to the 700 step in lightness.
+        assert_eq!(scale.get_named("700"), Some(teal.to_rgba()));
+
+        let lightness: Vec<f32> = scale.iter().map(|color| color.to_oklch().l).collect();
+        assert!(
+            lightness.windows(2).all(|pair| pair[0] > pair[1]),
+            "{:?}",
+            lightness
+        );
+
+        // Rounding to 8 bits shifts the hue of the palest steps.
+        for color in scale.iter().filter(|color| color.to_oklch().c > 0.03) {
+            assert!(teal.hue_distance(color, crate::HueSpace::OKLCH) < 3.0);
+        }
+    }
+
+    #[test]
+    fn can_tune_tailwind_curves() {
+        let curves = TailwindCurves {
+            chroma: [0.0; 11],
+            ..TailwindCurves::default()
+        };
+        let scale = rgb(255, 99, 71).tailwind_scale_with(curves);
+
+        for (i, color) in scale.iter().enumerate() {
+            if Some(color) != Some(rgba(255, 99, 71, 1.0)) {
+                assert!(
+                    color.r == color.g && color.g == color.b,
+                    "{}",
+                    TAILWIND_STEPS[i]
+                );
+            }
+        }
+
+        assert_eq!(
+            hsla(9, 100, 64, 0.5).tailwind_scale().get(0).unwrap().a,
+            hsla(9, 100, 64, 0.5).a
+        );
+    }
+
+    #[test]
+    fn can_build_ladders() {
+        let red = rgb(255, 0, 0);
+
+        assert_eq!(
+            red.shades(4).to_rgb(),
+            vec![
+                rgb(255, 0, 0),
+                rgb(191, 0, 0),
+                rgb(128, 0, 0),
+                rgb(64, 0, 0)
+            ]
+        );
+        assert_eq!(
+            red.tints(4).to_rgb(),
+            vec![
+                rgb(255, 0, 0),
+                rgb(255, 64, 64),
+                rgb(255, 127, 127),
+                rgb(255, 191, 191)
+            ]
+        );
+        assert_eq!(
+            red.tones(4).to_rgb(),
+            vec![
+                rgb(255, 0, 0),
+                rgb(223, 32, 32),
+                rgb(192, 64, 64),
+                rgb(160, 96, 96)
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_tint_and_shade() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(
+            tomato.shades(5).get(2).map(|color| color.to_rgb()),
+            Some(tomato.shade(crate::Ratio::from_f32(0.6)))
+        );
+        assert_eq!(
+            tomato.tints(5).get(2).map(|color| color.to_rgb()),
+            Some(tomato.tint(crate::Ratio::from_f32(0.6)))
+        );
+        assert_eq!(tomato.tones(1).to_rgba(), vec![rgba(255, 99, 71, 1.0)]);
+        assert!(tomato.shades(0).is_empty());
+    }
+
+    #[test]
+    fn can_build_monochromatic_ramps() {
+        let teal = rgb(23, 98, 119);
+        let ramp = teal.monochromatic(5);
+
+        assert_eq!(ramp.len(), 5);
+
+        let lightness: Vec<f32> = ramp.iter().map(|color| color.to_oklch().l).collect();
+        for (i, l) in lightness.iter().enumerate() {
+            assert!((l - (5 - i) as f32 / 6.0).abs() < 0.01, "{:?}", lightness);
+        }
+
+        for color in ramp.iter() {
+            assert!(teal.hue_distance(color, crate::HueSpace::OKLCH) < 3.0);
+        }
+    }
+
+    #[test]
+    fn keeps_ramps_in_gamut() {
+        // Pure blue is far too colorful to keep its chroma when lightened.
+        let ramp = rgb(0, 0, 255).monochromatic(3);
+
+        assert!(ramp.get(0).unwrap().to_oklch().c < rgb(0, 0, 255).to_oklch().c);
+        assert!(rgb(0, 0, 255).hue_distance(ramp.get(0).unwrap(), crate::HueSpace::OKLCH) < 3.0);
+    }
+
+    #[test]
+    fn handles_greys_and_alpha() {
+        let ramp = hsla(0, 0, 50, 0.5).monochromatic(3);
+
+        assert!(ramp
+            .iter()
+            .all(|color| color.r == color.g && color.g == color.b));
+        assert!(ramp.iter().all(|color| color.a == hsla(0, 0, 50, 0.5).a));
+        assert!(rgb(255, 99, 71).monochromatic(0).is_empty());
+    }
+}
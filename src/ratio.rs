@@ -29,7 +29,7 @@ impl Ratio {
         Ratio::from_f32(percentage as f32 / 100.0)
     }
 
-    pub fn from_u8(value: u8) -> Self {
+    pub const fn from_u8(value: u8) -> Self {
         Ratio(value)
     }
 
@@ -20,7 +20,7 @@ use std::{
 /// ```
 ///
 /// [css-rgb]: https://www.w3.org/TR/css-color-3/#rgb-color
-pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
+pub const fn rgb(r: u8, g: u8, b: u8) -> RGB {
     RGB {
         r: Ratio::from_u8(r),
         g: Ratio::from_u8(g),
@@ -0,0 +1,543 @@
+use super::{Color, ColorSpace, Colormap, Gradient, RGBA};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct to represent a continuous color scale, mapping numbers from a
+/// domain to colors, such as to color heatmaps and choropleth maps.
+///
+/// The domain is mapped onto a gradient, from its first stop to its last.
+/// Values out of the domain are clamped to it.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, rgba, ColorSpace, Scale};
+///
+/// let scale = Scale::new(&[rgb(255, 255, 255), rgb(255, 0, 0)])
+///     .domain(0.0, 50.0)
+///     .space(ColorSpace::SRGB);
+///
+/// assert_eq!(scale.get(25.0), rgba(255, 128, 128, 1.0));
+/// assert_eq!(scale.get(100.0), rgba(255, 0, 0, 1.0));
+/// ```
+pub struct Scale {
+    gradient: Gradient,
+    domain: (f32, f32),
+    transform: ScaleTransform,
+    classes: Option<Classes>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The transforms a `Scale` can apply to its domain before mapping it to
+/// colors, to spread skewed data over the whole scale.
+pub enum ScaleTransform {
+    /// Maps values as they are.
+    Linear,
+
+    /// Maps the logarithms of values, for data spanning orders of magnitude.
+    /// The domain must be either all positive or all negative.
+    Log,
+
+    /// Maps values raised to the given power, keeping their sign. Exponents
+    /// below `1.0`, like `0.5` for a square root, spread out small values,
+    /// and exponents above spread out large values.
+    Power(f32),
+}
+
+impl ScaleTransform {
+    fn apply(self, value: f32) -> f32 {
+        match self {
+            ScaleTransform::Linear => value,
+            ScaleTransform::Log if value < 0.0 => -(-value).ln(),
+            ScaleTransform::Log => value.ln(),
+            ScaleTransform::Power(exponent) => value.signum() * value.abs().powf(exponent),
+        }
+    }
+
+    fn invert(self, value: f32) -> f32 {
+        match self {
+            ScaleTransform::Linear => value,
+            ScaleTransform::Log if value < 0.0 => -(-value).exp(),
+            ScaleTransform::Log => value.exp(),
+            ScaleTransform::Power(exponent) => value.signum() * value.abs().powf(1.0 / exponent),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Classes {
+    // equal intervals over the domain
+    Uniform(usize),
+
+    // the bounds of every class, in domain units and in order
+    Breaks(Vec<f32>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that represents a class of a classed `Scale`, as returned by
+/// `Scale::legend()`.
+pub struct ScaleClass {
+    // the start of the class, inclusive
+    pub start: f32,
+
+    // the end of the class, exclusive except for the last class
+    pub end: f32,
+
+    pub color: RGBA,
+}
+
+impl Scale {
+    /// Creates a scale going evenly through `colors`, interpolated in OKLab
+    /// so the scale looks even, over a domain from `0.0` to `1.0`. Panics if
+    /// there are no colors.
+    pub fn new<T: Color + Copy>(colors: &[T]) -> Self {
+        Scale::from(Gradient::from_colors(colors))
+    }
+
+    /// Sets the domain of the scale, mapping `start` to its first color and
+    /// `end` to its last. The domain can go downwards, with `start` above
+    /// `end`. Panics if the bounds are the same or not finite.
+    pub fn domain(mut self, start: f32, end: f32) -> Self {
+        assert!(
+            start.is_finite() && end.is_finite() && start != end,
+            "Invalid scale domain"
+        );
+
+        self.domain = (start, end);
+        self.check_domain();
+        self
+    }
+
+    /// Sets the transform applied to the domain, the same transform applying
+    /// to every value. Values the transform cannot map, like negative values
+    /// on a positive logarithmic scale, map to the start of the scale. Panics
+    /// if a logarithmic domain is not all positive or all negative, or if a
+    /// power is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Scale, ScaleTransform};
+    ///
+    /// let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)])
+    ///     .domain(1.0, 1000.0)
+    ///     .transform(ScaleTransform::Log);
+    ///
+    /// assert_eq!(format!("{:.2}", scale.position(10.0)), "0.33");
+    /// assert_eq!(format!("{:.2}", scale.position(100.0)), "0.67");
+    /// ```
+    pub fn transform(mut self, transform: ScaleTransform) -> Self {
+        if let ScaleTransform::Power(exponent) = transform {
+            assert!(
+                exponent.is_finite() && exponent > 0.0,
+                "Invalid scale exponent"
+            );
+        }
+
+        self.transform = transform;
+        self.check_domain();
+        self
+    }
+
+    fn check_domain(&self) {
+        let (start, end) = self.domain;
+
+        if self.transform == ScaleTransform::Log {
+            assert!(
+                (start > 0.0 && end > 0.0) || (start < 0.0 && end < 0.0),
+                "Invalid logarithmic domain"
+            );
+        }
+    }
+
+    /// Splits the domain into `n` classes of equal width, for discrete
+    /// legends. Every value in a class gets the same color, sampled evenly
+    /// from the start of the scale to its end. Panics if `n` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, rgba, ColorSpace, Scale};
+    ///
+    /// let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)])
+    ///     .domain(0.0, 90.0)
+    ///     .space(ColorSpace::SRGB)
+    ///     .classes(3);
+    ///
+    /// assert_eq!(scale.get(10.0), rgba(0, 0, 0, 1.0));
+    /// assert_eq!(scale.get(40.0), rgba(128, 128, 128, 1.0));
+    /// assert_eq!(scale.breaks(), vec![0.0, 30.0, 60.0, 90.0]);
+    /// ```
+    pub fn classes(mut self, n: usize) -> Self {
+        assert!(n > 0, "Invalid class count");
+
+        self.classes = Some(Classes::Uniform(n));
+        self
+    }
+
+    /// Splits `data` into `n` classes holding as many values each, for
+    /// discrete legends of skewed data. The domain is set to span `data`,
+    /// and every value in a class gets the same color, sampled evenly from
+    /// the start of the scale to its end. Values that are not finite are
+    /// ignored. Panics if `n` is zero or if there is no finite value.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Scale};
+    ///
+    /// let data = [1.0, 2.0, 3.0, 4.0, 100.0];
+    /// let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)]).quantiles(&data, 2);
+    ///
+    /// assert_eq!(scale.breaks(), vec![1.0, 3.0, 100.0]);
+    /// assert_eq!(scale.get(4.0), scale.get(100.0));
+    /// ```
+    pub fn quantiles(mut self, data: &[f32], n: usize) -> Self {
+        assert!(n > 0, "Invalid class count");
+
+        let mut data: Vec<f32> = data.iter().copied().filter(|x| x.is_finite()).collect();
+        assert!(!data.is_empty(), "Invalid quantile data");
+        data.sort_by(f32::total_cmp);
+
+        let quantile = |q: f32| {
+            let position = q * (data.len() - 1) as f32;
+            let index = (position as usize).min(data.len() - 1);
+            let next = (index + 1).min(data.len() - 1);
+
+            data[index] + (data[next] - data[index]) * (position - index as f32)
+        };
+
+        let (min, max) = (data[0], data[data.len() - 1]);
+
+        // A domain needs distinct bounds, so data of a single value gets
+        // one of width one around it.
+        self.domain = if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+        self.check_domain();
+
+        let mut breaks: Vec<f32> = (0..=n).map(|i| quantile(i as f32 / n as f32)).collect();
+        breaks[0] = self.domain.0;
+        breaks[n] = self.domain.1;
+
+        self.classes = Some(Classes::Breaks(breaks));
+        self
+    }
+
+    /// Returns the bounds of every class, from the start of the domain to its
+    /// end, or only the bounds of the domain if the scale is continuous.
+    pub fn breaks(&self) -> Vec<f32> {
+        let (start, end) = self.domain;
+
+        match &self.classes {
+            None => vec![start, end],
+            Some(Classes::Uniform(n)) => {
+                let (lhs, rhs) = (self.transform.apply(start), self.transform.apply(end));
+
+                (0..=*n)
+                    .map(|i| {
+                        self.transform
+                            .invert(lhs + (rhs - lhs) * i as f32 / *n as f32)
+                    })
+                    .collect()
+            }
+            Some(Classes::Breaks(breaks)) => breaks.clone(),
+        }
+    }
+
+    /// Returns every class of the scale with its color, such as to draw a
+    /// legend, or a single class spanning the domain if the scale is
+    /// continuous.
+    pub fn legend(&self) -> Vec<ScaleClass> {
+        self.breaks()
+            .windows(2)
+            .map(|bounds| ScaleClass {
+                start: bounds[0],
+                end: bounds[1],
+                color: self.get((bounds[0] + bounds[1]) / 2.0),
+            })
+            .collect()
+    }
+
+    /// Sets the color space the colors are interpolated in.
+    pub fn space(mut self, space: ColorSpace) -> Self {
+        self.gradient = self.gradient.space(space);
+        self
+    }
+
+    /// Returns the bounds of the domain, as set by `domain()`.
+    pub fn bounds(&self) -> (f32, f32) {
+        self.domain
+    }
+
+    /// Returns the gradient the domain is mapped onto.
+    pub fn gradient(&self) -> &Gradient {
+        &self.gradient
+    }
+
+    /// Maps `value` to its position along the scale, from `0.0` at the start
+    /// of the domain to `1.0` at its end, once transformed. Values out of the
+    /// domain are clamped, and `NaN` maps to the start.
+    pub fn position(&self, value: f32) -> f32 {
+        let transform = |value: f32| self.transform.apply(value);
+        let (start, end) = (transform(self.domain.0), transform(self.domain.1));
+        let position = (transform(value) - start) / (end - start);
+
+        if position.is_nan() {
+            0.0
+        } else {
+            position.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Samples the scale at `value`. If the scale is classed, this is the
+    /// color of the class holding `value`.
+    pub fn get(&self, value: f32) -> RGBA {
+        let position = self.position(value);
+
+        let (class, n) = match &self.classes {
+            None => return self.at(position),
+            Some(Classes::Uniform(n)) => ((position * *n as f32) as usize, *n),
+            Some(Classes::Breaks(breaks)) => {
+                let inner = &breaks[1..breaks.len() - 1];
+                let class = inner
+                    .iter()
+                    .filter(|&&bound| position >= self.position(bound))
+                    .count();
+
+                (class, breaks.len() - 1)
+            }
+        };
+
+        if n == 1 {
+            self.at(0.0)
+        } else {
+            self.at(class.min(n - 1) as f32 / (n - 1) as f32)
+        }
+    }
+
+    // Samples the scale at a position between 0.0 and 1.0.
+    fn at(&self, position: f32) -> RGBA {
+        let stops = self.gradient.stops();
+        let (first, last) = (stops[0].position, stops[stops.len() - 1].position);
+
+        self.gradient.at(first + (last - first) * position)
+    }
+}
+
+/// Creates a scale along a gradient, over a domain from `0.0` to `1.0`.
+/// Panics if the gradient has no stops.
+impl From<Gradient> for Scale {
+    fn from(gradient: Gradient) -> Self {
+        assert!(!gradient.is_empty(), "Invalid scale colors");
+
+        Scale {
+            gradient,
+            domain: (0.0, 1.0),
+            transform: ScaleTransform::Linear,
+            classes: None,
+        }
+    }
+}
+
+// Colormaps are interpolated in sRGB, like their stops were sampled.
+impl From<Colormap> for Scale {
+    fn from(colormap: Colormap) -> Self {
+        Scale::new(colormap.stops()).space(ColorSpace::SRGB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rgb, rgba, Color, ColorSpace, Gradient, Scale, ScaleClass, ScaleTransform, VIRIDIS,
+    };
+
+    fn greys() -> Scale {
+        Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)]).space(ColorSpace::SRGB)
+    }
+
+    #[test]
+    fn maps_domain_to_colors() {
+        let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 0, 0), rgb(255, 255, 0)])
+            .domain(10.0, 30.0)
+            .space(ColorSpace::SRGB);
+
+        assert_eq!(scale.bounds(), (10.0, 30.0));
+        assert_eq!(scale.get(10.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(scale.get(15.0), rgba(128, 0, 0, 1.0));
+        assert_eq!(scale.get(20.0), rgba(255, 0, 0, 1.0));
+        assert_eq!(scale.get(25.0), rgba(255, 128, 0, 1.0));
+        assert_eq!(scale.get(30.0), rgba(255, 255, 0, 1.0));
+    }
+
+    #[test]
+    fn clamps_to_domain() {
+        let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)]).domain(-1.0, 1.0);
+
+        assert_eq!(scale.position(-5.0), 0.0);
+        assert_eq!(scale.position(5.0), 1.0);
+        assert_eq!(scale.position(f32::NAN), 0.0);
+        assert_eq!(scale.get(5.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(scale.get(f32::NEG_INFINITY), rgba(0, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn can_go_downwards() {
+        let scale = Scale::new(&[rgb(0, 0, 0), rgb(255, 255, 255)])
+            .domain(100.0, 0.0)
+            .space(ColorSpace::SRGB);
+
+        assert_eq!(scale.position(75.0), 0.25);
+        assert_eq!(scale.get(0.0), rgba(255, 255, 255, 1.0));
+    }
+
+    #[test]
+    fn interpolates_in_chosen_space() {
+        let colors = [rgb(0, 0, 0), rgb(255, 255, 255)];
+
+        assert_eq!(
+            Scale::new(&colors).space(ColorSpace::LinearRGB).get(0.5),
+            rgba(188, 188, 188, 1.0)
+        );
+        assert_eq!(
+            Scale::new(&colors).space(ColorSpace::SRGB).get(0.5),
+            rgba(128, 128, 128, 1.0)
+        );
+        assert_eq!(Scale::new(&[rgb(1, 2, 3)]).get(0.7), rgba(1, 2, 3, 1.0));
+    }
+
+    #[test]
+    fn can_build_from_colormaps() {
+        let scale = Scale::from(VIRIDIS).domain(0.0, 10.0);
+
+        for value in [0.0, 2.5, 5.0, 10.0] {
+            assert_eq!(scale.get(value).to_rgb(), VIRIDIS.sample(value / 10.0));
+        }
+    }
+
+    #[test]
+    fn can_split_into_classes() {
+        let scale = greys().domain(0.0, 100.0).classes(5);
+
+        assert_eq!(scale.breaks(), vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(scale.get(0.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(scale.get(19.9), rgba(0, 0, 0, 1.0));
+        assert_eq!(scale.get(20.0), rgba(64, 64, 64, 1.0));
+        assert_eq!(scale.get(50.0), rgba(128, 128, 128, 1.0));
+        assert_eq!(scale.get(100.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(greys().classes(1).get(0.9), rgba(0, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn can_split_into_quantiles() {
+        let data = [5.0, 1.0, 2.0, f32::NAN, 3.0, 4.0, 6.0, 7.0, 1000.0];
+        let scale = greys().quantiles(&data, 4);
+
+        assert_eq!(scale.bounds(), (1.0, 1000.0));
+        assert_eq!(scale.breaks(), vec![1.0, 2.75, 4.5, 6.25, 1000.0]);
+        assert_eq!(scale.get(1.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(scale.get(3.0), rgba(85, 85, 85, 1.0));
+        assert_eq!(scale.get(7.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(scale.get(2000.0), rgba(255, 255, 255, 1.0));
+        assert_eq!(greys().quantiles(&[3.0, 3.0], 2).bounds(), (2.5, 3.5));
+    }
+
+    #[test]
+    fn can_build_legends() {
+        let legend = greys().domain(0.0, 10.0).classes(2).legend();
+
+        assert_eq!(
+            legend,
+            vec![
+                ScaleClass {
+                    start: 0.0,
+                    end: 5.0,
+                    color: rgba(0, 0, 0, 1.0)
+                },
+                ScaleClass {
+                    start: 5.0,
+                    end: 10.0,
+                    color: rgba(255, 255, 255, 1.0)
+                },
+            ]
+        );
+        assert_eq!(greys().legend().len(), 1);
+    }
+
+    #[test]
+    fn can_map_logarithmically() {
+        let scale = greys().domain(1.0, 10_000.0).transform(ScaleTransform::Log);
+
+        assert_eq!(scale.position(1.0), 0.0);
+        assert!((scale.position(100.0) - 0.5).abs() < 1e-6);
+        assert_eq!(scale.position(0.0), 0.0);
+        assert_eq!(scale.position(-10.0), 0.0);
+        assert_eq!(scale.get(100.0), rgba(128, 128, 128, 1.0));
+
+        let negative = greys().domain(-1.0, -100.0).transform(ScaleTransform::Log);
+        assert!((negative.position(-10.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn can_map_powers() {
+        let scale = greys()
+            .domain(0.0, 100.0)
+            .transform(ScaleTransform::Power(0.5));
+
+        assert_eq!(scale.position(25.0), 0.5);
+        assert_eq!(scale.position(100.0), 1.0);
+
+        let symmetric = greys()
+            .domain(-10.0, 10.0)
+            .transform(ScaleTransform::Power(2.0));
+        assert_eq!(symmetric.position(0.0), 0.5);
+        assert_eq!(symmetric.position(-5.0), 0.375);
+    }
+
+    #[test]
+    fn transforms_classes() {
+        let scale = greys()
+            .domain(1.0, 1000.0)
+            .transform(ScaleTransform::Log)
+            .classes(3);
+        let breaks = scale.breaks();
+
+        assert_eq!(breaks.len(), 4);
+        assert!((breaks[1] - 10.0).abs() < 1e-3 && (breaks[2] - 100.0).abs() < 1e-2);
+        assert_eq!(scale.get(50.0), rgba(128, 128, 128, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_logarithmic_domain() {
+        greys().domain(-1.0, 1.0).transform(ScaleTransform::Log);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_missing_quantile_data() {
+        greys().quantiles(&[f32::NAN], 3);
+    }
+
+    #[test]
+    fn can_build_from_gradients() {
+        let mut gradient = Gradient::from_colors(&[rgb(0, 0, 0), rgb(255, 255, 255)]);
+        gradient.add_stop(0.2, rgb(255, 0, 0));
+
+        let scale = Scale::from(gradient.clone().space(ColorSpace::SRGB)).domain(0.0, 10.0);
+
+        assert_eq!(scale.get(2.0), rgba(255, 0, 0, 1.0));
+        assert_eq!(scale.gradient().len(), 3);
+        assert_eq!(scale.get(1.0), rgba(128, 0, 0, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_empty_gradients() {
+        let _ = Scale::from(Gradient::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_empty_domain() {
+        Scale::new(&[rgb(0, 0, 0)]).domain(1.0, 1.0);
+    }
+}
@@ -0,0 +1,521 @@
+use super::{
+    linear::{from_linear, linear_channels},
+    oklab,
+    oklab::normalize_hue,
+    oklch, Color, Ratio, LAB, OKLAB, RGB, RGBA,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The color spaces colors can be mixed in, matching the interpolation
+/// spaces of CSS. Each of them gives different results: mixing in sRGB
+/// matches what most tools do, in linear light how light physically adds up,
+/// and in CIELAB or OKLab what looks even. The cylindrical spaces mix hues
+/// around the color wheel, keeping colors vivid.
+pub enum ColorSpace {
+    /// The gamma-encoded sRGB channels, as stored by `RGB`, or `srgb` in CSS.
+    SRGB,
+
+    /// The linear light sRGB channels, or `srgb-linear` in CSS.
+    LinearRGB,
+
+    /// The HSL color model, or `hsl` in CSS.
+    HSL,
+
+    /// The CIELAB color space, relative to the D50 white point, or `lab` in
+    /// CSS.
+    LAB,
+
+    /// CIE LCH, the cylindrical form of CIELAB, or `lch` in CSS.
+    LCH,
+
+    /// The OKLab color space, or `oklab` in CSS.
+    OKLAB,
+
+    /// OKLCH, the cylindrical form of OKLab, or `oklch` in CSS.
+    OKLCH,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The arcs hues can take around the color wheel when interpolated in a
+/// cylindrical color space, as CSS defines them. Rectangular spaces ignore
+/// them.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#hue-interpolation).
+pub enum HueInterpolation {
+    /// Takes the shorter arc, the default.
+    Shorter,
+
+    /// Takes the longer arc.
+    Longer,
+
+    /// Goes around the wheel with increasing hue angles.
+    Increasing,
+
+    /// Goes around the wheel with decreasing hue angles.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Returns the keyword of the arc in CSS, as in `in oklch longer hue`.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            HueInterpolation::Shorter => "shorter",
+            HueInterpolation::Longer => "longer",
+            HueInterpolation::Increasing => "increasing",
+            HueInterpolation::Decreasing => "decreasing",
+        }
+    }
+
+    // Parses the keyword of an arc in CSS.
+    pub(crate) fn from_css_name(name: &str) -> Option<Self> {
+        [
+            HueInterpolation::Shorter,
+            HueInterpolation::Longer,
+            HueInterpolation::Increasing,
+            HueInterpolation::Decreasing,
+        ]
+        .into_iter()
+        .find(|arc| arc.css_name() == name)
+    }
+
+    // Shifts hues between 0° and 360° by a turn so that going straight from
+    // `lhs` to `rhs` follows the arc.
+    fn fix_up(self, lhs: &mut f32, rhs: &mut f32) {
+        let difference = *rhs - *lhs;
+
+        match self {
+            HueInterpolation::Shorter if difference > 180.0 => *lhs += 360.0,
+            HueInterpolation::Shorter if difference < -180.0 => *rhs += 360.0,
+            HueInterpolation::Longer if difference > 0.0 && difference < 180.0 => *lhs += 360.0,
+            HueInterpolation::Longer if difference > -180.0 && difference <= 0.0 => *rhs += 360.0,
+            HueInterpolation::Increasing if difference < 0.0 => *rhs += 360.0,
+            HueInterpolation::Decreasing if difference > 0.0 => *lhs += 360.0,
+            _ => {}
+        }
+    }
+}
+
+impl ColorSpace {
+    /// Returns the name of the space in CSS, as used by `color-mix()` and
+    /// gradients.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            ColorSpace::SRGB => "srgb",
+            ColorSpace::LinearRGB => "srgb-linear",
+            ColorSpace::HSL => "hsl",
+            ColorSpace::LAB => "lab",
+            ColorSpace::LCH => "lch",
+            ColorSpace::OKLAB => "oklab",
+            ColorSpace::OKLCH => "oklch",
+        }
+    }
+
+    // Parses the name of a space in CSS.
+    pub(crate) fn from_css_name(name: &str) -> Option<Self> {
+        [
+            ColorSpace::SRGB,
+            ColorSpace::LinearRGB,
+            ColorSpace::HSL,
+            ColorSpace::LAB,
+            ColorSpace::LCH,
+            ColorSpace::OKLAB,
+            ColorSpace::OKLCH,
+        ]
+        .into_iter()
+        .find(|space| space.css_name() == name)
+    }
+
+    // Returns the CSS color interpolation method for this space and arc, as
+    // in `in oklch longer hue`. The default shorter arc is left out.
+    pub(crate) fn css_method(self, arc: HueInterpolation) -> String {
+        match self.hue_channel() {
+            Some(_) if arc != HueInterpolation::Shorter => {
+                format!("in {} {} hue", self.css_name(), arc.css_name())
+            }
+            _ => format!("in {}", self.css_name()),
+        }
+    }
+
+    // Returns the index of the hue channel, for cylindrical spaces.
+    pub(crate) fn hue_channel(self) -> Option<usize> {
+        match self {
+            ColorSpace::HSL => Some(0),
+            ColorSpace::LCH | ColorSpace::OKLCH => Some(2),
+            _ => None,
+        }
+    }
+
+    // Splits a color into its channels in this space. Hues are in degrees,
+    // and are `NaN` for colors without a hue, which CSS calls powerless.
+    pub(crate) fn decompose<T: Color>(self, color: T) -> [f32; 3] {
+        let polar = |a: f32, b: f32, threshold: f32| {
+            let c = a.hypot(b);
+            let h = if c < threshold {
+                f32::NAN
+            } else {
+                normalize_hue(b.atan2(a).to_degrees())
+            };
+
+            (c, h)
+        };
+
+        match self {
+            ColorSpace::SRGB => {
+                let RGB { r, g, b } = color.to_rgb();
+                [r.as_f32(), g.as_f32(), b.as_f32()]
+            }
+            ColorSpace::LinearRGB => linear_channels(color.to_rgb()),
+            ColorSpace::HSL => hsl_channels(color.to_rgb()),
+            ColorSpace::LAB => {
+                let LAB { l, a, b } = color.to_lab();
+                [l, a, b]
+            }
+            ColorSpace::LCH => {
+                let LAB { l, a, b } = color.to_lab();
+                let (c, h) = polar(a, b, 1e-2);
+                [l, c, h]
+            }
+            ColorSpace::OKLAB => {
+                let OKLAB { l, a, b } = color.to_oklab();
+                [l, a, b]
+            }
+            ColorSpace::OKLCH => {
+                let OKLAB { l, a, b } = color.to_oklab();
+                let (c, h) = polar(a, b, 1e-4);
+                [l, c, h]
+            }
+        }
+    }
+
+    // Builds a color from its channels in this space. Colors falling outside
+    // of the sRGB gamut are clipped, and `NaN` hues are read as 0°.
+    pub(crate) fn compose(self, channels: [f32; 3]) -> RGB {
+        let [r, g, b] = self.srgb_channels(channels).map(|value| {
+            Ratio::from_f32(if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            })
+        });
+
+        RGB { r, g, b }
+    }
+
+    // Converts channels in this space into unrounded sRGB channels, which
+    // fall outside of the 0.0-1.0 range for colors outside of the gamut.
+    pub(crate) fn srgb_channels(self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let hue = |h: f32| if h.is_nan() { 0.0 } else { h };
+
+        let linear = match self {
+            ColorSpace::SRGB => return [x, y, z],
+            ColorSpace::HSL => return rgb_channels([hue(x), y.clamp(0.0, 1.0), z]),
+            ColorSpace::LinearRGB => [x, y, z],
+            ColorSpace::LAB => super::lab(x, y, z).to_linear(),
+            ColorSpace::LCH => {
+                let (sin, cos) = hue(z).to_radians().sin_cos();
+                super::lab(x, y * cos, y * sin).to_linear()
+            }
+            ColorSpace::OKLAB => oklab(x, y, z).to_linear(),
+            ColorSpace::OKLCH => oklch(x, y, hue(z)).to_oklab().to_linear(),
+        };
+
+        linear.map(from_linear)
+    }
+}
+
+// Converts sRGB to unrounded HSL channels, the hue being `NaN` for greys.
+fn hsl_channels(rgb: RGB) -> [f32; 3] {
+    let [r, g, b] = [rgb.r.as_f32(), rgb.g.as_f32(), rgb.b.as_f32()];
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return [f32::NAN, 0.0, l];
+    }
+
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        (g - b) / delta
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    [normalize_hue(h * 60.0), s, l]
+}
+
+// Converts HSL channels to unclipped sRGB channels.
+fn rgb_channels([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let a = s * l.min(1.0 - l);
+    let channel = |n: f32| {
+        let k = (n + h / 30.0) % 12.0;
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+// Interpolates between two colors the way CSS does, `t` going from `lhs` at
+// 0.0 to `rhs` at 1.0: channels are premultiplied by alpha, a powerless hue
+// takes the hue of the other color, and hues go around the given arc.
+pub(crate) fn interpolate<A: Color, B: Color>(
+    lhs: A,
+    rhs: B,
+    t: f32,
+    space: ColorSpace,
+    arc: HueInterpolation,
+) -> RGBA {
+    Segment::new(lhs, rhs, space, arc).at(t)
+}
+
+// Two colors split into their channels, ready to be interpolated many times
+// without converting them again.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Segment {
+    lhs: [f32; 3],
+    rhs: [f32; 3],
+    lhs_alpha: f32,
+    rhs_alpha: f32,
+    space: ColorSpace,
+}
+
+impl Segment {
+    pub(crate) fn new<A: Color, B: Color>(
+        lhs: A,
+        rhs: B,
+        space: ColorSpace,
+        arc: HueInterpolation,
+    ) -> Self {
+        let (lhs, rhs) = (lhs.to_rgba(), rhs.to_rgba());
+        let mut lhs_channels = space.decompose(lhs);
+        let mut rhs_channels = space.decompose(rhs);
+
+        if let Some(i) = space.hue_channel() {
+            if lhs_channels[i].is_nan() {
+                lhs_channels[i] = rhs_channels[i];
+            } else if rhs_channels[i].is_nan() {
+                rhs_channels[i] = lhs_channels[i];
+            }
+
+            arc.fix_up(&mut lhs_channels[i], &mut rhs_channels[i]);
+        }
+
+        Segment {
+            lhs: lhs_channels,
+            rhs: rhs_channels,
+            lhs_alpha: lhs.a.as_f32(),
+            rhs_alpha: rhs.a.as_f32(),
+            space,
+        }
+    }
+
+    // Returns the channels in the segment's space and the alpha at `t`.
+    fn channels(&self, t: f32) -> ([f32; 3], f32) {
+        let hue = self.space.hue_channel();
+        let alpha = self.lhs_alpha + (self.rhs_alpha - self.lhs_alpha) * t;
+        let mut channels = [0.0; 3];
+
+        for (i, channel) in channels.iter_mut().enumerate() {
+            let (lhs, rhs) = (self.lhs[i], self.rhs[i]);
+
+            *channel = if Some(i) == hue {
+                normalize_hue(lhs + (rhs - lhs) * t)
+            } else if alpha == 0.0 {
+                lhs + (rhs - lhs) * t
+            } else {
+                let lhs = lhs * self.lhs_alpha;
+                let rhs = rhs * self.rhs_alpha;
+
+                (lhs + (rhs - lhs) * t) / alpha
+            };
+        }
+
+        (channels, alpha.clamp(0.0, 1.0))
+    }
+
+    pub(crate) fn at(&self, t: f32) -> RGBA {
+        let (channels, alpha) = self.channels(t);
+        let RGB { r, g, b } = self.space.compose(channels);
+
+        RGBA {
+            r,
+            g,
+            b,
+            a: Ratio::from_f32(alpha),
+        }
+    }
+
+    // Returns the unrounded sRGB channels and alpha at `t`, clipped to the
+    // sRGB gamut.
+    pub(crate) fn srgb_at(&self, t: f32) -> [f32; 4] {
+        let (channels, alpha) = self.channels(t);
+        let [r, g, b] = self.space.srgb_channels(channels).map(|value| {
+            if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            }
+        });
+
+        [r, g, b, alpha]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, rgb, rgba, Color, ColorSpace, HueInterpolation};
+
+    const SPACES: [ColorSpace; 7] = [
+        ColorSpace::SRGB,
+        ColorSpace::LinearRGB,
+        ColorSpace::HSL,
+        ColorSpace::LAB,
+        ColorSpace::LCH,
+        ColorSpace::OKLAB,
+        ColorSpace::OKLCH,
+    ];
+
+    #[test]
+    fn can_round_trip() {
+        for space in SPACES {
+            for color in [rgb(255, 99, 71), rgb(23, 98, 119), rgb(0, 0, 0)] {
+                assert_eq!(space.compose(space.decompose(color)), color);
+            }
+        }
+    }
+
+    #[test]
+    fn marks_powerless_hues() {
+        for space in [ColorSpace::HSL, ColorSpace::LCH, ColorSpace::OKLCH] {
+            let i = space.hue_channel().unwrap();
+
+            assert!(space.decompose(rgb(128, 128, 128))[i].is_nan());
+            assert!(space.decompose(rgb(255, 255, 255))[i].is_nan());
+            assert!(!space.decompose(rgb(255, 0, 0))[i].is_nan());
+        }
+
+        assert_eq!(
+            ColorSpace::HSL.decompose(hsl(120, 100, 25)),
+            [120.0, 1.0, 0.2509804]
+        );
+    }
+
+    #[test]
+    fn interpolates_like_css() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(
+            red.lerp(blue, 0.5, ColorSpace::SRGB),
+            rgba(128, 0, 128, 1.0)
+        );
+        assert_eq!(red.lerp(blue, 0.5, ColorSpace::HSL), rgba(255, 0, 255, 1.0));
+        assert_eq!(
+            red.lerp(blue, 0.5, ColorSpace::OKLCH),
+            rgba(186, 0, 194, 1.0)
+        );
+        assert_eq!(
+            red.lerp(blue, 0.5, ColorSpace::OKLAB),
+            rgba(140, 83, 162, 1.0)
+        );
+        assert_eq!(red.lerp(blue, 0.0, ColorSpace::LCH), rgba(255, 0, 0, 1.0));
+        assert_eq!(red.lerp(blue, 1.0, ColorSpace::LCH), rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn interpolates_hues_around_shorter_arc() {
+        let lhs = hsl(350, 100, 50);
+        let rhs = hsl(30, 100, 50);
+
+        assert_eq!(
+            lhs.lerp(rhs, 0.5, ColorSpace::HSL).to_hsl(),
+            hsl(10, 100, 50)
+        );
+        assert_eq!(
+            rhs.lerp(lhs, 0.25, ColorSpace::HSL).to_hsl(),
+            hsl(20, 100, 50)
+        );
+    }
+
+    #[test]
+    fn interpolates_hues_around_chosen_arc() {
+        let hue = |lhs: i32, rhs: i32, arc: HueInterpolation| {
+            hsl(lhs, 100, 50)
+                .lerp_with(hsl(rhs, 100, 50), 0.5, ColorSpace::HSL, arc)
+                .to_hsl()
+                .h
+                .degrees()
+        };
+
+        assert_eq!(hue(10, 90, HueInterpolation::Shorter), 50);
+        assert_eq!(hue(10, 90, HueInterpolation::Longer), 230);
+        assert_eq!(hue(90, 10, HueInterpolation::Longer), 230);
+        assert_eq!(hue(90, 10, HueInterpolation::Increasing), 230);
+        assert_eq!(hue(10, 90, HueInterpolation::Increasing), 50);
+        assert_eq!(hue(10, 90, HueInterpolation::Decreasing), 230);
+        assert_eq!(hue(90, 10, HueInterpolation::Decreasing), 50);
+        assert_eq!(hue(350, 30, HueInterpolation::Shorter), 10);
+        assert_eq!(hue(350, 30, HueInterpolation::Decreasing), 190);
+    }
+
+    #[test]
+    fn ignores_arcs_in_rectangular_spaces() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(
+            red.lerp_with(blue, 0.5, ColorSpace::OKLAB, HueInterpolation::Longer),
+            red.lerp(blue, 0.5, ColorSpace::OKLAB)
+        );
+        assert_eq!(HueInterpolation::Increasing.css_name(), "increasing");
+    }
+
+    #[test]
+    fn takes_hue_of_chromatic_color() {
+        let white = rgb(255, 255, 255);
+        let red = rgb(255, 0, 0);
+        let pink = white.lerp(red, 0.5, ColorSpace::HSL).to_hsl();
+
+        assert_eq!(pink, hsl(0, 50, 75));
+        assert!(white.lerp(red, 0.5, ColorSpace::OKLCH).to_oklch().h < 40.0);
+    }
+
+    #[test]
+    fn premultiplies_alpha() {
+        let transparent = rgba(0, 0, 0, 0.0);
+        let red = rgba(255, 0, 0, 1.0);
+
+        assert_eq!(
+            transparent.lerp(red, 0.5, ColorSpace::SRGB),
+            rgba(255, 0, 0, 0.5)
+        );
+        let mix = rgb(255, 0, 0).mix_in(rgba(0, 0, 255, 0.5), crate::percent(50), ColorSpace::SRGB);
+        assert_eq!(mix.to_rgb(), rgb(170, 0, 85));
+        assert!((mix.a.as_f32() - 0.75).abs() < 0.01);
+    }
+
+    #[test]
+    fn has_css_names() {
+        let names: Vec<_> = SPACES.iter().map(|space| space.css_name()).collect();
+
+        assert_eq!(
+            names,
+            ["srgb", "srgb-linear", "hsl", "lab", "lch", "oklab", "oklch"]
+        );
+    }
+
+    #[test]
+    fn clips_out_of_gamut_channels() {
+        assert_eq!(
+            ColorSpace::SRGB.compose([2.0, -1.0, f32::NAN]),
+            rgb(255, 0, 0)
+        );
+        assert_eq!(
+            ColorSpace::LinearRGB.compose([2.0, -1.0, 0.0]),
+            rgb(255, 0, 0)
+        );
+    }
+}
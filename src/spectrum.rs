@@ -0,0 +1,232 @@
+use super::{
+    linear::{from_linear_channels, from_xyz},
+    rgb, xyz, RGB, XYZ,
+};
+
+// The range of wavelengths the eye can see, in nanometers.
+const MIN_WAVELENGTH: f32 = 380.0;
+const MAX_WAVELENGTH: f32 = 780.0;
+
+/// Converts a wavelength of light in nanometers into an approximate sRGB
+/// color, for drawing spectra, as bright as sRGB can show it.
+///
+/// Pure spectral colors fall outside of the sRGB gamut, so they are washed
+/// out with white until they fit. The color dims towards the ends of the
+/// visible spectrum, from 420nm down to 380nm and from 700nm up to 780nm,
+/// where the eye barely sees, and is black outside of it. Panics if `nm` is
+/// not finite.
+///
+/// Past 650nm the hue barely changes, and the approximation of the CIE
+/// observer used gets inaccurate, so the hue is held there, and likewise
+/// below 420nm.
+///
+/// # Example
+/// ```
+/// use farver::{from_wavelength, rgb, Color};
+///
+/// assert_eq!(from_wavelength(470.0).to_hex(), "#0065ff");
+/// assert_eq!(from_wavelength(550.0).to_hex(), "#2dff00");
+/// assert_eq!(from_wavelength(300.0), rgb(0, 0, 0));
+/// ```
+pub fn from_wavelength(nm: f32) -> RGB {
+    assert!(nm.is_finite(), "Invalid wavelength");
+
+    if !(MIN_WAVELENGTH..=MAX_WAVELENGTH).contains(&nm) {
+        return rgb(0, 0, 0);
+    }
+
+    let mut linear = from_xyz(observer(nm.clamp(420.0, 650.0) as f64));
+
+    // desaturate with white until every channel is positive
+    let min = linear[0].min(linear[1]).min(linear[2]);
+    if min < 0.0 {
+        linear = linear.map(|channel| channel - min);
+    }
+
+    let max = linear[0].max(linear[1]).max(linear[2]);
+    let fade = match nm {
+        nm if nm < 420.0 => 0.3 + 0.7 * (nm - MIN_WAVELENGTH) / (420.0 - MIN_WAVELENGTH),
+        nm if nm > 700.0 => 0.3 + 0.7 * (MAX_WAVELENGTH - nm) / (MAX_WAVELENGTH - 700.0),
+        _ => 1.0,
+    };
+
+    from_linear_channels(linear.map(|channel| (channel / max) as f32 * fade))
+}
+
+/// Integrates a spectral power distribution against the CIE 1931 standard
+/// observer into the color it looks like, as in the light of a lamp or an
+/// LED measured by a spectrometer. `samples` are pairs of wavelengths in
+/// nanometers and their spectral power, ordered by wavelength.
+///
+/// The power is interpolated linearly between samples, and is zero outside
+/// of them. Only the relative power matters: the result is scaled so that
+/// its luminance `y` is `1.0`, and is black if no power falls in the visible
+/// spectrum. Panics if the samples are not finite or not ordered.
+///
+/// # Example
+/// ```
+/// use farver::from_spectrum;
+///
+/// // An equal-energy spectrum looks white, with the chromaticity of illuminant E.
+/// let flat = from_spectrum(&[(380.0, 1.0), (780.0, 1.0)]);
+/// let (x, y) = flat.chromaticity().unwrap();
+///
+/// assert_eq!(format!("{:.2} {:.2}", x, y), "0.33 0.33");
+/// ```
+pub fn from_spectrum(samples: &[(f32, f32)]) -> XYZ {
+    assert!(
+        samples
+            .iter()
+            .all(|(nm, power)| nm.is_finite() && power.is_finite()),
+        "Invalid spectrum"
+    );
+    assert!(
+        samples.windows(2).all(|pair| pair[0].0 <= pair[1].0),
+        "Invalid spectrum"
+    );
+
+    let power = |nm: f32| {
+        let index = samples.partition_point(|&(wavelength, _)| wavelength <= nm);
+
+        match (index.checked_sub(1).map(|i| samples[i]), samples.get(index)) {
+            (Some((start, lhs)), _) if start == nm => lhs,
+            (Some((start, lhs)), Some(&(end, rhs))) => {
+                lhs + (rhs - lhs) * (nm - start) / (end - start)
+            }
+            _ => 0.0,
+        }
+    };
+
+    // summing every nanometer follows the observer closely
+    let mut sum = [0.0_f64; 3];
+    for nm in MIN_WAVELENGTH as u32..=MAX_WAVELENGTH as u32 {
+        let power = power(nm as f32) as f64;
+
+        for (channel, weight) in sum.iter_mut().zip(observer(nm as f64)) {
+            *channel += power * weight;
+        }
+    }
+
+    if sum[1] <= 0.0 {
+        return xyz(0.0, 0.0, 0.0);
+    }
+
+    let [x, y, z] = sum.map(|channel| (channel / sum[1]) as f32);
+    xyz(x, y, z)
+}
+
+// Approximates the CIE 1931 2° standard observer color matching functions at
+// a wavelength, with the multi-lobe Gaussian fit of Wyman, Sloan and
+// Shirley.
+pub(crate) fn observer(nm: f64) -> [f64; 3] {
+    let gaussian = |mean: f64, below: f64, above: f64| {
+        let spread = if nm < mean { below } else { above };
+        (-0.5 * ((nm - mean) / spread).powi(2)).exp()
+    };
+
+    [
+        1.056 * gaussian(599.8, 37.9, 31.0) + 0.362 * gaussian(442.0, 16.0, 26.7)
+            - 0.065 * gaussian(501.1, 20.4, 26.2),
+        0.821 * gaussian(568.8, 46.9, 40.5) + 0.286 * gaussian(530.9, 16.3, 31.1),
+        1.217 * gaussian(437.0, 11.8, 36.0) + 0.681 * gaussian(459.0, 26.0, 13.8),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::observer;
+    use crate::{from_spectrum, from_wavelength, rgb, xyz, Color};
+
+    // Planck's law, giving the spectral power of a blackbody radiator.
+    fn blackbody(kelvin: f32) -> Vec<(f32, f32)> {
+        (380..=780)
+            .step_by(5)
+            .map(|nm| {
+                let meters = nm as f64 * 1e-9;
+                let power =
+                    1.0 / (meters.powi(5) * ((1.4388e-2 / (meters * kelvin as f64)).exp() - 1.0));
+                (nm as f32, (power * 1e-12) as f32)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn integrates_spectra() {
+        for kelvin in [2700.0, 4000.0, 6500.0] {
+            let color = from_spectrum(&blackbody(kelvin));
+
+            // dimmed, so that no channel gets clipped
+            let cct = xyz(color.x / 2.0, color.y / 2.0, color.z / 2.0)
+                .to_rgb()
+                .cct()
+                .unwrap();
+
+            assert_eq!(color.y, 1.0);
+            assert!(
+                (cct - kelvin).abs() / kelvin < 0.03,
+                "{}K: {}K",
+                kelvin,
+                cct
+            );
+        }
+    }
+
+    #[test]
+    fn integrates_sparse_spectra() {
+        // A narrow green peak, sampled coarsely.
+        let green = from_spectrum(&[(520.0, 0.0), (530.0, 1.0), (540.0, 0.0)]);
+        let hue = green.to_rgb().to_oklch().h;
+
+        assert!(hue > 130.0 && hue < 160.0, "{}", hue);
+        assert_eq!(
+            from_spectrum(&[]),
+            from_spectrum(&[(900.0, 1.0), (1000.0, 2.0)])
+        );
+        assert_eq!(from_spectrum(&[]).y, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_unordered_spectra() {
+        from_spectrum(&[(500.0, 1.0), (400.0, 1.0)]);
+    }
+
+    #[test]
+    fn fits_standard_observer() {
+        // Published values of the CIE 1931 observer.
+        let expected = [
+            (450.0, [0.3362, 0.0380, 1.7721]),
+            (555.0, [0.5121, 1.0000, 0.0058]),
+            (600.0, [1.0622, 0.6310, 0.0008]),
+        ];
+
+        for (nm, values) in expected {
+            for (fit, value) in observer(nm).iter().zip(values) {
+                assert!((fit - value).abs() < 0.06, "{}nm: {} {}", nm, fit, value);
+            }
+        }
+    }
+
+    #[test]
+    fn goes_through_the_rainbow() {
+        let hue = |nm: f32| from_wavelength(nm).to_oklch().h;
+
+        assert!(hue(470.0) > hue(500.0));
+        assert!(hue(500.0) > hue(550.0));
+        assert!(hue(550.0) > hue(600.0));
+        assert!(hue(600.0) > hue(650.0));
+    }
+
+    #[test]
+    fn fades_at_the_ends() {
+        assert!(from_wavelength(390.0).to_oklch().l < from_wavelength(450.0).to_oklch().l);
+        assert!(from_wavelength(770.0).to_oklch().l < from_wavelength(650.0).to_oklch().l);
+        assert_eq!(from_wavelength(800.0), rgb(0, 0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_wavelengths() {
+        from_wavelength(f32::INFINITY);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{ramp::fit_chroma, Color, Ratio, RGB, RGBA};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to configure `state_variants_with()`. Lightness deltas are in
+/// OKLCH, where `0.05` is a small but visible step.
+pub struct StateDeltas {
+    // the lightness step of the hover state
+    pub hover: f32,
+
+    // the lightness step of the focus state
+    pub focus: f32,
+
+    // the lightness step of the active (pressed) state
+    pub active: f32,
+
+    // the opacity of the disabled state, relative to the base color
+    pub disabled_opacity: Ratio,
+}
+
+impl Default for StateDeltas {
+    fn default() -> Self {
+        StateDeltas {
+            hover: 0.05,
+            focus: 0.08,
+            active: 0.1,
+            disabled_opacity: Ratio::from_f32(0.38),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that holds the colors of an interactive element in each of its
+/// states, as built by `state_variants()`.
+pub struct StateVariants {
+    pub base: RGBA,
+    pub hover: RGBA,
+    pub focus: RGBA,
+    pub active: RGBA,
+    pub disabled: RGBA,
+}
+
+// Builds the state variants of `color`. Light colors get darker as they are
+// interacted with and dark colors lighter, so the steps always stay visible.
+pub(crate) fn state_variants<T: Color>(color: T, deltas: StateDeltas) -> StateVariants {
+    let base = color.to_rgba();
+    let lch = base.to_oklch();
+    let direction = if base.is_light() { -1.0 } else { 1.0 };
+
+    let step = |delta: f32| {
+        let RGB { r, g, b } = fit_chroma((lch.l + delta * direction).clamp(0.0, 1.0), lch.c, lch.h);
+        RGBA { r, g, b, a: base.a }
+    };
+
+    StateVariants {
+        base,
+        hover: step(deltas.hover),
+        focus: step(deltas.focus),
+        active: step(deltas.active),
+        disabled: RGBA {
+            a: Ratio::from_f32(base.a.as_f32() * deltas.disabled_opacity.as_f32()),
+            ..base
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{percent, rgb, rgba, Color, StateDeltas};
+
+    #[test]
+    fn darkens_light_colors() {
+        let yellow = rgb(255, 214, 0);
+        let states = yellow.state_variants();
+        let l = |color: crate::RGBA| color.to_oklch().l;
+
+        assert_eq!(states.base, yellow.to_rgba());
+        assert!(l(states.hover) < l(states.base));
+        assert!(l(states.focus) < l(states.hover));
+        assert!(l(states.active) < l(states.focus));
+        assert!((l(states.base) - l(states.hover) - 0.05).abs() < 0.01);
+    }
+
+    #[test]
+    fn lightens_dark_colors() {
+        let navy = rgb(23, 42, 98);
+        let states = navy.state_variants();
+        let l = |color: crate::RGBA| color.to_oklch().l;
+
+        assert!(l(states.hover) > l(states.base));
+        assert!(l(states.active) > l(states.focus));
+        assert!(navy.hue_distance(states.active, crate::HueSpace::OKLCH) < 3.0);
+    }
+
+    #[test]
+    fn fades_disabled_state() {
+        assert_eq!(
+            rgb(23, 98, 119).state_variants().disabled,
+            rgba(23, 98, 119, 0.38)
+        );
+        assert_eq!(
+            rgba(23, 98, 119, 0.5)
+                .state_variants_with(StateDeltas {
+                    disabled_opacity: percent(50),
+                    ..StateDeltas::default()
+                })
+                .disabled,
+            rgba(23, 98, 119, 0.25)
+        );
+    }
+
+    #[test]
+    fn can_configure_deltas() {
+        let states = rgb(23, 98, 119).state_variants_with(StateDeltas {
+            hover: 0.0,
+            ..StateDeltas::default()
+        });
+
+        assert_eq!(states.hover, rgba(23, 98, 119, 1.0));
+        assert_eq!(states.active.a, states.base.a);
+    }
+}
@@ -0,0 +1,531 @@
+use super::{rgb, Color, ColorSpace, Palette, RGB};
+use std::fmt::Write;
+
+impl Palette {
+    /// Writes the palette as a GIMP palette file (`.gpl`), read by GIMP,
+    /// Inkscape, Krita and Aseprite, under the given name. The format has no
+    /// alpha channel, so colors are written opaque, and unnamed colors are
+    /// named `Untitled`, as GIMP names them.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("salmon", rgb(250, 128, 114));
+    /// palette.push(rgb(0, 0, 0));
+    ///
+    /// assert_eq!(
+    ///     palette.to_gpl("Fish"),
+    ///     "GIMP Palette\nName: Fish\n#\n250 128 114\tsalmon\n  0   0   0\tUntitled\n"
+    /// );
+    /// ```
+    pub fn to_gpl(&self, name: &str) -> String {
+        let mut gpl = format!("GIMP Palette\nName: {}\n#\n", name.replace('\n', " "));
+
+        for (name, color) in self.iter_named() {
+            let color = color.to_rgb();
+            let _ = writeln!(
+                gpl,
+                "{:3} {:3} {:3}\t{}",
+                color.r.as_u8(),
+                color.g.as_u8(),
+                color.b.as_u8(),
+                name.unwrap_or("Untitled").replace('\n', " ")
+            );
+        }
+
+        gpl
+    }
+
+    /// Reads the colors of a GIMP palette file (`.gpl`), with their names.
+    /// Colors named `Untitled` are read unnamed, and the header fields and
+    /// comments are skipped. Returns `None` if the input does not follow the
+    /// format.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Palette};
+    ///
+    /// let palette = Palette::from_gpl("GIMP Palette\nName: Fish\nColumns: 4\n# a comment\n250 128 114 salmon\n").unwrap();
+    ///
+    /// assert_eq!(palette.get_named("salmon"), Some(rgba(250, 128, 114, 1.0)));
+    /// assert_eq!(Palette::from_gpl("250 128 114 salmon"), None);
+    /// ```
+    pub fn from_gpl(input: &str) -> Option<Palette> {
+        let mut lines = input.trim_start_matches('\u{feff}').lines();
+        if lines.next()?.trim() != "GIMP Palette" {
+            return None;
+        }
+
+        let mut palette = Palette::new();
+
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("Name:")
+                || line.starts_with("Columns:")
+            {
+                continue;
+            }
+
+            let mut rest = line;
+            let mut channels = [0; 3];
+            for channel in &mut channels {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                *channel = rest[..end].parse().ok()?;
+                rest = rest[end..].trim_start();
+            }
+
+            let color = rgb(channels[0], channels[1], channels[2]);
+            match rest {
+                "" | "Untitled" => palette.push(color),
+                name => palette.push_named(name, color),
+            }
+        }
+
+        Some(palette)
+    }
+}
+
+impl Palette {
+    /// Writes the palette as an Adobe Swatch Exchange file (`.ase`), read by
+    /// Illustrator, InDesign and Photoshop, with every color as an RGB swatch
+    /// named after the color and empty for unnamed colors. The format has
+    /// no alpha channel, so colors are written opaque.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("salmon", rgb(250, 128, 114));
+    ///
+    /// let ase = palette.to_ase();
+    ///
+    /// assert!(ase.starts_with(b"ASEF"));
+    /// assert_eq!(Palette::from_ase(&ase), Some(palette));
+    /// ```
+    pub fn to_ase(&self) -> Vec<u8> {
+        let mut ase = b"ASEF\0\x01\0\0".to_vec();
+        ase.extend((self.len() as u32).to_be_bytes());
+
+        for (name, color) in self.iter_named() {
+            let name = utf16(name.unwrap_or_default(), u16::MAX as usize);
+
+            let mut block = (name.len() as u16).to_be_bytes().to_vec();
+            block.extend(name.iter().flat_map(|unit| unit.to_be_bytes()));
+            block.extend(b"RGB ");
+            for channel in [color.r, color.g, color.b] {
+                block.extend(channel.as_f32().to_be_bytes());
+            }
+            // a normal swatch, rather than a global or spot color
+            block.extend(2_u16.to_be_bytes());
+
+            ase.extend(1_u16.to_be_bytes());
+            ase.extend((block.len() as u32).to_be_bytes());
+            ase.extend(block);
+        }
+
+        ase
+    }
+
+    /// Reads the swatches of an Adobe Swatch Exchange file (`.ase`), with
+    /// their names. Swatches may be RGB, CMYK, Lab or grayscale, and groups
+    /// are flattened into the list of their swatches. CMYK is converted
+    /// naively, without a color profile. Returns `None` if the input does not
+    /// follow the format.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::Palette;
+    ///
+    /// assert_eq!(Palette::from_ase(b"ASEF\0\x01\0\0\0\0\0\0"), Some(Palette::new()));
+    /// assert_eq!(Palette::from_ase(b"ASEF"), None);
+    /// ```
+    pub fn from_ase(bytes: &[u8]) -> Option<Palette> {
+        let mut reader = Reader(bytes);
+        if reader.take(4)? != b"ASEF" {
+            return None;
+        }
+        reader.take(4)?;
+
+        let mut palette = Palette::new();
+
+        for _ in 0..reader.u32()? {
+            let kind = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let mut block = Reader(reader.take(length)?);
+
+            // the blocks starting and ending groups only hold their names
+            if kind != 0x0001 {
+                continue;
+            }
+
+            let units = block.u16()? as usize;
+            let name = block.utf16(units)?;
+            let model = block.take(4)?;
+            let mut channel = || {
+                block
+                    .take(4)
+                    .map(|bytes| f32::from_be_bytes(bytes.try_into().unwrap()))
+            };
+
+            let color = match model {
+                b"RGB " => ColorSpace::SRGB.compose([channel()?, channel()?, channel()?]),
+                b"CMYK" => {
+                    ColorSpace::SRGB.compose(cmyk([channel()?, channel()?, channel()?, channel()?]))
+                }
+                b"LAB " => ColorSpace::LAB.compose([channel()? * 100.0, channel()?, channel()?]),
+                b"Gray" => {
+                    let gray = channel()?;
+                    ColorSpace::SRGB.compose([gray; 3])
+                }
+                _ => return None,
+            };
+
+            push(&mut palette, name, color);
+        }
+
+        Some(palette)
+    }
+
+    /// Writes the palette as a Photoshop color swatches file (`.aco`), with
+    /// every color as an RGB swatch named after the color and empty for
+    /// unnamed colors. The file holds the version 1 list of colors, read by
+    /// older software, followed by the version 2 list with their names. The
+    /// format has no alpha channel, so colors are written opaque, and holds
+    /// up to 65535 colors, past which colors are left out.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("salmon", rgb(250, 128, 114));
+    ///
+    /// assert_eq!(Palette::from_aco(&palette.to_aco()), Some(palette));
+    /// ```
+    pub fn to_aco(&self) -> Vec<u8> {
+        let count = self.len().min(u16::MAX as usize);
+        let mut aco = Vec::new();
+
+        for version in [1_u16, 2] {
+            aco.extend(version.to_be_bytes());
+            aco.extend((count as u16).to_be_bytes());
+
+            for (name, color) in self.iter_named().take(count) {
+                // the RGB color space, then its channels and an unused one
+                aco.extend(0_u16.to_be_bytes());
+                for channel in [color.r, color.g, color.b] {
+                    aco.extend((channel.as_u8() as u16 * 257).to_be_bytes());
+                }
+                aco.extend(0_u16.to_be_bytes());
+
+                if version == 2 {
+                    let name = utf16(name.unwrap_or_default(), u32::MAX as usize);
+                    aco.extend((name.len() as u32).to_be_bytes());
+                    aco.extend(name.iter().flat_map(|unit| unit.to_be_bytes()));
+                }
+            }
+        }
+
+        aco
+    }
+
+    /// Reads the swatches of a Photoshop color swatches file (`.aco`), with
+    /// their names if the file holds its version 2 list. Swatches may be
+    /// RGB, HSB, CMYK, Lab or grayscale, and CMYK is converted naively,
+    /// without a color profile. Returns `None` if the input does not follow
+    /// the format.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Palette};
+    ///
+    /// // a version 1 file holding one RGB swatch
+    /// let aco = [0, 1, 0, 1, 0, 0, 0xfa, 0xfa, 0x80, 0x80, 0x72, 0x72, 0, 0];
+    ///
+    /// assert_eq!(Palette::from_aco(&aco).unwrap().get(0), Some(rgba(250, 128, 114, 1.0)));
+    /// assert_eq!(Palette::from_aco(&aco[..6]), None);
+    /// ```
+    pub fn from_aco(bytes: &[u8]) -> Option<Palette> {
+        let mut reader = Reader(bytes);
+        let mut palette = None;
+
+        // the version 2 list, if any, repeats the colors with their names
+        while !reader.0.is_empty() {
+            let version = reader.u16()?;
+            if !matches!((version, &palette), (1, None) | (2, _)) {
+                return None;
+            }
+
+            let mut swatches = Palette::new();
+
+            for _ in 0..reader.u16()? {
+                let space = reader.u16()?;
+                let [w, x, y, z] = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+                let name = match version {
+                    2 => {
+                        let units = reader.u32()? as usize;
+                        reader.utf16(units)?
+                    }
+                    _ => String::new(),
+                };
+
+                let color = match space {
+                    0 => {
+                        ColorSpace::SRGB.compose([w, x, y].map(|channel| channel as f32 / 65535.0))
+                    }
+                    1 => ColorSpace::SRGB
+                        .compose(hsb([w, x, y].map(|channel| channel as f32 / 65535.0))),
+                    // ink coverage is inverted, 0 being full coverage
+                    2 => ColorSpace::SRGB.compose(cmyk(
+                        [w, x, y, z].map(|channel| 1.0 - channel as f32 / 65535.0),
+                    )),
+                    7 => ColorSpace::LAB.compose([
+                        w as f32 / 100.0,
+                        x as i16 as f32 / 100.0,
+                        y as i16 as f32 / 100.0,
+                    ]),
+                    8 => ColorSpace::SRGB.compose([w as f32 / 10000.0; 3]),
+                    _ => return None,
+                };
+
+                push(&mut swatches, name, color);
+            }
+
+            palette = Some(swatches);
+        }
+
+        palette
+    }
+}
+
+// Appends a swatch to a palette, unnamed if its name is empty.
+fn push(palette: &mut Palette, name: String, color: RGB) {
+    if name.is_empty() {
+        palette.push(color);
+    } else {
+        palette.push_named(name, color);
+    }
+}
+
+// Encodes a name into UTF-16 with a terminating null, as swatch files store
+// names, cut short to fit in `max` code units.
+fn utf16(name: &str, max: usize) -> Vec<u16> {
+    let mut units: Vec<u16> = name.encode_utf16().take(max - 1).collect();
+    units.push(0);
+    units
+}
+
+// Converts CMYK ink coverage into sRGB channels, without a color profile.
+fn cmyk([c, m, y, k]: [f32; 4]) -> [f32; 3] {
+    [c, m, y].map(|ink| (1.0 - ink) * (1.0 - k))
+}
+
+// Converts hue, saturation and brightness, all between 0.0 and 1.0, into
+// sRGB channels.
+fn hsb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    [5.0, 3.0, 1.0].map(|n: f32| {
+        let k = (n + h * 6.0) % 6.0;
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    })
+}
+
+// Reads the big-endian values of swatch files.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.0.len() {
+            return None;
+        }
+
+        let (bytes, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    // Reads a name of `units` UTF-16 code units, dropping its terminating null.
+    fn utf16(&mut self, units: usize) -> Option<String> {
+        let bytes = self.take(units.checked_mul(2)?)?;
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+
+        let name = String::from_utf16(&units).ok()?;
+        Some(name.trim_end_matches('\0').to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cmyk, hsb};
+    use crate::{rgb, rgba, Palette};
+
+    // Builds an ASE color block from its name, model and channels.
+    fn ase_block(name: &str, model: &[u8; 4], channels: &[f32]) -> Vec<u8> {
+        let name: Vec<u16> = name.encode_utf16().chain([0]).collect();
+
+        let mut block = (name.len() as u16).to_be_bytes().to_vec();
+        block.extend(name.iter().flat_map(|unit| unit.to_be_bytes()));
+        block.extend(model);
+        block.extend(channels.iter().flat_map(|channel| channel.to_be_bytes()));
+        block.extend(0_u16.to_be_bytes());
+
+        let mut bytes = 1_u16.to_be_bytes().to_vec();
+        bytes.extend((block.len() as u32).to_be_bytes());
+        bytes.extend(block);
+        bytes
+    }
+
+    #[test]
+    fn can_round_trip_ase() {
+        let mut palette = Palette::new();
+        palette.push_named("Ünïcødé 🎨", rgb(0, 105, 148));
+        palette.push(rgb(255, 255, 255));
+
+        let read = Palette::from_ase(&palette.to_ase()).unwrap();
+        assert_eq!(read, palette);
+        assert_eq!(read.name(1), None);
+    }
+
+    #[test]
+    fn can_read_ase_models() {
+        let mut ase = b"ASEF\0\x01\0\0\0\0\0\x05".to_vec();
+        // a group holding the swatches, which is flattened
+        ase.extend([0xc0, 0x01, 0, 0, 0, 4, 0, 1, 0, 0]);
+        ase.extend(ase_block("cyan", b"CMYK", &[1.0, 0.0, 0.0, 0.0]));
+        ase.extend(ase_block("white", b"LAB ", &[1.0, 0.0, 0.0]));
+        ase.extend(ase_block("", b"Gray", &[0.5]));
+        ase.extend([0xc0, 0x02, 0, 0, 0, 0]);
+
+        let palette = Palette::from_ase(&ase).unwrap();
+        assert_eq!(palette.get_named("cyan"), Some(rgba(0, 255, 255, 1.0)));
+        assert_eq!(palette.get_named("white"), Some(rgba(255, 255, 255, 1.0)));
+        assert_eq!(palette.get(2), Some(rgba(128, 128, 128, 1.0)));
+        assert_eq!(palette.name(2), None);
+
+        let mut unknown = b"ASEF\0\x01\0\0\0\0\0\x01".to_vec();
+        unknown.extend(ase_block("", b"HSV ", &[0.0, 0.0, 0.0]));
+        assert_eq!(Palette::from_ase(&unknown), None);
+        assert_eq!(Palette::from_ase(&ase[..ase.len() - 1]), None);
+    }
+
+    #[test]
+    fn can_round_trip_aco() {
+        let mut palette = Palette::new();
+        palette.push_named("Deep sea blue", rgb(0, 105, 148));
+        palette.push(rgba(255, 0, 0, 0.5));
+
+        let aco = palette.to_aco();
+        let read = Palette::from_aco(&aco).unwrap();
+        assert_eq!(
+            read.get_named("Deep sea blue"),
+            Some(rgba(0, 105, 148, 1.0))
+        );
+        assert_eq!(read.get(1), Some(rgba(255, 0, 0, 1.0)));
+        assert_eq!(read.name(1), None);
+
+        // the version 1 list alone has the colors, without their names
+        let colors = Palette::from_aco(&aco[..4 + 2 * 10]).unwrap();
+        assert_eq!(colors.name(0), None);
+        assert_eq!(colors.to_hex(), read.to_hex());
+    }
+
+    #[test]
+    fn can_read_aco_color_spaces() {
+        let aco: Vec<u8> = [
+            [1, 5].as_slice(),
+            &[1, 0x5555, 0xffff, 0xffff, 0],
+            &[2, 0, 0xffff, 0xffff, 0xffff],
+            &[7, 10000, 0, 0, 0],
+            &[8, 5000, 0, 0, 0],
+            &[0, 0, 0, 0xffff, 0],
+        ]
+        .concat()
+        .iter()
+        .flat_map(|value: &u16| value.to_be_bytes())
+        .collect();
+
+        let palette = Palette::from_aco(&aco).unwrap();
+        assert_eq!(
+            palette.to_hex(),
+            ["#00ff00", "#00ffff", "#ffffff", "#808080", "#0000ff"]
+        );
+
+        for input in [
+            &[][..],
+            &[0, 3, 0, 0],
+            &[0, 1, 0, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0],
+        ] {
+            assert_eq!(Palette::from_aco(input), None, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn converts_cmyk_and_hsb() {
+        assert_eq!(cmyk([0.0, 0.0, 0.0, 0.0]), [1.0, 1.0, 1.0]);
+        assert_eq!(cmyk([0.0, 1.0, 1.0, 0.5]), [0.5, 0.0, 0.0]);
+        assert_eq!(hsb([0.0, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+        assert_eq!(hsb([0.5, 1.0, 0.5]), [0.0, 0.5, 0.5]);
+        assert_eq!(hsb([0.25, 0.0, 0.8]), [0.8, 0.8, 0.8]);
+    }
+
+    #[test]
+    fn can_round_trip_gpl() {
+        let mut palette = Palette::new();
+        palette.push_named("Deep sea blue", rgb(0, 105, 148));
+        palette.push(rgb(255, 255, 255));
+        palette.push_named("line\nbreak", rgb(1, 2, 3));
+
+        let gpl = palette.to_gpl("Sea");
+        assert!(gpl.starts_with("GIMP Palette\nName: Sea\n"));
+
+        let read = Palette::from_gpl(&gpl).unwrap();
+        assert_eq!(read.len(), 3);
+        assert_eq!(
+            read.get_named("Deep sea blue"),
+            Some(rgba(0, 105, 148, 1.0))
+        );
+        assert_eq!(read.name(1), None);
+        assert_eq!(read.name(2), Some("line break"));
+    }
+
+    #[test]
+    fn writes_gpl_colors_opaque() {
+        let mut palette = Palette::new();
+        palette.push(rgba(255, 0, 0, 0.5));
+
+        assert!(palette.to_gpl("").ends_with("255   0   0\tUntitled\n"));
+    }
+
+    #[test]
+    fn handles_invalid_gpl() {
+        for input in [
+            "",
+            "Not A Palette\n",
+            "GIMP Palette\n256 0 0 Red\n",
+            "GIMP Palette\n255 0 Red\n",
+            "GIMP Palette\n255 0\n",
+        ] {
+            assert_eq!(Palette::from_gpl(input), None, "{:?}", input);
+        }
+
+        assert_eq!(
+            Palette::from_gpl("\u{feff}GIMP Palette\r\nName: x\r\n\r\n  1   2   3\r\n"),
+            Some([rgb(1, 2, 3)].into_iter().collect())
+        );
+    }
+}
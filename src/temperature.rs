@@ -0,0 +1,193 @@
+use super::{
+    linear::{from_linear_channels, from_xyz, linear_channels, to_xyz},
+    Color, RGB,
+};
+
+// The range of temperatures the locus approximations hold for, in kelvin.
+const MIN_TEMPERATURE: f32 = 1667.0;
+const MAX_TEMPERATURE: f32 = 25000.0;
+const MIN_DAYLIGHT: f32 = 4000.0;
+
+/// Converts a color temperature in kelvin into the color of a blackbody
+/// radiator at that temperature, like "2700K warm white" light bulbs, as
+/// bright as sRGB can show it.
+///
+/// The chromaticity follows the approximation of the Planckian locus by Kim
+/// et al., which holds from 1667K to 25000K. Temperatures outside of that
+/// range are clamped into it. Panics if `kelvin` is not finite.
+///
+/// # Example
+/// ```
+/// use farver::{from_temperature, rgb};
+///
+/// assert_eq!(from_temperature(2700.0), rgb(255, 173, 89));
+/// assert_eq!(from_temperature(6500.0), rgb(255, 249, 254));
+/// ```
+pub fn from_temperature(kelvin: f32) -> RGB {
+    assert!(kelvin.is_finite(), "Invalid temperature");
+
+    let (x, y) = planckian(kelvin.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE) as f64);
+    from_chromaticity(x, y)
+}
+
+// Approximates the CIE xy chromaticity of a blackbody radiator, following
+// Kim et al.
+fn planckian(t: f64) -> (f64, f64) {
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t.powi(3) - 0.2343589e6 / t.powi(2) + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t.powi(3) + 2.1070379e6 / t.powi(2) + 0.2226347e3 / t + 0.240390
+    };
+    let y = if t <= 2222.0 {
+        -1.1063814 * x.powi(3) - 1.34811020 * x.powi(2) + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x.powi(3) - 1.37418593 * x.powi(2) + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x.powi(3) - 5.87338670 * x.powi(2) + 3.75112997 * x - 0.37001483
+    };
+
+    (x, y)
+}
+
+// Estimates the correlated color temperature of a color, as the temperature
+// of the point of the Planckian locus closest to it in the CIE 1960 UCS,
+// like Robertson's method. The locus is searched evenly in mireds, where
+// equal steps look about as different.
+pub(crate) fn cct<T: Color>(color: T) -> Option<f32> {
+    let [x, y, z] = to_xyz(linear_channels(color.to_rgb()).map(|channel| channel as f64));
+
+    if x + y + z == 0.0 {
+        return None;
+    }
+
+    let target = ucs(x / (x + y + z), y / (x + y + z));
+    let distance = |mired: f64| {
+        let (x, y) = planckian(1e6 / mired);
+        let (u, v) = ucs(x, y);
+        (u - target.0).powi(2) + (v - target.1).powi(2)
+    };
+
+    // golden-section search of the closest mired
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (1e6 / MAX_TEMPERATURE as f64, 1e6 / MIN_TEMPERATURE as f64);
+
+    for _ in 0..64 {
+        let lhs = high - ratio * (high - low);
+        let rhs = low + ratio * (high - low);
+
+        if distance(lhs) < distance(rhs) {
+            high = rhs;
+        } else {
+            low = lhs;
+        }
+    }
+
+    Some((2e6 / (low + high)) as f32)
+}
+
+// Converts a CIE xy chromaticity into CIE 1960 uv.
+fn ucs(x: f64, y: f64) -> (f64, f64) {
+    let denominator = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / denominator, 6.0 * y / denominator)
+}
+
+/// Converts a color temperature in kelvin into the color of daylight at that
+/// temperature, along the CIE daylight locus the D-series illuminants are
+/// defined on, as bright as sRGB can show it. D65, the white of sRGB, lies
+/// at about 6504K.
+///
+/// The locus is defined from 4000K to 25000K, and temperatures outside of
+/// that range are clamped into it. Panics if `kelvin` is not finite.
+///
+/// # Example
+/// ```
+/// use farver::{from_daylight, rgb};
+///
+/// assert_eq!(from_daylight(6504.0), rgb(255, 255, 255));
+/// assert_eq!(from_daylight(5000.0), rgb(255, 235, 205));
+/// ```
+pub fn from_daylight(kelvin: f32) -> RGB {
+    assert!(kelvin.is_finite(), "Invalid temperature");
+
+    let t = kelvin.clamp(MIN_DAYLIGHT, MAX_TEMPERATURE) as f64;
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 0.24748e3 / t + 0.237040
+    };
+    let y = -3.000 * x * x + 2.870 * x - 0.275;
+
+    from_chromaticity(x, y)
+}
+
+// Converts a CIE xy chromaticity into the brightest sRGB color with it,
+// clipping the channels that fall outside of the gamut.
+fn from_chromaticity(x: f64, y: f64) -> RGB {
+    let linear = from_xyz([x / y, 1.0, (1.0 - x - y) / y]).map(|channel| channel.max(0.0));
+    let max = linear[0].max(linear[1]).max(linear[2]);
+
+    from_linear_channels(linear.map(|channel| (channel / max) as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_daylight, from_temperature, rgb, Color};
+
+    #[test]
+    fn estimates_temperatures() {
+        for kelvin in [1900.0, 2700.0, 4000.0, 5500.0, 6500.0, 9000.0, 15000.0] {
+            let cct = from_temperature(kelvin).cct().unwrap();
+
+            // 8-bit channels shift the chromaticity a little.
+            assert!(
+                (1e6 / cct - 1e6 / kelvin).abs() < 3.0,
+                "{}K: {}K",
+                kelvin,
+                cct
+            );
+        }
+
+        let d65 = rgb(255, 255, 255).cct().unwrap();
+        assert!((d65 - 6504.0).abs() < 30.0, "{}", d65);
+        assert_eq!(rgb(0, 0, 0).cct(), None);
+    }
+
+    #[test]
+    fn warms_up_as_temperature_drops() {
+        let mut previous = from_temperature(25000.0);
+
+        for kelvin in (1700..25000).rev().step_by(500) {
+            let color = from_temperature(kelvin as f32);
+
+            assert_eq!(
+                color.r.as_u8().max(color.g.as_u8()).max(color.b.as_u8()),
+                255
+            );
+            assert!(color.b <= previous.b, "{}K", kelvin);
+            previous = color;
+        }
+
+        assert!(from_temperature(1667.0).to_oklch().h < 70.0);
+        assert!(from_temperature(25000.0).to_oklch().h > 220.0);
+    }
+
+    #[test]
+    fn clamps_temperatures() {
+        assert_eq!(from_temperature(100.0), from_temperature(1667.0));
+        assert_eq!(from_temperature(1e6), from_temperature(25000.0));
+        assert_eq!(from_daylight(1000.0), from_daylight(4000.0));
+    }
+
+    #[test]
+    fn follows_daylight_locus() {
+        assert_eq!(from_daylight(6504.0), rgb(255, 255, 255));
+        assert!(from_daylight(5003.0).is_light());
+        assert!(from_daylight(4000.0).b < from_daylight(7500.0).b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_invalid_temperatures() {
+        from_temperature(f32::NAN);
+    }
+}
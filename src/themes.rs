@@ -0,0 +1,587 @@
+use super::{css::parse_color, Color, ColorSpace, Palette, ANSI16_XTERM, RGB};
+use std::fmt::Write;
+
+// The names of the 16 colors of a Base16 scheme, from the darkest background
+// to the accents.
+const BASE16: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08",
+    "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+impl Palette {
+    /// Writes the first 16 colors of the palette as a [Base16](https://github.com/chriskempson/base16)
+    /// scheme in YAML, the colors `base00` to `base0F`, under the given
+    /// scheme name and author. The format has no alpha channel, so colors
+    /// are written opaque. Panics if the palette holds fewer than 16 colors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let palette: Palette = (0..16).map(|i| rgb(i * 16, i * 16, i * 16)).collect();
+    /// let scheme = palette.to_base16("Grays", "Jane Doe");
+    ///
+    /// assert!(scheme.starts_with("scheme: \"Grays\"\nauthor: \"Jane Doe\"\nbase00: \"000000\"\n"));
+    /// assert_eq!(Palette::from_base16(&scheme).unwrap().to_hex(), palette.to_hex());
+    /// ```
+    pub fn to_base16(&self, scheme: &str, author: &str) -> String {
+        assert!(self.len() >= 16, "Invalid Base16 palette");
+
+        let quote = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut yaml = format!(
+            "scheme: \"{}\"\nauthor: \"{}\"\n",
+            quote(scheme),
+            quote(author)
+        );
+
+        for (name, color) in BASE16.iter().zip(self.iter()) {
+            let hex = color.to_rgb().to_hex();
+            let _ = writeln!(yaml, "{}: \"{}\"", name, &hex[1..]);
+        }
+
+        yaml
+    }
+
+    /// Reads a [Base16](https://github.com/chriskempson/base16) scheme in
+    /// YAML into a palette of its 16 colors, named `base00` to `base0F` in
+    /// order. Both the original format, with the colors at the top level,
+    /// and the format of Tinted Theming, with the colors under `palette`,
+    /// are read, and the other fields are skipped. Returns `None` if any of
+    /// the colors is missing or is not a hex color.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Palette};
+    ///
+    /// let yaml: String = (0..16)
+    ///     .map(|i| format!("  base0{:X}: \"#{:02x}0000\"\n", i, i * 16))
+    ///     .collect();
+    /// let palette = Palette::from_base16(&format!("system: \"base16\"\npalette:\n{}", yaml)).unwrap();
+    ///
+    /// assert_eq!(palette.get_named("base0F"), Some(rgba(240, 0, 0, 1.0)));
+    /// ```
+    pub fn from_base16(input: &str) -> Option<Palette> {
+        let mut colors = [None; 16];
+
+        for line in input.lines() {
+            let Some((key, value)) = line.trim().split_once(':') else {
+                continue;
+            };
+            let Some(i) = BASE16
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(key.trim()))
+            else {
+                continue;
+            };
+
+            let hex = yaml_scalar(value);
+            colors[i] = Some(parse_color(&format!("#{}", hex.trim_start_matches('#')))?);
+        }
+
+        let mut palette = Palette::new();
+        for (name, color) in BASE16.iter().zip(colors) {
+            palette.push_named(*name, color?);
+        }
+
+        Some(palette)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The colors of a terminal emulator theme, to be exported to the color
+/// scheme formats of terminals.
+pub struct TerminalTheme {
+    pub foreground: RGB,
+    pub background: RGB,
+
+    // the 16 ANSI colors, in the order of `ANSI16_XTERM`
+    pub ansi: [RGB; 16],
+}
+
+// The xterm colors, with its light grey text on black.
+impl Default for TerminalTheme {
+    fn default() -> Self {
+        TerminalTheme {
+            foreground: ANSI16_XTERM[7],
+            background: ANSI16_XTERM[0],
+            ansi: ANSI16_XTERM,
+        }
+    }
+}
+
+// The names terminals give the 8 ANSI colors.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl TerminalTheme {
+    /// Builds a theme from the first 16 colors of a palette, as its ANSI
+    /// colors, and its foreground and background colors. Alpha values are
+    /// dropped. Panics if the palette holds fewer than 16 colors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette, TerminalTheme, ANSI16_VGA};
+    ///
+    /// let palette: Palette = ANSI16_VGA.into_iter().collect();
+    /// let theme = TerminalTheme::from_palette(&palette, rgb(170, 170, 170), rgb(0, 0, 0));
+    ///
+    /// assert_eq!(theme.ansi, ANSI16_VGA);
+    /// ```
+    pub fn from_palette<F: Color, B: Color>(
+        palette: &Palette,
+        foreground: F,
+        background: B,
+    ) -> Self {
+        assert!(palette.len() >= 16, "Invalid terminal palette");
+
+        let mut ansi = ANSI16_XTERM;
+        for (slot, color) in ansi.iter_mut().zip(palette.iter()) {
+            *slot = color.to_rgb();
+        }
+
+        TerminalTheme {
+            foreground: foreground.to_rgb(),
+            background: background.to_rgb(),
+            ansi,
+        }
+    }
+
+    /// Writes the theme as the colors of an [Alacritty](https://alacritty.org)
+    /// config, in TOML, to be saved on its own and imported by it.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::TerminalTheme;
+    ///
+    /// let toml = TerminalTheme::default().to_alacritty();
+    ///
+    /// assert!(toml.starts_with("[colors.primary]\nforeground = \"#e5e5e5\"\nbackground = \"#000000\"\n"));
+    /// assert!(toml.contains("[colors.bright]\nblack = \"#7f7f7f\"\n"));
+    /// ```
+    pub fn to_alacritty(&self) -> String {
+        let mut toml = format!(
+            "[colors.primary]\nforeground = \"{}\"\nbackground = \"{}\"\n",
+            self.foreground.to_hex(),
+            self.background.to_hex()
+        );
+
+        for (table, colors) in [("normal", &self.ansi[..8]), ("bright", &self.ansi[8..])] {
+            let _ = writeln!(toml, "\n[colors.{}]", table);
+            for (name, color) in ANSI_NAMES.iter().zip(colors) {
+                let _ = writeln!(toml, "{} = \"{}\"", name, color.to_hex());
+            }
+        }
+
+        toml
+    }
+
+    /// Writes the theme as a [kitty](https://sw.kovidgoyal.net/kitty/) color
+    /// scheme, to be included in its config.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::TerminalTheme;
+    ///
+    /// let conf = TerminalTheme::default().to_kitty();
+    ///
+    /// assert!(conf.starts_with("foreground #e5e5e5\nbackground #000000\ncolor0 #000000\n"));
+    /// assert!(conf.ends_with("color15 #ffffff\n"));
+    /// ```
+    pub fn to_kitty(&self) -> String {
+        let mut conf = format!(
+            "foreground {}\nbackground {}\n",
+            self.foreground.to_hex(),
+            self.background.to_hex()
+        );
+
+        for (i, color) in self.ansi.iter().enumerate() {
+            let _ = writeln!(conf, "color{} {}", i, color.to_hex());
+        }
+
+        conf
+    }
+
+    /// Writes the theme as an [iTerm2](https://iterm2.com) color preset
+    /// (`.itermcolors`), a property list of the sRGB components of every
+    /// color, to be imported in its profile settings.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::TerminalTheme;
+    ///
+    /// let plist = TerminalTheme::default().to_iterm2();
+    ///
+    /// assert!(plist.contains("\t<key>Ansi 15 Color</key>\n\t<dict>\n\t\t<key>Alpha Component</key>\n\t\t<real>1</real>\n"));
+    /// ```
+    pub fn to_iterm2(&self) -> String {
+        let mut plist = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+            "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n<dict>\n",
+        ));
+
+        let ansi = self
+            .ansi
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (format!("Ansi {} Color", i), color));
+        let primary = [
+            ("Background Color".to_string(), &self.background),
+            ("Foreground Color".to_string(), &self.foreground),
+        ];
+
+        for (key, color) in ansi.chain(primary) {
+            let _ = write!(plist, "\t<key>{}</key>\n\t<dict>\n", key);
+            for (component, value) in [
+                ("Alpha", 1.0),
+                ("Blue", color.b.as_f32()),
+                ("Green", color.g.as_f32()),
+                ("Red", color.r.as_f32()),
+            ] {
+                let _ = write!(
+                    plist,
+                    "\t\t<key>{} Component</key>\n\t\t<real>{}</real>\n",
+                    component, value
+                );
+            }
+            plist.push_str("\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n\t</dict>\n");
+        }
+
+        plist.push_str("</dict>\n</plist>\n");
+        plist
+    }
+
+    /// Writes the theme as X resources, to be merged into `~/.Xresources` by
+    /// `xrdb` and read by xterm, URxvt and other X terminals.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::TerminalTheme;
+    ///
+    /// let resources = TerminalTheme::default().to_xresources();
+    ///
+    /// assert!(resources.starts_with("*foreground: #e5e5e5\n*background: #000000\n*color0: #000000\n"));
+    /// assert_eq!(TerminalTheme::from_xresources(&resources), Some(TerminalTheme::default()));
+    /// ```
+    pub fn to_xresources(&self) -> String {
+        let mut resources = format!(
+            "*foreground: {}\n*background: {}\n",
+            self.foreground.to_hex(),
+            self.background.to_hex()
+        );
+
+        for (i, color) in self.ansi.iter().enumerate() {
+            let _ = writeln!(resources, "*color{}: {}", i, color.to_hex());
+        }
+
+        resources
+    }
+
+    /// Reads the terminal colors of X resources, like `*color0: #1d1f21` or
+    /// `URxvt.foreground: rgb:c5/c8/c6`, whatever the program they are set
+    /// for. Colors may be hex colors, X `rgb:` colors or CSS color keywords,
+    /// and names given to them with `#define` are resolved. Other resources
+    /// and comments are skipped, and colors left unset keep their value in
+    /// the default theme. Returns `None` if no color is set, or if a color
+    /// is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, TerminalTheme};
+    ///
+    /// let theme = TerminalTheme::from_xresources(
+    ///     "! Tomorrow Night\n#define fg #c5c8c6\nURxvt*font: xft:Hack\n*foreground: fg\n*.color1: rgb:cc/66/66\n",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(theme.foreground, rgb(197, 200, 198));
+    /// assert_eq!(theme.ansi[1], rgb(204, 102, 102));
+    /// assert_eq!(TerminalTheme::from_xresources("*font: xft:Hack"), None);
+    /// ```
+    pub fn from_xresources(input: &str) -> Option<TerminalTheme> {
+        let mut theme = TerminalTheme::default();
+        let mut defines = Vec::new();
+        let mut found = false;
+
+        for line in input.lines().map(str::trim) {
+            if let Some(define) = line.strip_prefix("#define") {
+                if let Some((name, value)) = define.trim().split_once(char::is_whitespace) {
+                    defines.push((name, value.trim()));
+                }
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':').filter(|_| !line.starts_with('!')) else {
+                continue;
+            };
+
+            // the resource name follows the program and any wildcards
+            let name = key.trim().rsplit(['*', '.']).next().unwrap_or_default();
+            let slot = match name {
+                "foreground" => &mut theme.foreground,
+                "background" => &mut theme.background,
+                _ => match name
+                    .strip_prefix("color")
+                    .and_then(|i| i.parse::<usize>().ok())
+                {
+                    Some(i) if i < 16 => &mut theme.ansi[i],
+                    _ => continue,
+                },
+            };
+
+            let mut value = value.trim();
+            if let Some(&(_, defined)) = defines.iter().rev().find(|(name, _)| *name == value) {
+                value = defined;
+            }
+
+            *slot = parse_x_color(value)?;
+            found = true;
+        }
+
+        found.then_some(theme)
+    }
+}
+
+// Parses a color of X resources, either in the `rgb:r/g/b` form X defines,
+// with 1 to 4 hex digits per channel, or as a CSS color.
+fn parse_x_color(value: &str) -> Option<RGB> {
+    let Some(channels) = value.strip_prefix("rgb:") else {
+        return parse_color(value).map(Color::to_rgb);
+    };
+
+    let channels: Vec<f32> = channels
+        .split('/')
+        .map(|digits| {
+            if digits.is_empty() || digits.len() > 4 {
+                return None;
+            }
+
+            let max = 16_u32.pow(digits.len() as u32) - 1;
+            u32::from_str_radix(digits, 16)
+                .ok()
+                .map(|channel| channel as f32 / max as f32)
+        })
+        .collect::<Option<_>>()?;
+
+    match channels[..] {
+        [r, g, b] => Some(ColorSpace::SRGB.compose([r, g, b])),
+        _ => None,
+    }
+}
+
+// Reads a YAML scalar, either quoted or bare with an optional comment after
+// it.
+fn yaml_scalar(value: &str) -> &str {
+    let value = value.trim();
+
+    match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let value = &value[1..];
+            value.find(quote).map_or(value, |end| &value[..end])
+        }
+        _ => value.split(" #").next().unwrap_or_default().trim(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_x_color, yaml_scalar};
+    use crate::{rgb, rgba, Palette, TerminalTheme, ANSI16_VGA};
+
+    fn theme() -> TerminalTheme {
+        TerminalTheme {
+            foreground: rgb(197, 200, 198),
+            background: rgb(29, 31, 33),
+            ansi: ANSI16_VGA,
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_short_terminal_palettes() {
+        let palette: Palette = ANSI16_VGA[..8].iter().copied().collect();
+        TerminalTheme::from_palette(&palette, rgb(0, 0, 0), rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn can_write_alacritty_themes() {
+        let toml = theme().to_alacritty();
+
+        assert_eq!(
+            toml,
+            r##"[colors.primary]
+foreground = "#c5c8c6"
+background = "#1d1f21"
+
+[colors.normal]
+black = "#000000"
+red = "#aa0000"
+green = "#00aa00"
+yellow = "#aa5500"
+blue = "#0000aa"
+magenta = "#aa00aa"
+cyan = "#00aaaa"
+white = "#aaaaaa"
+
+[colors.bright]
+black = "#555555"
+red = "#ff5555"
+green = "#55ff55"
+yellow = "#ffff55"
+blue = "#5555ff"
+magenta = "#ff55ff"
+cyan = "#55ffff"
+white = "#ffffff"
+"##
+        );
+    }
+
+    #[test]
+    fn can_write_kitty_themes() {
+        let conf = theme().to_kitty();
+        let lines: Vec<_> = conf.lines().collect();
+
+        assert_eq!(lines.len(), 18);
+        assert_eq!(
+            lines[..3],
+            ["foreground #c5c8c6", "background #1d1f21", "color0 #000000"]
+        );
+        assert_eq!(lines[11], "color9 #ff5555");
+    }
+
+    #[test]
+    fn can_write_iterm2_themes() {
+        let plist = theme().to_iterm2();
+
+        assert!(plist.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist"));
+        assert!(plist.ends_with("\t</dict>\n</dict>\n</plist>\n"));
+        assert_eq!(plist.matches("<key>Color Space</key>").count(), 18);
+        assert!(plist.contains(concat!(
+            "\t<key>Background Color</key>\n\t<dict>\n",
+            "\t\t<key>Alpha Component</key>\n\t\t<real>1</real>\n",
+            "\t\t<key>Blue Component</key>\n\t\t<real>0.12941177</real>\n",
+            "\t\t<key>Green Component</key>\n\t\t<real>0.12156863</real>\n",
+            "\t\t<key>Red Component</key>\n\t\t<real>0.11372549</real>\n",
+        )));
+    }
+
+    const TOMORROW_NIGHT: &str = r#"
+scheme: "Tomorrow Night"
+author: "Chris Kempson (http://chriskempson.com)"
+base00: "1d1f21"
+base01: "282a2e"
+base02: "373b41"
+base03: "969896"
+base04: "b4b7b4"
+base05: "c5c8c6"
+base06: "e0e0e0"
+base07: "ffffff"
+base08: "cc6666"
+base09: "de935f"
+base0A: "f0c674"
+base0B: "b5bd68"
+base0C: "8abeb7"
+base0D: "81a2be"
+base0E: "b294bb"
+base0F: "a3685a"
+"#;
+
+    #[test]
+    fn can_read_base16() {
+        let palette = Palette::from_base16(TOMORROW_NIGHT).unwrap();
+
+        assert_eq!(palette.len(), 16);
+        assert_eq!(palette.name(0), Some("base00"));
+        assert_eq!(palette.get_named("base00"), Some(rgba(29, 31, 33, 1.0)));
+        assert_eq!(palette.get_named("base0F"), Some(rgba(163, 104, 90, 1.0)));
+
+        let tinted = TOMORROW_NIGHT
+            .replace("scheme:", "system: \"base16\"\nname:")
+            .replace("\nbase", "\n  base")
+            .replace("base0A", "base0a")
+            .replacen("\n  base00", "\npalette:\n  base00", 1);
+        assert_eq!(Palette::from_base16(&tinted), Some(palette));
+    }
+
+    #[test]
+    fn can_write_base16() {
+        let palette = Palette::from_base16(TOMORROW_NIGHT).unwrap();
+        let scheme = palette.to_base16("Tomorrow \"Night\"", "Chris Kempson");
+
+        assert!(scheme.starts_with(
+            "scheme: \"Tomorrow \\\"Night\\\"\"\nauthor: \"Chris Kempson\"\nbase00: \"1d1f21\"\n"
+        ));
+        assert!(scheme.ends_with("base0F: \"a3685a\"\n"));
+        assert_eq!(Palette::from_base16(&scheme), Some(palette));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_short_base16_palettes() {
+        let palette: Palette = [rgb(0, 0, 0)].into_iter().collect();
+        palette.to_base16("", "");
+    }
+
+    #[test]
+    fn handles_invalid_base16() {
+        assert_eq!(Palette::from_base16(""), None);
+        assert_eq!(
+            Palette::from_base16(&TOMORROW_NIGHT.replace("base0F: \"a3685a\"", "")),
+            None
+        );
+        assert_eq!(
+            Palette::from_base16(&TOMORROW_NIGHT.replace("a3685a", "red")),
+            None
+        );
+    }
+
+    #[test]
+    fn can_round_trip_xresources() {
+        let resources = theme().to_xresources();
+
+        assert_eq!(resources.lines().count(), 18);
+        assert!(resources.contains("\n*color15: #ffffff\n"));
+        assert_eq!(TerminalTheme::from_xresources(&resources), Some(theme()));
+    }
+
+    #[test]
+    fn can_read_xresources() {
+        let theme = TerminalTheme::from_xresources(
+            "#define base00 #1d1f21\n\
+             #define base08 #cc6666\n\
+             ! *color0: #ffffff\n\
+             XTerm*background: base00\n\
+             URxvt.color9: base08\n\
+             *color16: #ff0000\n\
+             *colorMode: on\n",
+        )
+        .unwrap();
+
+        assert_eq!(theme.background, rgb(29, 31, 33));
+        assert_eq!(theme.ansi[0], TerminalTheme::default().ansi[0]);
+        assert_eq!(theme.ansi[9], rgb(204, 102, 102));
+        assert_eq!(theme.foreground, TerminalTheme::default().foreground);
+
+        assert_eq!(TerminalTheme::from_xresources(""), None);
+        assert_eq!(TerminalTheme::from_xresources("*color0: nope"), None);
+    }
+
+    #[test]
+    fn parses_x_colors() {
+        assert_eq!(parse_x_color("rgb:f/80/1234"), Some(rgb(255, 128, 18)));
+        assert_eq!(parse_x_color("rgb:ffff/0/0"), Some(rgb(255, 0, 0)));
+        assert_eq!(parse_x_color("tomato"), Some(rgb(255, 99, 71)));
+        assert_eq!(parse_x_color("rgb:ff/00"), None);
+        assert_eq!(parse_x_color("rgb:fffff/0/0"), None);
+        assert_eq!(parse_x_color("rgb:/0/0"), None);
+    }
+
+    #[test]
+    fn reads_yaml_scalars() {
+        assert_eq!(yaml_scalar(" \"1d1f21\" # dark"), "1d1f21");
+        assert_eq!(yaml_scalar(" '1d1f21'"), "1d1f21");
+        assert_eq!(yaml_scalar(" 1d1f21 # dark"), "1d1f21");
+    }
+}
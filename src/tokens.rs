@@ -0,0 +1,601 @@
+use super::{css::parse_color, json::Json, Color, ColorSpace, Palette, Ratio, RGBA};
+
+impl Palette {
+    /// Writes the palette as color tokens in the JSON format of the W3C
+    /// [Design Tokens Community Group](https://www.designtokens.org/tr/drafts/format/),
+    /// read by token pipelines like Style Dictionary.
+    ///
+    /// Each color becomes a token of `$type` `color`, named after the color
+    /// or, for unnamed colors, after their position in the palette. Dots in
+    /// names nest tokens into groups, so `brand.primary` is the `primary`
+    /// token of the `brand` group. The `$value` is written as an sRGB color
+    /// object, with a `hex` fallback, and roles are kept in the `farver`
+    /// extension:
+    ///
+    /// ```json
+    /// {
+    ///   "brand": {
+    ///     "primary": {
+    ///       "$type": "color",
+    ///       "$value": {
+    ///         "colorSpace": "srgb",
+    ///         "components": [
+    ///           0.9804,
+    ///           0.502,
+    ///           0.4471
+    ///         ],
+    ///         "hex": "#fa8072"
+    ///       },
+    ///       "$extensions": {
+    ///         "farver": {
+    ///           "role": "accent"
+    ///         }
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Returns `None` if two colors have the same name, or if a name is both
+    /// a token and a group, like `brand` and `brand.primary`, as a token
+    /// cannot hold other tokens.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.push_named("brand.primary", rgb(250, 128, 114));
+    ///
+    /// let tokens = palette.to_design_tokens().unwrap();
+    /// assert_eq!(Palette::from_design_tokens(&tokens), Some(palette));
+    /// ```
+    pub fn to_design_tokens(&self) -> Option<String> {
+        self.write_tokens(|i, color| {
+            let round = |value: f32| Json::Number((value as f64 * 10000.0).round() / 10000.0);
+            let mut value = vec![
+                ("colorSpace", Json::string("srgb")),
+                (
+                    "components",
+                    Json::Array(vec![
+                        round(color.r.as_f32()),
+                        round(color.g.as_f32()),
+                        round(color.b.as_f32()),
+                    ]),
+                ),
+            ];
+            if color.a.as_u8() < 255 {
+                let alpha = (color.a.as_f32() as f64 * 1000.0).round() / 1000.0;
+                value.push(("alpha", Json::Number(alpha)));
+            }
+            value.push(("hex", Json::String(color.to_rgb().to_hex())));
+
+            let mut token = vec![
+                ("$type", Json::string("color")),
+                ("$value", Json::object(value)),
+            ];
+            if let Some(role) = self.role(i) {
+                let farver = Json::object([("role", Json::string(role))]);
+                token.push(("$extensions", Json::object([("farver", farver)])));
+            }
+
+            Json::object(token)
+        })
+    }
+
+    /// Writes the palette as a token file of [Style Dictionary](https://styledictionary.com),
+    /// in its original format, where every color is a token holding its
+    /// `value` as a hex string and its `type`. Tokens are named and nested in
+    /// groups like in `to_design_tokens()`, so names following its
+    /// category, type and item structure, like `color.brand.primary`, work
+    /// with its built-in transforms. Roles are kept in the `attributes` of
+    /// tokens:
+    ///
+    /// ```json
+    /// {
+    ///   "color": {
+    ///     "brand": {
+    ///       "primary": {
+    ///         "value": "#fa8072",
+    ///         "type": "color",
+    ///         "attributes": {
+    ///           "role": "accent"
+    ///         }
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Returns `None` if names collide, in the same way as `to_design_tokens()`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, MaterialScheme};
+    ///
+    /// let scheme = MaterialScheme::light(rgb(103, 80, 164));
+    /// let tokens = scheme.to_palette().to_style_dictionary().unwrap();
+    ///
+    /// assert!(tokens.contains(r#""on-primary": {"#));
+    /// ```
+    pub fn to_style_dictionary(&self) -> Option<String> {
+        let hexes = self.to_hex();
+
+        self.write_tokens(|i, _| {
+            let mut token = vec![
+                ("value", Json::string(&hexes[i])),
+                ("type", Json::string("color")),
+            ];
+            if let Some(role) = self.role(i) {
+                token.push(("attributes", Json::object([("role", Json::string(role))])));
+            }
+
+            Json::object(token)
+        })
+    }
+
+    // Writes a token for every color, named after the color or its position,
+    // with dots in names nesting tokens into groups.
+    fn write_tokens(&self, token: impl Fn(usize, RGBA) -> Json) -> Option<String> {
+        let mut root = Vec::new();
+
+        for (i, (name, color)) in self.iter_named().enumerate() {
+            let name = match name {
+                Some(name) => name.to_string(),
+                None => format!("color-{}", i + 1),
+            };
+
+            insert(
+                &mut root,
+                &name.split('.').collect::<Vec<_>>(),
+                token(i, color),
+            )?;
+        }
+
+        Some(Node::Group(root).into_json().to_string())
+    }
+
+    /// Reads the color tokens of a file in the JSON format of the W3C
+    /// [Design Tokens Community Group](https://www.designtokens.org/tr/drafts/format/)
+    /// into a palette, in the order they appear, named after their path of
+    /// groups joined by dots, like `brand.primary`.
+    ///
+    /// Tokens of other types are skipped. Types are inherited from the
+    /// `$type` of the groups holding a token, and aliases like
+    /// `{brand.primary}` are resolved, taking the type of the token they
+    /// refer to if they have none. Values may be color objects in any of the
+    /// color spaces of `ColorSpace`, falling back to their `hex` in other
+    /// spaces, or strings holding any CSS color.
+    ///
+    /// Returns `None` if the input does not follow the format, if a color
+    /// token has an invalid value, or if an alias refers to a missing token
+    /// or to itself.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, Palette};
+    ///
+    /// let palette = Palette::from_design_tokens(
+    ///     r##"{
+    ///         "base": {
+    ///             "$type": "color",
+    ///             "red": { "$value": { "colorSpace": "srgb", "components": [1, 0, 0] } },
+    ///             "size": { "$type": "dimension", "$value": "4px" }
+    ///         },
+    ///         "danger": { "$value": "{base.red}" }
+    ///     }"##,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(palette.len(), 2);
+    /// assert_eq!(palette.get_named("danger"), Some(rgba(255, 0, 0, 1.0)));
+    /// ```
+    pub fn from_design_tokens(input: &str) -> Option<Palette> {
+        let json = Json::parse(input)?;
+        let mut tokens = Vec::new();
+        collect(&json, String::new(), None, &mut tokens)?;
+
+        let mut palette = Palette::new();
+
+        for token in &tokens {
+            let (kind, value) = resolve(&tokens, token)?;
+            if kind != Some("color") {
+                continue;
+            }
+
+            palette.push_named(token.path.clone(), parse_value(value)?);
+            if let Some(role) = token.role {
+                palette.set_role(palette.len() - 1, role);
+            }
+        }
+
+        Some(palette)
+    }
+}
+
+// A token read from a design tokens file, with the type it inherits.
+struct Token<'a> {
+    path: String,
+    kind: Option<&'a str>,
+    value: &'a Json,
+    role: Option<&'a str>,
+}
+
+// A token being written, or a group of them. Both are objects once written,
+// so they are told apart until then.
+enum Node {
+    Token(Json),
+    Group(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn into_json(self) -> Json {
+        match self {
+            Node::Token(token) => token,
+            Node::Group(entries) => Json::Object(
+                entries
+                    .into_iter()
+                    .map(|(name, node)| (name, node.into_json()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+// Inserts a token into a group at a path, creating the groups on the way.
+// Returns `None` if the path is already taken by a token or a group, or goes
+// through a token.
+fn insert(group: &mut Vec<(String, Node)>, path: &[&str], token: Json) -> Option<()> {
+    let (name, rest) = path.split_first().expect("Invalid token path");
+    let index = match group.iter().position(|(key, _)| key == name) {
+        Some(_) if rest.is_empty() => return None,
+        Some(index) => index,
+        None if rest.is_empty() => {
+            group.push((name.to_string(), Node::Token(token)));
+            return Some(());
+        }
+        None => {
+            group.push((name.to_string(), Node::Group(Vec::new())));
+            group.len() - 1
+        }
+    };
+
+    match &mut group[index].1 {
+        Node::Group(entries) => insert(entries, rest, token),
+        Node::Token(_) => None,
+    }
+}
+
+// Collects the tokens of a group and its nested groups, in order.
+fn collect<'a>(
+    group: &'a Json,
+    path: String,
+    kind: Option<&'a str>,
+    tokens: &mut Vec<Token<'a>>,
+) -> Option<()> {
+    let entries = group.as_object()?;
+    let kind = match group.get("$type") {
+        Some(kind) => Some(kind.as_str()?),
+        None => kind,
+    };
+
+    if let Some(value) = group.get("$value") {
+        let role = group
+            .get("$extensions")
+            .and_then(|extensions| extensions.get("farver")?.get("role")?.as_str());
+
+        tokens.push(Token {
+            path,
+            kind,
+            value,
+            role,
+        });
+        return Some(());
+    }
+
+    // properties like `$description` start with `$`, and are not groups
+    for (name, child) in entries.iter().filter(|(name, _)| !name.starts_with('$')) {
+        let path = if path.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", path, name)
+        };
+
+        collect(child, path, kind, tokens)?;
+    }
+
+    Some(())
+}
+
+// Follows the aliases of a token to its value, along with its type, taken
+// from the first token on the way that has one.
+fn resolve<'a>(tokens: &'a [Token], token: &'a Token) -> Option<(Option<&'a str>, &'a Json)> {
+    let (mut kind, mut value) = (token.kind, token.value);
+
+    // a chain longer than there are tokens loops back on itself
+    for _ in 0..=tokens.len() {
+        let alias = value
+            .as_str()
+            .and_then(|text| text.strip_prefix('{')?.strip_suffix('}'));
+
+        match alias {
+            Some(path) => {
+                let target = tokens.iter().find(|token| token.path == path)?;
+                kind = kind.or(target.kind);
+                value = target.value;
+            }
+            None => return Some((kind, value)),
+        }
+    }
+
+    None
+}
+
+// Parses the value of a color token, either a color object or a CSS color.
+fn parse_value(value: &Json) -> Option<RGBA> {
+    if let Some(text) = value.as_str() {
+        return parse_color(text);
+    }
+
+    let alpha = match value.get("alpha") {
+        Some(alpha) => alpha.as_f64()? as f32,
+        None => 1.0,
+    };
+    if !(0.0..=1.0).contains(&alpha) {
+        return None;
+    }
+
+    let rgb = match ColorSpace::from_css_name(value.get("colorSpace")?.as_str()?) {
+        Some(space) => {
+            let components = value.get("components")?.as_array()?;
+            if components.len() != 3 {
+                return None;
+            }
+
+            let mut channels = [0.0; 3];
+            for (channel, component) in channels.iter_mut().zip(components) {
+                *channel = match component {
+                    Json::String(none) if none == "none" => 0.0,
+                    component => component.as_f64()? as f32,
+                };
+            }
+
+            // saturation and lightness are percentages in tokens
+            if space == ColorSpace::HSL {
+                channels[1] /= 100.0;
+                channels[2] /= 100.0;
+            }
+
+            space.compose(channels)
+        }
+        None => {
+            let hex = value.get("hex")?.as_str()?;
+            if !hex.starts_with('#') {
+                return None;
+            }
+
+            parse_color(hex)?.to_rgb()
+        }
+    };
+
+    Some(RGBA {
+        r: rgb.r,
+        g: rgb.g,
+        b: rgb.b,
+        a: Ratio::from_f32(alpha),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl, rgb, rgba, Palette};
+
+    #[test]
+    fn can_write_tokens() {
+        let mut palette = Palette::new();
+        palette.push_named("brand.primary", rgb(255, 0, 0));
+        palette.push(rgba(0, 0, 0, 0.5));
+        palette.push_named("brand.muted", hsl(0, 0, 50));
+        palette.set_role(0, "accent");
+
+        assert_eq!(
+            palette.to_design_tokens().unwrap(),
+            r##"{
+  "brand": {
+    "primary": {
+      "$type": "color",
+      "$value": {
+        "colorSpace": "srgb",
+        "components": [
+          1,
+          0,
+          0
+        ],
+        "hex": "#ff0000"
+      },
+      "$extensions": {
+        "farver": {
+          "role": "accent"
+        }
+      }
+    },
+    "muted": {
+      "$type": "color",
+      "$value": {
+        "colorSpace": "srgb",
+        "components": [
+          0.502,
+          0.502,
+          0.502
+        ],
+        "hex": "#808080"
+      }
+    }
+  },
+  "color-2": {
+    "$type": "color",
+    "$value": {
+      "colorSpace": "srgb",
+      "components": [
+        0,
+        0,
+        0
+      ],
+      "alpha": 0.502,
+      "hex": "#000000"
+    }
+  }
+}
+"##
+        );
+        assert_eq!(Palette::new().to_design_tokens().unwrap(), "{}\n");
+    }
+
+    #[test]
+    fn rejects_colliding_tokens() {
+        let mut nested_first = Palette::new();
+        nested_first.push_named("a.b", rgb(255, 0, 0));
+        nested_first.push_named("a", rgb(0, 0, 255));
+        assert_eq!(nested_first.to_design_tokens(), None);
+
+        let mut token_first = Palette::new();
+        token_first.push_named("a", rgb(0, 0, 255));
+        token_first.push_named("a.b", rgb(255, 0, 0));
+        assert_eq!(token_first.to_design_tokens(), None);
+
+        let mut duplicate = Palette::new();
+        duplicate.push_named("a", rgb(255, 0, 0));
+        duplicate.push_named("a", rgb(0, 0, 255));
+        assert_eq!(duplicate.to_design_tokens(), None);
+    }
+
+    #[test]
+    fn can_write_style_dictionary_tokens() {
+        let mut palette = Palette::new();
+        palette.push_named("color.brand.primary", rgb(250, 128, 114));
+        palette.push_named("color.brand.shadow", rgba(0, 0, 0, 0.5));
+        palette.push(rgb(255, 255, 255));
+        palette.set_role(1, "shadow");
+
+        assert_eq!(
+            palette.to_style_dictionary().unwrap(),
+            r##"{
+  "color": {
+    "brand": {
+      "primary": {
+        "value": "#fa8072",
+        "type": "color"
+      },
+      "shadow": {
+        "value": "#00000080",
+        "type": "color",
+        "attributes": {
+          "role": "shadow"
+        }
+      }
+    }
+  },
+  "color-3": {
+    "value": "#ffffff",
+    "type": "color"
+  }
+}
+"##
+        );
+    }
+
+    #[test]
+    fn rejects_colliding_style_dictionary_tokens() {
+        let mut nested_first = Palette::new();
+        nested_first.push_named("color.brand", rgb(255, 0, 0));
+        nested_first.push_named("color", rgb(0, 0, 255));
+        assert_eq!(nested_first.to_style_dictionary(), None);
+
+        let mut token_first = Palette::new();
+        token_first.push_named("color", rgb(0, 0, 255));
+        token_first.push_named("color.brand", rgb(255, 0, 0));
+        assert_eq!(token_first.to_style_dictionary(), None);
+
+        // unnamed colors are named after their position, which can collide too
+        let mut positional = Palette::new();
+        positional.push_named("color-2", rgb(255, 0, 0));
+        positional.push(rgb(0, 0, 255));
+        assert_eq!(positional.to_style_dictionary(), None);
+    }
+
+    #[test]
+    fn can_round_trip_tokens() {
+        let mut palette = Palette::new();
+        palette.push_named("brand.primary", rgb(250, 128, 114));
+        palette.push_named("brand.shadow", rgba(0, 0, 0, 0.2));
+        palette.push_named("text", rgb(17, 17, 17));
+        palette.set_role(2, "text");
+
+        let read = Palette::from_design_tokens(&palette.to_design_tokens().unwrap()).unwrap();
+        assert_eq!(read, palette);
+        assert_eq!(read.role(2), Some("text"));
+    }
+
+    #[test]
+    fn can_read_tokens() {
+        let palette = Palette::from_design_tokens(
+            r##"{
+                "$description": "A theme",
+                "color": {
+                    "$type": "color",
+                    "ok": { "$value": { "colorSpace": "oklch", "components": [0.628, 0.2577, 29.23] } },
+                    "hsl": { "$value": { "colorSpace": "hsl", "components": ["none", 100, 50], "alpha": 0.5 } },
+                    "p3": { "$value": { "colorSpace": "display-p3", "components": [1, 0, 0], "hex": "#ff0000" } },
+                    "css": { "$value": "rebeccapurple", "$description": "A keyword" }
+                },
+                "space": { "$type": "dimension", "small": { "$value": "4px" } },
+                "alias": { "$value": "{semantic.danger}" },
+                "semantic": { "danger": { "$value": "{color.ok}" } }
+            }"##,
+        )
+        .unwrap();
+
+        let names: Vec<_> = palette
+            .iter_named()
+            .map(|(name, _)| name.unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "color.ok",
+                "color.hsl",
+                "color.p3",
+                "color.css",
+                "alias",
+                "semantic.danger"
+            ]
+        );
+        assert_eq!(palette.get(0), Some(rgba(255, 0, 0, 1.0)));
+        assert_eq!(palette.get(1), Some(rgba(255, 0, 0, 0.5)));
+        assert_eq!(palette.get(2), Some(rgba(255, 0, 0, 1.0)));
+        assert_eq!(palette.get(3), Some(rgba(102, 51, 153, 1.0)));
+        assert_eq!(palette.get_named("alias"), palette.get(0));
+    }
+
+    #[test]
+    fn handles_invalid_tokens() {
+        for input in [
+            "[]",
+            r#"{"a": 1}"#,
+            r#"{"a": {"$type": 1, "$value": "red"}}"#,
+            r#"{"a": {"$type": "color", "$value": "4px"}}"#,
+            r#"{"a": {"$type": "color", "$value": "{b}"}}"#,
+            r#"{"a": {"$type": "color", "$value": "{b}"}, "b": {"$value": "{a}"}}"#,
+            r#"{"a": {"$type": "color", "$value": {"colorSpace": "srgb", "components": [1, 0]}}}"#,
+            r#"{"a": {"$type": "color", "$value": {"colorSpace": "srgb", "components": [1, 0, 0], "alpha": 2}}}"#,
+            r#"{"a": {"$type": "color", "$value": {"colorSpace": "rec2020", "components": [1, 0, 0]}}}"#,
+        ] {
+            assert_eq!(Palette::from_design_tokens(input), None, "{}", input);
+        }
+
+        assert_eq!(
+            Palette::from_design_tokens(r#"{"a": {"$value": "red"}}"#),
+            Some(Palette::new())
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use super::{
+    adaptation::adapt,
+    gamut::xyz_to_srgb,
+    lab::from_white_relative,
+    linear::{from_linear_channels, from_xyz, linear_channels, to_xyz},
+    linear_rgb, Adaptation, Gamut, LinearRGB, WhitePoint, LAB, OKLAB, RGB,
+};
+use std::fmt;
+
+/// Constructs a CIE XYZ color from numerical values, relative to the D65
+/// white point like the `xyz-d65` space of the CSS `color()` function.
+///
+/// The `y` component is the relative luminance, `1.0` being the luminance of
+/// white. The `x` and `z` components are unbounded, but are mostly between
+/// `0.0` and `1.1`.
+///
+/// # Example
+/// ```
+/// use farver::xyz;
+///
+/// let white = xyz(0.9505, 1.0, 1.089);
+///
+/// assert_eq!(white.to_string(), "color(xyz-d65 0.9505 1.0000 1.0890)");
+/// ```
+pub fn xyz(x: f32, y: f32, z: f32) -> XYZ {
+    XYZ { x, y, z }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in the CIE 1931 XYZ color space, relative
+/// to the D65 white point of sRGB. Every other color space is defined from
+/// it, which makes it the common ground for colorimetric data.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#predefined-xyz).
+pub struct XYZ {
+    pub x: f32,
+
+    // relative luminance
+    pub y: f32,
+
+    pub z: f32,
+}
+
+impl XYZ {
+    pub(crate) fn from_rgb(rgb: RGB) -> Self {
+        let [x, y, z] = to_xyz(linear_channels(rgb).map(|channel| channel as f64));
+
+        XYZ {
+            x: x as f32,
+            y: y as f32,
+            z: z as f32,
+        }
+    }
+
+    /// Converts `self` into its RGB representation. Colors falling outside of
+    /// the sRGB gamut are clipped.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_xyz().to_rgb(), tomato);
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        let XYZ { x, y, z } = self;
+        from_linear_channels(from_xyz([x as f64, y as f64, z as f64]).map(|channel| channel as f32))
+    }
+
+    /// Returns the CIE xy chromaticity of `self`, its color regardless of its
+    /// luminance, or `None` for black.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let (x, y) = rgb(255, 255, 255).to_xyz().chromaticity().unwrap();
+    ///
+    /// assert_eq!(format!("{:.4} {:.4}", x, y), "0.3127 0.3290");
+    /// ```
+    pub fn chromaticity(self) -> Option<(f32, f32)> {
+        let sum = self.x + self.y + self.z;
+
+        if sum == 0.0 {
+            None
+        } else {
+            Some((self.x / sum, self.y / sum))
+        }
+    }
+
+    /// Returns whether `self` can be represented in the `gamut`, with every
+    /// linear channel allowed to stray up to `tolerance` outside of the
+    /// `0.0`-`1.0` range, to absorb rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{xyz, Gamut};
+    ///
+    /// assert!(xyz(0.4, 0.3, 0.2).in_gamut(Gamut::Srgb, 1e-4));
+    /// assert!(!xyz(0.1, 0.5, 0.1).in_gamut(Gamut::Srgb, 1e-4));
+    /// ```
+    pub fn in_gamut(self, gamut: Gamut, tolerance: f32) -> bool {
+        gamut.contains(xyz_to_srgb([self.x, self.y, self.z]), tolerance)
+    }
+
+    /// Adapts `self` from being seen under the `from` white point to the
+    /// color that looks the same under the `to` white point, as when reading
+    /// D50 colorimetry from ICC profiles or ProPhoto RGB into the D65 of sRGB.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{xyz, Adaptation, WhitePoint};
+    ///
+    /// // The D50 white of an ICC profile is plain white in sRGB.
+    /// let white = WhitePoint::D50
+    ///     .to_xyz()
+    ///     .adapt(WhitePoint::D50, WhitePoint::D65, Adaptation::Bradford);
+    ///
+    /// assert_eq!(white.to_string(), "color(xyz-d65 0.9505 1.0000 1.0891)");
+    /// ```
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint, method: Adaptation) -> XYZ {
+        adapt(self, from, to, method)
+    }
+
+    /// Converts `self` into CIELAB relative to the `white` point, adapting
+    /// it from D65 with the Bradford transform. [`Color::to_lab`] is the same
+    /// as using `WhitePoint::D50`, like CSS does.
+    ///
+    /// [`Color::to_lab`]: crate::Color::to_lab
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb, WhitePoint};
+    ///
+    /// let red = rgb(255, 0, 0).to_xyz();
+    ///
+    /// assert_eq!(red.to_lab(WhitePoint::D65).to_string(), "lab(53.24 80.09 67.20)");
+    /// ```
+    pub fn to_lab(self, white: WhitePoint) -> LAB {
+        let reference = white.to_xyz();
+        let XYZ { x, y, z } = self.adapt(WhitePoint::D65, white, Adaptation::Bradford);
+
+        from_white_relative([x / reference.x, y / reference.y, z / reference.z])
+    }
+
+    /// Converts `self` into OKLab, keeping values out of range, such as
+    /// highlights brighter than white.
+    pub fn to_oklab(self) -> OKLAB {
+        OKLAB::from_linear(xyz_to_srgb([self.x, self.y, self.z]))
+    }
+
+    /// Converts `self` into the linear channels of the `gamut`, keeping
+    /// values out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb, Gamut};
+    ///
+    /// let red = rgb(255, 0, 0).to_xyz().to_linear_rgb(Gamut::Srgb);
+    ///
+    /// assert!((red.r - 1.0).abs() < 1e-6 && red.g.abs() < 1e-6 && red.b.abs() < 1e-6);
+    /// ```
+    pub fn to_linear_rgb(self, gamut: Gamut) -> LinearRGB {
+        let [r, g, b] = gamut.channels(xyz_to_srgb([self.x, self.y, self.z]));
+        linear_rgb(r, g, b, gamut)
+    }
+
+    /// Returns whether every component of `self` is finite, which conversions
+    /// of infinite or NaN values can't restore.
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+impl fmt::Display for XYZ {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "color(xyz-d65 {:.04} {:.04} {:.04})",
+            self.x, self.y, self.z
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{rgb, rgba, xyz, Color, WhitePoint};
+
+    #[test]
+    fn can_convert_reference_colors() {
+        assert_eq!(
+            rgb(255, 255, 255).to_xyz().to_string(),
+            "color(xyz-d65 0.9505 1.0000 1.0891)"
+        );
+        assert_eq!(
+            rgb(255, 0, 0).to_xyz().to_string(),
+            "color(xyz-d65 0.4124 0.2126 0.0193)"
+        );
+        assert_eq!(rgb(0, 0, 0).to_xyz().chromaticity(), None);
+    }
+
+    #[test]
+    fn can_round_trip() {
+        for color in [
+            rgb(255, 99, 71),
+            rgb(23, 98, 119),
+            rgb(1, 2, 3),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ] {
+            assert_eq!(color.to_xyz().to_rgb(), color);
+        }
+
+        assert_eq!(rgba(255, 99, 71, 0.5).to_xyz(), rgb(255, 99, 71).to_xyz());
+    }
+
+    #[test]
+    fn can_convert_to_lab() {
+        for white in [
+            WhitePoint::D65,
+            WhitePoint::D50,
+            WhitePoint::A,
+            WhitePoint::F2,
+            WhitePoint::Custom(0.32, 0.33),
+        ] {
+            let white_lab = rgb(255, 255, 255).to_xyz().to_lab(white);
+            assert!((white_lab.l - 100.0).abs() < 1e-3, "{:?}", white);
+            assert!(
+                white_lab.a.abs() < 1e-2 && white_lab.b.abs() < 1e-2,
+                "{:?}",
+                white
+            );
+
+            let color = rgb(23, 98, 119);
+            assert_eq!(color.to_xyz().to_lab(white).to_xyz(white).to_rgb(), color);
+        }
+
+        let tomato = rgb(255, 99, 71);
+        let (lhs, rhs) = (tomato.to_xyz().to_lab(WhitePoint::D50), tomato.to_lab());
+        assert!((lhs.l - rhs.l).abs() < 0.05 && (lhs.a - rhs.a).abs() < 0.05);
+        assert!((lhs.b - rhs.b).abs() < 0.05);
+    }
+
+    #[test]
+    fn clips_out_of_gamut_colors() {
+        assert_eq!(xyz(2.0, 2.0, 2.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(xyz(-1.0, 0.0, 0.0).to_rgb().r.as_u8(), 0);
+    }
+}